@@ -0,0 +1,190 @@
+//! Implementation of the `agent` attribute macro, for exporting JVMTI agent entry points
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Ident, Result, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+mod kw {
+    syn::custom_keyword!(on_load);
+    syn::custom_keyword!(on_attach);
+    syn::custom_keyword!(on_unload);
+    syn::custom_keyword!(error_policy);
+}
+
+/// Which agent entry point should be exported
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AgentEntryPoint {
+    /// `Agent_OnLoad`
+    Load,
+    /// `Agent_OnAttach`
+    Attach,
+    /// `Agent_OnUnload`
+    Unload,
+}
+
+impl AgentEntryPoint {
+    fn export_name(&self) -> &'static str {
+        match self {
+            AgentEntryPoint::Load => "Agent_OnLoad",
+            AgentEntryPoint::Attach => "Agent_OnAttach",
+            AgentEntryPoint::Unload => "Agent_OnUnload",
+        }
+    }
+}
+
+/// Parsed `#[agent(...)]` attribute arguments
+struct AgentAttr {
+    jni_crate: syn::Path,
+    entry_point: AgentEntryPoint,
+    error_policy: Option<syn::Path>,
+}
+
+impl Parse for AgentAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let jni_crate = crate::utils::parse_jni_crate_override(&input)?;
+
+        let mut entry_point = None;
+        let mut error_policy = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::on_load)
+                || lookahead.peek(kw::on_attach)
+                || lookahead.peek(kw::on_unload)
+            {
+                let ident = input.call(Ident::parse_any)?;
+                if entry_point.is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "The `agent` attribute only supports exporting one entry point per function",
+                    ));
+                }
+                entry_point = Some(match ident.to_string().as_str() {
+                    "on_load" => AgentEntryPoint::Load,
+                    "on_attach" => AgentEntryPoint::Attach,
+                    _ => AgentEntryPoint::Unload,
+                });
+            } else if lookahead.peek(kw::error_policy) {
+                // Parse: error_policy = Path
+                let kw = input.parse::<kw::error_policy>()?;
+                input.parse::<Token![=]>()?;
+                if error_policy.is_some() {
+                    return Err(syn::Error::new(
+                        kw.span,
+                        "Duplicate `error_policy` property",
+                    ));
+                }
+                error_policy = Some(input.parse::<syn::Path>()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let Some(entry_point) = entry_point else {
+            return Err(syn::Error::new(
+                input.span(),
+                "The `agent` attribute requires one of `on_load`, `on_attach` or `on_unload`",
+            ));
+        };
+
+        if let Some(error_policy) = error_policy
+            .as_ref()
+            .filter(|_| entry_point != AgentEntryPoint::Attach)
+        {
+            return Err(syn::Error::new(
+                error_policy.span(),
+                "`error_policy` is only supported for `on_attach` (JNI isn't available while \
+                 loading or unloading an agent, so errors are logged)",
+            ));
+        }
+
+        Ok(AgentAttr {
+            jni_crate,
+            entry_point,
+            error_policy,
+        })
+    }
+}
+
+pub fn agent_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let attr: AgentAttr = syn::parse2(attr)?;
+
+    let function = crate::utils::parse_entry_point_fn("agent", item)?;
+
+    let jni = &attr.jni_crate;
+    let fn_ident = &function.sig.ident;
+    let export_name = attr.entry_point.export_name();
+    let export_name_attr = crate::utils::export_name_attr(export_name);
+
+    let entry_point = match attr.entry_point {
+        AgentEntryPoint::Load => quote! {
+            #[doc(hidden)]
+            #export_name_attr
+            pub extern "system" fn __jni_agent_on_load(
+                vm: *mut #jni::sys::JavaVM,
+                options: *mut ::std::os::raw::c_char,
+                _reserved: *mut ::std::ffi::c_void,
+            ) -> #jni::sys::jint {
+                // Safety: the arguments are passed through from the JVM
+                unsafe { #jni::jvmti::__agent_on_load(vm, options, #fn_ident) }
+            }
+        },
+        AgentEntryPoint::Attach => {
+            let error_policy = attr
+                .error_policy
+                .as_ref()
+                .map(|p| quote! { #p })
+                .unwrap_or_else(|| quote! { #jni::errors::LogErrorAndDefault });
+            quote! {
+                #[doc(hidden)]
+                #export_name_attr
+                pub extern "system" fn __jni_agent_on_attach(
+                    vm: *mut #jni::sys::JavaVM,
+                    options: *mut ::std::os::raw::c_char,
+                    _reserved: *mut ::std::ffi::c_void,
+                ) -> #jni::sys::jint {
+                    // Safety: the arguments are passed through from the JVM
+                    unsafe {
+                        #jni::jvmti::__agent_on_attach(vm, options, |vm, mut unowned_env, options| {
+                            unowned_env
+                                .with_env(|env| {
+                                    // Agent_OnAttach isn't called like a native method, so we need
+                                    // a local frame to avoid leaking local references
+                                    env.with_local_frame(#jni::DEFAULT_LOCAL_FRAME_CAPACITY, |env| {
+                                        #fn_ident(vm, env, options)
+                                    })
+                                    .map(|()| #jni::jvmti::AgentStatus::OK)
+                                })
+                                .resolve::<#error_policy>()
+                        })
+                    }
+                }
+            }
+        }
+        AgentEntryPoint::Unload => quote! {
+            #[doc(hidden)]
+            #export_name_attr
+            pub extern "system" fn __jni_agent_on_unload(vm: *mut #jni::sys::JavaVM) {
+                // Safety: the arguments are passed through from the JVM
+                unsafe { #jni::jvmti::__agent_on_unload(vm, #fn_ident) }
+            }
+        },
+    };
+
+    Ok(quote! {
+        #function
+
+        const _: () = {
+            #entry_point
+        };
+    })
+}
@@ -1,6 +1,8 @@
+mod agent;
 mod bind_java_type;
 mod mangle;
 mod native_method;
+mod on_load;
 mod signature;
 mod str;
 mod types;
@@ -120,6 +122,42 @@ pub fn jni_mangle(
     mangle::jni_mangle2(attr.into(), item.into()).into()
 }
 
+// Note: This crate is marked with doctest = false and documentation is owned
+// by the jni crate. See ../../jni/docs/macros/agent.md file
+#[proc_macro_attribute]
+pub fn agent(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    agent::agent_impl(attr.into(), item.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+// Note: This crate is marked with doctest = false and documentation is owned
+// by the jni crate. See ../../jni/docs/macros/on_load.md file
+#[proc_macro_attribute]
+pub fn on_load(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    on_load::on_load_impl(attr.into(), item.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+// Note: This crate is marked with doctest = false and documentation is owned
+// by the jni crate. See ../../jni/docs/macros/on_unload.md file
+#[proc_macro_attribute]
+pub fn on_unload(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    on_load::on_unload_impl(attr.into(), item.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 // Note: This crate is marked with doctest = false and documentation is owned
 // by the jni crate. See ../../jni/docs/macros/native_method.md file
 #[proc_macro]
@@ -0,0 +1,188 @@
+//! Implementation of the `on_load` and `on_unload` attribute macros, for exporting `JNI_OnLoad`
+//! and `JNI_OnUnload` library entry points
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Result, Token, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+mod kw {
+    syn::custom_keyword!(version);
+    syn::custom_keyword!(natives);
+    syn::custom_keyword!(error_policy);
+}
+
+/// Parsed `#[on_load(...)]` attribute arguments
+struct OnLoadAttr {
+    jni_crate: syn::Path,
+    version: Option<syn::Expr>,
+    natives: Vec<syn::Type>,
+    error_policy: Option<syn::Path>,
+}
+
+impl Parse for OnLoadAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let jni_crate = crate::utils::parse_jni_crate_override(&input)?;
+
+        let mut version = None;
+        let mut natives = None;
+        let mut error_policy = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::version) {
+                // Parse: version = Expr
+                let kw = input.parse::<kw::version>()?;
+                input.parse::<Token![=]>()?;
+                if version.is_some() {
+                    return Err(syn::Error::new(kw.span, "Duplicate `version` property"));
+                }
+                version = Some(input.parse::<syn::Expr>()?);
+            } else if lookahead.peek(kw::natives) {
+                // Parse: natives = [Type, ...]
+                let kw = input.parse::<kw::natives>()?;
+                input.parse::<Token![=]>()?;
+                if natives.is_some() {
+                    return Err(syn::Error::new(kw.span, "Duplicate `natives` property"));
+                }
+                let content;
+                bracketed!(content in input);
+                let types: Punctuated<syn::Type, Token![,]> =
+                    content.parse_terminated(syn::Type::parse, Token![,])?;
+                natives = Some(types.into_iter().collect());
+            } else if lookahead.peek(kw::error_policy) {
+                // Parse: error_policy = Path
+                let kw = input.parse::<kw::error_policy>()?;
+                input.parse::<Token![=]>()?;
+                if error_policy.is_some() {
+                    return Err(syn::Error::new(
+                        kw.span,
+                        "Duplicate `error_policy` property",
+                    ));
+                }
+                error_policy = Some(input.parse::<syn::Path>()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(OnLoadAttr {
+            jni_crate,
+            version,
+            natives: natives.unwrap_or_default(),
+            error_policy,
+        })
+    }
+}
+
+/// Parsed `#[on_unload(...)]` attribute arguments
+struct OnUnloadAttr {
+    jni_crate: syn::Path,
+}
+
+impl Parse for OnUnloadAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let jni_crate = crate::utils::parse_jni_crate_override(&input)?;
+        if !input.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "The `on_unload` attribute only supports a `jni = <path>` argument",
+            ));
+        }
+        Ok(OnUnloadAttr { jni_crate })
+    }
+}
+
+pub fn on_load_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let attr: OnLoadAttr = syn::parse2(attr)?;
+    let function = crate::utils::parse_entry_point_fn("on_load", item)?;
+
+    let jni = &attr.jni_crate;
+    let fn_ident = &function.sig.ident;
+    let export_name_attr = crate::utils::export_name_attr("JNI_OnLoad");
+
+    let version = attr
+        .version
+        .as_ref()
+        .map(|v| quote! { #v })
+        .unwrap_or_else(|| quote! { #jni::JNIVersion::V1_6 });
+    let error_policy = attr
+        .error_policy
+        .as_ref()
+        .map(|p| quote! { #p })
+        .unwrap_or_else(|| quote! { #jni::errors::ThrowRuntimeExAndDefault });
+
+    // Looking up the class for a `bind_java_type!` type will register its native methods
+    //
+    // The class is looked up via the loader that `FindClass` uses within `JNI_OnLoad` (i.e. the
+    // loader of the class that called `System.loadLibrary`), not the thread's context loader.
+    let native_registrations = attr.natives.iter().map(|ty| {
+        quote! {
+            let loader = #jni::vm::__jni_on_load_class_loader::<#ty>(env)?;
+            let _ = <#ty as #jni::refs::Reference>::lookup_class(
+                env,
+                &#jni::refs::LoaderContext::Loader(&loader),
+            )?;
+        }
+    });
+
+    Ok(quote! {
+        #function
+
+        const _: () = {
+            #[doc(hidden)]
+            #export_name_attr
+            pub extern "system" fn __jni_on_load(
+                vm: *mut #jni::sys::JavaVM,
+                _reserved: *mut ::std::ffi::c_void,
+            ) -> #jni::sys::jint {
+                // Safety: the arguments are passed through from the JVM
+                unsafe {
+                    #jni::vm::__jni_on_load(vm, |vm, mut unowned_env| {
+                        unowned_env
+                            .with_env(|env| {
+                                env.with_local_frame(#jni::DEFAULT_LOCAL_FRAME_CAPACITY, |env| {
+                                    #(#native_registrations)*
+                                    #fn_ident(vm, env)
+                                })
+                                .map(|()| #jni::sys::jint::from(#version))
+                            })
+                            .resolve::<#error_policy>()
+                    })
+                }
+            }
+        };
+    })
+}
+
+pub fn on_unload_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let attr: OnUnloadAttr = syn::parse2(attr)?;
+    let function = crate::utils::parse_entry_point_fn("on_unload", item)?;
+
+    let jni = &attr.jni_crate;
+    let fn_ident = &function.sig.ident;
+    let export_name_attr = crate::utils::export_name_attr("JNI_OnUnload");
+
+    Ok(quote! {
+        #function
+
+        const _: () = {
+            #[doc(hidden)]
+            #export_name_attr
+            pub extern "system" fn __jni_on_unload(
+                vm: *mut #jni::sys::JavaVM,
+                _reserved: *mut ::std::ffi::c_void,
+            ) {
+                // Safety: the arguments are passed through from the JVM
+                unsafe { #jni::vm::__jni_on_unload(vm, #fn_ident) }
+            }
+        };
+    })
+}
@@ -590,12 +590,26 @@ impl TypeMappings {
 
         // Add default type mappings for built-in jni crate types
         let builtins = [
+            (
+                "JBiFunction",
+                "java.util.function.BiFunction",
+                "objects::JBiFunction",
+                false,
+            ),
+            ("JBoolean", "java.lang.Boolean", "objects::JBoolean", false),
+            ("JByte", "java.lang.Byte", "objects::JByte", false),
             (
                 "JByteBuffer",
                 "java.nio.ByteBuffer",
                 "objects::JByteBuffer",
                 false,
             ),
+            (
+                "JCharacter",
+                "java.lang.Character",
+                "objects::JCharacter",
+                false,
+            ),
             (
                 "JCharSequence",
                 "java.lang.CharSequence",
@@ -615,6 +629,51 @@ impl TypeMappings {
                 "objects::JCollection",
                 false,
             ),
+            (
+                "JComparator",
+                "java.util.Comparator",
+                "objects::JComparator",
+                false,
+            ),
+            (
+                "JCompletableFuture",
+                "java.util.concurrent.CompletableFuture",
+                "objects::JCompletableFuture",
+                false,
+            ),
+            (
+                "JConstructor",
+                "java.lang.reflect.Constructor",
+                "objects::JConstructor",
+                false,
+            ),
+            (
+                "JConsumer",
+                "java.util.function.Consumer",
+                "objects::JConsumer",
+                false,
+            ),
+            ("JDouble", "java.lang.Double", "objects::JDouble", false),
+            (
+                "JExecutable",
+                "java.lang.reflect.Executable",
+                "objects::JExecutable",
+                false,
+            ),
+            (
+                "JField",
+                "java.lang.reflect.Field",
+                "objects::JField",
+                false,
+            ),
+            ("JFloat", "java.lang.Float", "objects::JFloat", false),
+            (
+                "JFunction",
+                "java.util.function.Function",
+                "objects::JFunction",
+                false,
+            ),
+            ("JInteger", "java.lang.Integer", "objects::JInteger", false),
             (
                 "JIterator",
                 "java.util.Iterator",
@@ -622,6 +681,7 @@ impl TypeMappings {
                 false,
             ),
             ("JList", "java.util.List", "objects::JList", false),
+            ("JLong", "java.lang.Long", "objects::JLong", false),
             ("JMap", "java.util.Map", "objects::JMap", false),
             (
                 "JMapEntry",
@@ -629,8 +689,29 @@ impl TypeMappings {
                 "objects::JMapEntry",
                 false,
             ),
+            (
+                "JMethod",
+                "java.lang.reflect.Method",
+                "objects::JMethod",
+                false,
+            ),
+            ("JModule", "java.lang.Module", "objects::JModule", false),
+            ("JNumber", "java.lang.Number", "objects::JNumber", false),
             ("JObject", "java.lang.Object", "objects::JObject", true),
+            (
+                "JPredicate",
+                "java.util.function.Predicate",
+                "objects::JPredicate",
+                false,
+            ),
+            (
+                "JRunnable",
+                "java.lang.Runnable",
+                "objects::JRunnable",
+                false,
+            ),
             ("JSet", "java.util.Set", "objects::JSet", false),
+            ("JShort", "java.lang.Short", "objects::JShort", false),
             (
                 "JStackTraceElement",
                 "java.lang.StackTraceElement",
@@ -638,6 +719,12 @@ impl TypeMappings {
                 false,
             ),
             ("JString", "java.lang.String", "objects::JString", true),
+            (
+                "JSupplier",
+                "java.util.function.Supplier",
+                "objects::JSupplier",
+                false,
+            ),
             ("JThread", "java.lang.Thread", "objects::JThread", false),
             (
                 "JThrowable",
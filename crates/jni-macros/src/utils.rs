@@ -1,4 +1,6 @@
-use syn::{Ident, Token};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Token, spanned::Spanned};
 
 /// The default name for the `jni` crate
 ///
@@ -45,3 +47,48 @@ pub fn parse_jni_crate_override(input: &syn::parse::ParseStream) -> Result<syn::
 
     Ok(jni_path.unwrap_or_else(jni_crate_default))
 }
+
+/// Parses and validates the function that an entry point attribute is applied to
+pub fn parse_entry_point_fn(attr_name: &str, item: TokenStream) -> syn::Result<syn::ItemFn> {
+    let item_span = item.span();
+    let function: syn::ItemFn = syn::parse2(item).map_err(|_| {
+        syn::Error::new(
+            item_span,
+            format!("The `{attr_name}` attribute can only be applied to `fn` items"),
+        )
+    })?;
+
+    let sig = &function.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            format!("`{attr_name}` functions can't be `async`"),
+        ));
+    }
+    if let Some(abi) = &sig.abi {
+        return Err(syn::Error::new(
+            abi.span(),
+            format!(
+                "`{attr_name}` functions should be regular Rust functions (the `extern \"system\"` \
+                 entry point is generated)"
+            ),
+        ));
+    }
+    if sig.generics.type_params().next().is_some() || sig.generics.const_params().next().is_some() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            format!("`{attr_name}` functions can't have type or const generic parameters"),
+        ));
+    }
+
+    Ok(function)
+}
+
+/// Returns an `export_name` attribute for an exported entry point
+pub fn export_name_attr(export_name: &str) -> TokenStream {
+    if cfg!(has_unsafe_attr) {
+        quote! { #[unsafe(export_name = #export_name)] }
+    } else {
+        quote! { #[export_name = #export_name] }
+    }
+}
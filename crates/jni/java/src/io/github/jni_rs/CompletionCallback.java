@@ -0,0 +1,31 @@
+package io.github.jni_rs;
+
+import java.util.function.BiConsumer;
+
+/**
+ * A {@code CompletableFuture} completion action that forwards the outcome to Rust.
+ *
+ * <p>The {@code handle} refers to Rust state that's released by the native {@code complete}
+ * method, so it's only passed to Rust once, even if {@code accept} is called more than once.
+ */
+final class CompletionCallback implements BiConsumer<Object, Throwable> {
+    private long handle;
+
+    CompletionCallback(long handle) {
+        this.handle = handle;
+    }
+
+    @Override
+    public void accept(Object value, Throwable error) {
+        long handle;
+        synchronized (this) {
+            handle = this.handle;
+            this.handle = 0;
+        }
+        if (handle != 0) {
+            complete(handle, value, error);
+        }
+    }
+
+    private static native void complete(long handle, Object value, Throwable error);
+}
@@ -0,0 +1,40 @@
+package io.github.jni_rs;
+
+import java.lang.ref.Cleaner;
+
+/**
+ * A cleaning action that releases Rust state once the object that owns it becomes unreachable.
+ *
+ * <p>All the actions share a single {@code Cleaner} (and so a single cleaner thread), which is also
+ * used by {@link RustInvocationHandler}. The Rust state is referenced by a {@code handle}, which is
+ * released by calling the Rust {@code dropFn} function pointer.
+ */
+final class RustCleanup implements Runnable {
+    static final Cleaner CLEANER = Cleaner.create();
+
+    private final long dropFn;
+    private long handle;
+
+    private RustCleanup(long handle, long dropFn) {
+        this.handle = handle;
+        this.dropFn = dropFn;
+    }
+
+    static void register(Object owner, long handle, long dropFn) {
+        CLEANER.register(owner, new RustCleanup(handle, dropFn));
+    }
+
+    @Override
+    public void run() {
+        long handle;
+        synchronized (this) {
+            handle = this.handle;
+            this.handle = 0;
+        }
+        if (handle != 0) {
+            release(handle, dropFn);
+        }
+    }
+
+    private static native void release(long handle, long dropFn);
+}
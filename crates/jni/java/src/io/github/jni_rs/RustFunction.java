@@ -0,0 +1,103 @@
+package io.github.jni_rs;
+
+import java.util.function.BiFunction;
+import java.util.function.Consumer;
+import java.util.function.Function;
+import java.util.function.Predicate;
+import java.util.function.Supplier;
+
+/**
+ * Base class for implementations of the {@code java.util.function} interfaces (and
+ * {@code Runnable}) that call a Rust closure.
+ *
+ * <p>The closure is referenced by a Rust handle that's released by {@link RustCleanup} once the
+ * object becomes unreachable. The native methods are instance methods so that the object stays
+ * reachable while the closure is running.
+ */
+abstract class RustFunction {
+    private final long handle;
+
+    RustFunction(long handle, long dropFn) {
+        this.handle = handle;
+        RustCleanup.register(this, handle, dropFn);
+    }
+
+    final Object call(Object a, Object b) {
+        return call(handle, a, b);
+    }
+
+    final boolean callBoolean(Object a) {
+        return callBoolean(handle, a);
+    }
+
+    private native Object call(long handle, Object a, Object b);
+
+    private native boolean callBoolean(long handle, Object a);
+
+    static final class OfFunction extends RustFunction implements Function<Object, Object> {
+        OfFunction(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public Object apply(Object t) {
+            return call(t, null);
+        }
+    }
+
+    static final class OfSupplier extends RustFunction implements Supplier<Object> {
+        OfSupplier(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public Object get() {
+            return call(null, null);
+        }
+    }
+
+    static final class OfConsumer extends RustFunction implements Consumer<Object> {
+        OfConsumer(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public void accept(Object t) {
+            call(t, null);
+        }
+    }
+
+    static final class OfBiFunction extends RustFunction
+            implements BiFunction<Object, Object, Object> {
+        OfBiFunction(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public Object apply(Object t, Object u) {
+            return call(t, u);
+        }
+    }
+
+    static final class OfPredicate extends RustFunction implements Predicate<Object> {
+        OfPredicate(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public boolean test(Object t) {
+            return callBoolean(t);
+        }
+    }
+
+    static final class OfRunnable extends RustFunction implements Runnable {
+        OfRunnable(long handle, long dropFn) {
+            super(handle, dropFn);
+        }
+
+        @Override
+        public void run() {
+            call(null, null);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+package io.github.jni_rs;
+
+import java.lang.reflect.InvocationHandler;
+import java.lang.reflect.Method;
+
+/**
+ * An {@code InvocationHandler} for {@code java.lang.reflect.Proxy} instances that dispatches
+ * method calls to Rust.
+ *
+ * <p>The handler is registered as the cleaning action for its proxy (with the cleaner that's shared
+ * with {@link RustCleanup}), so the Rust state is released once the proxy becomes unreachable (or
+ * when {@code run} is called explicitly), after any calls that are still in progress have
+ * returned.
+ */
+final class RustInvocationHandler implements InvocationHandler, Runnable {
+    private long handle;
+    private int activeCalls;
+    private boolean released;
+
+    RustInvocationHandler(long handle) {
+        this.handle = handle;
+    }
+
+    void registerCleanup(Object proxy) {
+        RustCleanup.CLEANER.register(proxy, this);
+    }
+
+    @Override
+    public Object invoke(Object proxy, Method method, Object[] args) throws Throwable {
+        if (method.getDeclaringClass() == Object.class) {
+            switch (method.getName()) {
+                case "equals":
+                    return proxy == args[0];
+                case "hashCode":
+                    return System.identityHashCode(proxy);
+                case "toString":
+                    return proxy.getClass().getName() + "@"
+                            + Integer.toHexString(System.identityHashCode(proxy));
+                default:
+                    break;
+            }
+        }
+
+        long handle;
+        synchronized (this) {
+            if (released) {
+                throw new IllegalStateException("The Rust state for this proxy has been released");
+            }
+            handle = this.handle;
+            activeCalls++;
+        }
+        try {
+            return dispatch(handle, proxy, method, args);
+        } finally {
+            synchronized (this) {
+                activeCalls--;
+                handle = takeHandleIfReleasable();
+            }
+            if (handle != 0) {
+                release(handle);
+            }
+        }
+    }
+
+    @Override
+    public void run() {
+        long handle;
+        synchronized (this) {
+            released = true;
+            handle = takeHandleIfReleasable();
+        }
+        if (handle != 0) {
+            release(handle);
+        }
+    }
+
+    private long takeHandleIfReleasable() {
+        if (!released || activeCalls > 0) {
+            return 0;
+        }
+        long handle = this.handle;
+        this.handle = 0;
+        return handle;
+    }
+
+    private static native Object dispatch(long handle, Object proxy, Method method, Object[] args);
+
+    private static native void release(long handle);
+}
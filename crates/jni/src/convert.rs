@@ -0,0 +1,411 @@
+//! Conversions between Java values and Rust types, via the
+//! [`FromJava`](crate::convert::FromJava) and [`IntoJava`](crate::convert::IntoJava) traits.
+//!
+//! Both traits convert via [`JValueOwned`](crate::JValueOwned), so that the same conversions work
+//! for primitive values and objects. For example, an `i32` can be converted from an `int` method
+//! return value, or from a `java.lang.Integer` that's an element of a `java.util.List`.
+//!
+//! The built-in conversions are:
+//!
+//! | Rust                      | Java (from)                                   | Java (into)           |
+//! |---------------------------|-----------------------------------------------|-----------------------|
+//! | `bool`, `i8`, `u16`, `i16`, `i32`, `i64`, `f32`, `f64` | the primitive, or its boxed wrapper | the primitive |
+//! | `String` / `&str`         | `java.lang.String`                            | `java.lang.String`    |
+//! | `Option<T>`               | `null` (as `None`), or `T`                    | `null` or `T`         |
+//! | `Vec<u8>`                 | `byte[]`                                      | `byte[]`              |
+//! | `Vec<T>`                  | any array, or `java.util.Collection`          | `java.util.ArrayList` |
+//! | `HashSet<T>`              | `java.util.Collection`                        | `java.util.HashSet`   |
+//! | `HashMap<K, V>`           | `java.util.Map`                               | `java.util.HashMap`   |
+//! | `JObject`                 | any object                                    | the object            |
+//! | `Global<T>`               | any object (as a new global reference)        |                       |
+//!
+//! Primitive values are boxed (e.g. as a `java.lang.Integer`) when they are stored in a
+//! collection.
+//!
+//! Each element of an array or collection is converted within its own local reference frame, so
+//! converting large collections doesn't exhaust the local reference capacity.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use std::collections::HashMap;
+//! # use jni::{errors::Result, Env, jni_sig, jni_str, objects::JObject};
+//! # fn example<'local>(env: &mut Env<'local>, config: &JObject<'local>) -> Result<()> {
+//! let settings: HashMap<String, i32> = env
+//!     .call_method(config, jni_str!("getSettings"), jni_sig!("()Ljava/util/Map;"), &[])?
+//!     .into_rust(env)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    Env, JValueOwned,
+    errors::{Error, Result},
+    jni_sig, jni_str,
+    objects::{
+        BoxedPrimitive, ELEMENT_FRAME_CAPACITY, JBoolean, JBooleanArray, JByte, JByteArray,
+        JCharArray, JCharacter, JCollection, JDouble, JDoubleArray, JFloat, JFloatArray, JIntArray,
+        JInteger, JList, JLong, JLongArray, JMap, JObject, JObjectArray, JPrimitiveArray, JSet,
+        JShort, JShortArray, JString, Reference, TypeArray,
+    },
+    refs::Global,
+    sys::{jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort},
+};
+
+/// A Rust type that can be converted from a Java value
+///
+/// See the [module documentation](crate::convert) for the built-in conversions.
+///
+/// Types that don't borrow any local references implement `FromJava` for all lifetimes (i.e.
+/// `for<'any> FromJava<'any>`), which is required for the elements of collections.
+pub trait FromJava<'local>: Sized {
+    /// Converts the given Java `value`.
+    ///
+    /// Returns [`Error::WrongJValueType`] or [`Error::WrongObjectType`] if the value has the
+    /// wrong type, or [`Error::NullPtr`] if the value is `null` and the type doesn't have a
+    /// representation for `null` (unlike `Option<T>`).
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self>;
+}
+
+/// A Rust type that can be converted into a Java value
+///
+/// See the [module documentation](crate::convert) for the built-in conversions.
+pub trait IntoJava<'local> {
+    /// Converts this value into a Java value.
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>>;
+
+    /// Converts this value into a Java object, boxing any primitive value.
+    fn into_java_object(self, env: &mut Env<'local>) -> Result<JObject<'local>>
+    where
+        Self: Sized,
+    {
+        let value = self.into_java(env)?;
+        box_value(env, value)
+    }
+}
+
+/// Boxes a primitive `value` (e.g. as a `java.lang.Integer`), or returns an object unchanged.
+fn box_value<'local>(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<JObject<'local>> {
+    Ok(match value {
+        JValueOwned::Object(obj) => obj,
+        JValueOwned::Byte(v) => JByte::try_from_primitive(env, v)?.into(),
+        JValueOwned::Char(v) => JCharacter::try_from_primitive(env, v)?.into(),
+        JValueOwned::Short(v) => JShort::try_from_primitive(env, v)?.into(),
+        JValueOwned::Int(v) => JInteger::try_from_primitive(env, v)?.into(),
+        JValueOwned::Long(v) => JLong::try_from_primitive(env, v)?.into(),
+        JValueOwned::Bool(v) => JBoolean::try_from_primitive(env, v)?.into(),
+        JValueOwned::Float(v) => JFloat::try_from_primitive(env, v)?.into(),
+        JValueOwned::Double(v) => JDouble::try_from_primitive(env, v)?.into(),
+        JValueOwned::Void => return Err(Error::WrongJValueType("object", "void")),
+    })
+}
+
+/// Returns the object for a `value` that must be a non-null object.
+fn non_null_object<'local>(
+    value: JValueOwned<'local>,
+    what: &'static str,
+) -> Result<JObject<'local>> {
+    let obj = value.l()?;
+    if obj.is_null() {
+        return Err(Error::NullPtr(what));
+    }
+    Ok(obj)
+}
+
+macro_rules! impl_primitive_conversions {
+    ($primitive:ty, $variant:ident, $boxed:ident, $name:literal) => {
+        impl<'local> FromJava<'local> for $primitive {
+            fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+                match value {
+                    JValueOwned::$variant(v) => Ok(v),
+                    JValueOwned::Object(obj) => {
+                        let boxed = env.cast_local::<$boxed>(obj)?;
+                        boxed.try_to_primitive(env)
+                    }
+                    other => Err(Error::WrongJValueType($name, other.type_name())),
+                }
+            }
+        }
+
+        impl<'local> IntoJava<'local> for $primitive {
+            fn into_java(self, _env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+                Ok(JValueOwned::$variant(self))
+            }
+        }
+    };
+}
+
+impl_primitive_conversions!(jboolean, Bool, JBoolean, "boolean");
+impl_primitive_conversions!(jbyte, Byte, JByte, "byte");
+impl_primitive_conversions!(jchar, Char, JCharacter, "char");
+impl_primitive_conversions!(jshort, Short, JShort, "short");
+impl_primitive_conversions!(jint, Int, JInteger, "int");
+impl_primitive_conversions!(jlong, Long, JLong, "long");
+impl_primitive_conversions!(jfloat, Float, JFloat, "float");
+impl_primitive_conversions!(jdouble, Double, JDouble, "double");
+
+impl<'local> FromJava<'local> for JValueOwned<'local> {
+    fn from_java(_env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl<'local> IntoJava<'local> for JValueOwned<'local> {
+    fn into_java(self, _env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        Ok(self)
+    }
+}
+
+impl<'local> FromJava<'local> for JObject<'local> {
+    fn from_java(_env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        value.l()
+    }
+}
+
+impl<'local> IntoJava<'local> for JObject<'local> {
+    fn into_java(self, _env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(self))
+    }
+}
+
+impl<'local, T> FromJava<'local> for Global<T>
+where
+    T: Reference<GlobalKind = T>
+        + Into<JObject<'static>>
+        + AsRef<JObject<'static>>
+        + Default
+        + Send
+        + Sync
+        + 'static,
+{
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        env.new_cast_global_ref::<T>(value.l()?)
+    }
+}
+
+impl<'local> FromJava<'local> for String {
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        let obj = non_null_object(value, "String value")?;
+        let string = env.cast_local::<JString>(obj)?;
+        string.try_to_string(env)
+    }
+}
+
+impl<'local> IntoJava<'local> for String {
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        self.as_str().into_java(env)
+    }
+}
+
+impl<'local> IntoJava<'local> for &str {
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(JString::from_str(env, self)?.into()))
+    }
+}
+
+impl<'local, T: FromJava<'local>> FromJava<'local> for Option<T> {
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            T::from_java(env, value).map(Some)
+        }
+    }
+}
+
+impl<'local, T: IntoJava<'local>> IntoJava<'local> for Option<T> {
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        match self {
+            Some(value) => value.into_java(env),
+            None => Ok(JValueOwned::Object(JObject::null())),
+        }
+    }
+}
+
+impl<'local> FromJava<'local> for Vec<u8> {
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        let obj = non_null_object(value, "byte[] value")?;
+        let array = env.cast_local::<JByteArray>(obj)?;
+        env.convert_byte_array(array)
+    }
+}
+
+impl<'local> IntoJava<'local> for Vec<u8> {
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(
+            env.byte_array_from_slice(&self)?.into(),
+        ))
+    }
+}
+
+/// Converts each element of a primitive array (via `JValueOwned`).
+fn primitive_array_to_vec<P, T>(
+    env: &mut Env<'_>,
+    array: &JPrimitiveArray<'_, P>,
+    variant: fn(P) -> JValueOwned<'static>,
+) -> Result<Vec<T>>
+where
+    P: TypeArray + Default + Clone,
+    T: for<'any> FromJava<'any>,
+{
+    let mut buf = vec![P::default(); array.len(env)?];
+    array.get_region(env, 0, &mut buf)?;
+    buf.into_iter()
+        .map(|value| T::from_java(env, variant(value)))
+        .collect()
+}
+
+impl<'local, T> FromJava<'local> for Vec<T>
+where
+    T: for<'any> FromJava<'any>,
+{
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        let obj = non_null_object(value, "array or collection value")?;
+
+        if let Ok(collection) = env.as_cast::<JCollection>(&obj) {
+            return collection.to_vec(env);
+        }
+
+        let class = env.get_object_class(&obj)?;
+        let name = class.get_name(env)?.try_to_string(env)?;
+        match name.as_str() {
+            "[Z" => primitive_array_to_vec(
+                env,
+                &env.cast_local::<JBooleanArray>(obj)?,
+                JValueOwned::Bool,
+            ),
+            "[B" => {
+                primitive_array_to_vec(env, &env.cast_local::<JByteArray>(obj)?, JValueOwned::Byte)
+            }
+            "[C" => {
+                primitive_array_to_vec(env, &env.cast_local::<JCharArray>(obj)?, JValueOwned::Char)
+            }
+            "[S" => primitive_array_to_vec(
+                env,
+                &env.cast_local::<JShortArray>(obj)?,
+                JValueOwned::Short,
+            ),
+            "[I" => {
+                primitive_array_to_vec(env, &env.cast_local::<JIntArray>(obj)?, JValueOwned::Int)
+            }
+            "[J" => {
+                primitive_array_to_vec(env, &env.cast_local::<JLongArray>(obj)?, JValueOwned::Long)
+            }
+            "[F" => primitive_array_to_vec(
+                env,
+                &env.cast_local::<JFloatArray>(obj)?,
+                JValueOwned::Float,
+            ),
+            "[D" => primitive_array_to_vec(
+                env,
+                &env.cast_local::<JDoubleArray>(obj)?,
+                JValueOwned::Double,
+            ),
+            name if name.starts_with('[') => {
+                let array = env.cast_local::<JObjectArray>(obj)?;
+                let len = array.len(env)?;
+                let mut vec = Vec::with_capacity(len);
+                for index in 0..len {
+                    vec.push(
+                        env.with_local_frame(ELEMENT_FRAME_CAPACITY, |env| -> Result<_> {
+                            let element = array.get_element(env, index)?;
+                            T::from_java(env, JValueOwned::Object(element))
+                        })?,
+                    );
+                }
+                Ok(vec)
+            }
+            _ => Err(Error::WrongObjectType),
+        }
+    }
+}
+
+impl<'local, T> IntoJava<'local> for Vec<T>
+where
+    T: for<'any> IntoJava<'any>,
+{
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        let list = env.new_object(
+            jni_str!("java/util/ArrayList"),
+            jni_sig!("(I)V"),
+            &[(self.len().min(jint::MAX as usize) as jint).into()],
+        )?;
+        let list = env.cast_local::<JList>(list)?;
+        for element in self {
+            env.with_local_frame(ELEMENT_FRAME_CAPACITY, |env| -> Result<()> {
+                let element = element.into_java_object(env)?;
+                list.add(env, &element)?;
+                Ok(())
+            })?;
+        }
+        Ok(JValueOwned::Object(list.into()))
+    }
+}
+
+impl<'local, T, S> FromJava<'local> for HashSet<T, S>
+where
+    T: for<'any> FromJava<'any> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        let obj = non_null_object(value, "collection value")?;
+        let collection = env.cast_local::<JCollection>(obj)?;
+        collection.iter_as(env)?.collect()
+    }
+}
+
+impl<'local, T, S> IntoJava<'local> for HashSet<T, S>
+where
+    T: for<'any> IntoJava<'any>,
+{
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        let set = env.new_object(jni_str!("java/util/HashSet"), jni_sig!("()V"), &[])?;
+        let set = env.cast_local::<JSet>(set)?;
+        for element in self {
+            env.with_local_frame(ELEMENT_FRAME_CAPACITY, |env| -> Result<()> {
+                let element = element.into_java_object(env)?;
+                set.add(env, &element)?;
+                Ok(())
+            })?;
+        }
+        Ok(JValueOwned::Object(set.into()))
+    }
+}
+
+impl<'local, K, V, S> FromJava<'local> for HashMap<K, V, S>
+where
+    K: for<'any> FromJava<'any> + Eq + Hash,
+    V: for<'any> FromJava<'any>,
+    S: BuildHasher + Default,
+{
+    fn from_java(env: &mut Env<'local>, value: JValueOwned<'local>) -> Result<Self> {
+        let obj = non_null_object(value, "map value")?;
+        let map = env.cast_local::<JMap>(obj)?;
+        map.iter_as(env)?.collect()
+    }
+}
+
+impl<'local, K, V, S> IntoJava<'local> for HashMap<K, V, S>
+where
+    K: for<'any> IntoJava<'any>,
+    V: for<'any> IntoJava<'any>,
+{
+    fn into_java(self, env: &mut Env<'local>) -> Result<JValueOwned<'local>> {
+        let map = env.new_object(jni_str!("java/util/HashMap"), jni_sig!("()V"), &[])?;
+        let map = env.cast_local::<JMap>(map)?;
+        for (key, value) in self {
+            env.with_local_frame(ELEMENT_FRAME_CAPACITY, |env| -> Result<()> {
+                let key = key.into_java_object(env)?;
+                let value = value.into_java_object(env)?;
+                map.put(env, &key, &value)?;
+                Ok(())
+            })?;
+        }
+        Ok(JValueOwned::Object(map.into()))
+    }
+}
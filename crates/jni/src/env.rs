@@ -16,9 +16,11 @@ use crate::{
     jni_sig,
     objects::{
         Auto, AutoElements, AutoElementsCritical, Global, IntoAuto, JByteBuffer, JClass,
-        JClassLoader, JFieldID, JList, JMap, JMethodID, JObject, JStaticFieldID, JStaticMethodID,
-        JString, JThrowable, JValue, JValueOwned, ReleaseMode, TypeArray, Weak,
+        JClassLoader, JExecutable, JField, JFieldID, JList, JMap, JMethodID, JModule, JObject,
+        JStaticFieldID, JStaticMethodID, JString, JThrowable, JValue, JValueOwned, ReleaseMode,
+        TypeArray, Weak,
     },
+    refs::RefType,
     signature::{FieldSignature, JavaType, MethodSignature, Primitive},
     strings::{JNIStr, MUTF8Chars},
     sys::{
@@ -367,14 +369,17 @@ mod test {
 }
 
 impl<'local> Env<'local> {
-    /// Returns an `UnsupportedVersion` error if the current JNI version is
+    /// Returns an `UnsupportedJniVersion` error if the current JNI version is
     /// lower than the one given.
     ///
     /// Returns `JavaException` if called while there is a pending exception.
-    #[allow(unused)]
-    fn ensure_version(&self, version: JNIVersion) -> Result<()> {
-        if self.version()? < version {
-            Err(Error::UnsupportedVersion)
+    pub(crate) fn ensure_version(&self, version: JNIVersion) -> Result<()> {
+        let actual = self.version()?;
+        if actual < version {
+            Err(Error::UnsupportedJniVersion {
+                required: version,
+                actual,
+            })
         } else {
             Ok(())
         }
@@ -844,6 +849,35 @@ See the jni-rs Env documentation for more details.
         Ok((!superclass.is_null()).then_some(superclass))
     }
 
+    /// Returns the `java.lang.Module` that a class is a member of (via `GetModule`).
+    ///
+    /// If the class is not in a named module then the unnamed module of the class's
+    /// `ClassLoader` is returned. As with [Self::find_class], takes a descriptor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedJniVersion`] if the JNI version is lower than 9.
+    ///
+    /// Returns [`Error::NullPtr`] if `class` is `null`.
+    pub fn get_module<'other_local, T>(&mut self, class: T) -> Result<JModule<'local>>
+    where
+        T: Desc<'local, JClass<'other_local>>,
+    {
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        self.assert_top();
+        self.ensure_version(JNIVersion::V9)?;
+        let class = class.lookup(self)?;
+        let class = null_check!(class.as_ref(), "get_module class argument")?;
+        // Safety:
+        // - GetModule is a 9.0 API that we have checked is available
+        // - we have checked that `class` is not null
+        unsafe {
+            let module = jni_call_only_check_null_ret!(self, v9, GetModule, class.as_raw())?;
+            Ok(JModule::from_raw(self, module))
+        }
+    }
+
     // Like is_assignable_from but it doesn't need a mutable Env reference because it doesn't do any
     // descriptor lookups.
     fn is_assignable_from_class(&self, class1: &JClass, class2: &JClass) -> Result<bool> {
@@ -976,6 +1010,61 @@ See the jni-rs Env documentation for more details.
         }
     }
 
+    /// Returns the kind of JNI reference that `obj` is (via `GetObjectRefType`).
+    ///
+    /// This is mainly useful for debugging, to check what kind of reference a raw `jobject` really
+    /// is, such as before wrapping it via [`Self::global_from_raw`] or [`Self::weak_from_raw`].
+    ///
+    /// A `null` reference will return [`RefType::Invalid`].
+    ///
+    /// Note: a weak global reference will still be reported as [`RefType::WeakGlobal`] after the
+    /// object it refers to has been garbage collected.
+    ///
+    /// Returns [`Error::JavaException`] if called while there is a pending Java exception
+    pub fn get_object_ref_type<'other_local, O>(&self, obj: O) -> Result<RefType>
+    where
+        O: AsRef<JObject<'other_local>>,
+    {
+        let obj = obj.as_ref();
+        if obj.is_null() {
+            return Ok(RefType::Invalid);
+        }
+        // Safety: GetObjectRefType is a 1.6 API that must be valid
+        let ref_type =
+            unsafe { jni_call_no_post_check_ex!(self, v1_6, GetObjectRefType, obj.as_raw())? };
+        Ok(ref_type.into())
+    }
+
+    /// Returns `true` if `thread` is a virtual thread (via `IsVirtualThread`).
+    ///
+    /// `thread` should be a `java.lang.Thread`. Any other object, or `null`, will return `false`.
+    ///
+    /// Combined with [`JThread::current_thread`], this can be used to check if native code is
+    /// running on a virtual thread, where blocking (for example while holding a
+    /// [`MonitorGuard`]) may pin the carrier thread. See also
+    /// [`JThread::is_virtual`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedJniVersion`] if the JNI version is lower than 21.
+    ///
+    /// Returns [`Error::JavaException`] if called while there is a pending Java exception
+    pub fn is_virtual_thread<'other_local, O>(&self, thread: O) -> Result<bool>
+    where
+        O: AsRef<JObject<'other_local>>,
+    {
+        self.ensure_version(JNIVersion::V21)?;
+        let thread = thread.as_ref();
+        if thread.is_null() {
+            return Ok(false);
+        }
+        // Safety: IsVirtualThread is a 21 API that we have checked is available (it was
+        // first added as a preview API in JNI 19, which is why it's part of the `v19` table)
+        let is_virtual =
+            unsafe { jni_call_no_post_check_ex!(self, v19, IsVirtualThread, thread.as_raw())? };
+        Ok(is_virtual)
+    }
+
     // FIXME: this API shouldn't need a `&mut self` reference since it doesn't return a local reference
     // (currently it just needs the `&mut self` for the sake of `Desc<JThrowable>::lookup`)
     //
@@ -3842,6 +3931,182 @@ See the jni-rs Env documentation for more details.
         }
     }
 
+    /// Convert a `java.lang.reflect.Method` or `java.lang.reflect.Constructor` into a
+    /// [`JMethodID`].
+    ///
+    /// This is the inverse of [`Self::to_reflected_method`] and can be used to cache the IDs of
+    /// methods that have been discovered via Java reflection.
+    ///
+    /// Use [`Self::from_reflected_static_method`] for static methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongObjectType`] if `method` represents a static method.
+    ///
+    /// Returns [`Error::NullPtr`] if `method` is `null`.
+    pub fn from_reflected_method<'other_local>(
+        &mut self,
+        method: impl AsRef<JExecutable<'other_local>>,
+    ) -> Result<JMethodID> {
+        let method = method.as_ref();
+        null_check!(method, "from_reflected_method method argument")?;
+        if method.is_static(self)? {
+            return Err(Error::WrongObjectType);
+        }
+
+        // Safety: we have checked that `method` is a non-null `Executable` that's not static
+        unsafe {
+            jni_call_post_check_ex_and_null_ret!(self, v1_2, FromReflectedMethod, method.as_raw())
+                .map(|method_id| JMethodID::from_raw(method_id))
+        }
+    }
+
+    /// Convert a static `java.lang.reflect.Method` into a [`JStaticMethodID`].
+    ///
+    /// This is the inverse of [`Self::to_reflected_static_method`] and can be used to cache the
+    /// IDs of static methods that have been discovered via Java reflection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongObjectType`] if `method` doesn't represent a static method.
+    ///
+    /// Returns [`Error::NullPtr`] if `method` is `null`.
+    pub fn from_reflected_static_method<'other_local>(
+        &mut self,
+        method: impl AsRef<JExecutable<'other_local>>,
+    ) -> Result<JStaticMethodID> {
+        let method = method.as_ref();
+        null_check!(method, "from_reflected_static_method method argument")?;
+        if !method.is_static(self)? {
+            return Err(Error::WrongObjectType);
+        }
+
+        // Safety: we have checked that `method` is a non-null, static `Executable`
+        unsafe {
+            jni_call_post_check_ex_and_null_ret!(self, v1_2, FromReflectedMethod, method.as_raw())
+                .map(|method_id| JStaticMethodID::from_raw(method_id))
+        }
+    }
+
+    /// Convert a [`JFieldID`] into a [`JField`] with the corresponding
+    /// `java.lang.reflect.Field` instance.
+    pub fn to_reflected_field<'other_local>(
+        &mut self,
+        class: impl Desc<'local, JClass<'other_local>>,
+        field_id: impl Desc<'local, JFieldID>,
+    ) -> Result<JField<'local>> {
+        // Safety: Rust type safety ensures that field_id is a JFieldID, while is_static is false
+        unsafe { self.to_reflected_field_base(class, field_id, JFieldID::into_raw, false) }
+    }
+
+    /// Convert a [`JStaticFieldID`] into a [`JField`] with the corresponding
+    /// `java.lang.reflect.Field` instance.
+    pub fn to_reflected_static_field<'other_local>(
+        &mut self,
+        class: impl Desc<'local, JClass<'other_local>>,
+        field_id: impl Desc<'local, JStaticFieldID>,
+    ) -> Result<JField<'local>> {
+        // Safety: Rust type safety ensures that field_id is a JStaticFieldID, while is_static is true
+        unsafe { self.to_reflected_field_base(class, field_id, JStaticFieldID::into_raw, true) }
+    }
+
+    /// Convert a [`JFieldID`] or [`JStaticFieldID`] into a [`JField`] with the
+    /// corresponding `java.lang.reflect.Field` instance.
+    ///
+    /// The `to_jfieldid` function is used to convert the field ID type into
+    /// a raw [`sys::jfieldID`].
+    ///
+    /// # Safety
+    ///
+    /// `is_static` must correctly indicate whether the field ID is for a static field. (The JNI
+    /// spec does not define what happens if this is incorrect.)
+    #[allow(clippy::wrong_self_convention)]
+    unsafe fn to_reflected_field_base<'other_local, F>(
+        &mut self,
+        class: impl Desc<'local, JClass<'other_local>>,
+        field_id: impl Desc<'local, F>,
+        to_jfieldid: impl FnOnce(F) -> crate::sys::jfieldID,
+        is_static: bool,
+    ) -> Result<JField<'local>>
+    where
+        F: Copy,
+    {
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        self.assert_top();
+        let class = class.lookup(self)?;
+
+        let field_id = to_jfieldid(*field_id.lookup(self)?.as_ref());
+
+        unsafe {
+            jni_call_post_check_ex_and_null_ret!(
+                self,
+                v1_2,
+                ToReflectedField,
+                class.as_ref().as_raw(),
+                field_id,
+                is_static
+            )
+            .map(|jobject| JField::from_raw(self, jobject))
+        }
+    }
+
+    /// Convert a `java.lang.reflect.Field` into a [`JFieldID`].
+    ///
+    /// This is the inverse of [`Self::to_reflected_field`] and can be used to cache the IDs of
+    /// fields that have been discovered via Java reflection.
+    ///
+    /// Use [`Self::from_reflected_static_field`] for static fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongObjectType`] if `field` represents a static field.
+    ///
+    /// Returns [`Error::NullPtr`] if `field` is `null`.
+    pub fn from_reflected_field<'other_local>(
+        &mut self,
+        field: impl AsRef<JField<'other_local>>,
+    ) -> Result<JFieldID> {
+        let field = field.as_ref();
+        null_check!(field, "from_reflected_field field argument")?;
+        if field.is_static(self)? {
+            return Err(Error::WrongObjectType);
+        }
+
+        // Safety: we have checked that `field` is a non-null `Field` that's not static
+        unsafe {
+            jni_call_post_check_ex_and_null_ret!(self, v1_2, FromReflectedField, field.as_raw())
+                .map(|field_id| JFieldID::from_raw(field_id))
+        }
+    }
+
+    /// Convert a static `java.lang.reflect.Field` into a [`JStaticFieldID`].
+    ///
+    /// This is the inverse of [`Self::to_reflected_static_field`] and can be used to cache the
+    /// IDs of static fields that have been discovered via Java reflection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongObjectType`] if `field` doesn't represent a static field.
+    ///
+    /// Returns [`Error::NullPtr`] if `field` is `null`.
+    pub fn from_reflected_static_field<'other_local>(
+        &mut self,
+        field: impl AsRef<JField<'other_local>>,
+    ) -> Result<JStaticFieldID> {
+        let field = field.as_ref();
+        null_check!(field, "from_reflected_static_field field argument")?;
+        if !field.is_static(self)? {
+            return Err(Error::WrongObjectType);
+        }
+
+        // Safety: we have checked that `field` is a non-null, static `Field`
+        unsafe {
+            jni_call_post_check_ex_and_null_ret!(self, v1_2, FromReflectedField, field.as_raw())
+                .map(|field_id| JStaticFieldID::from_raw(field_id))
+        }
+    }
+
     /// Get a field without checking the provided type against the actual field.
     ///
     /// # Safety
@@ -4190,7 +4455,7 @@ See the jni-rs Env documentation for more details.
     /// Looks up the field ID for the given field name and takes the monitor
     /// lock on the given object so the field can be updated without racing
     /// with other Java threads
-    fn lock_rust_field<'other_local, O, S>(
+    pub(crate) fn lock_rust_field<'other_local, O, S>(
         &self,
         obj: O,
         field: S,
@@ -4256,6 +4521,9 @@ See the jni-rs Env documentation for more details.
     /// cleaned up later is for the Java object to implement `Closeable` and let
     /// people use a `use` block (Kotlin) or `try-with-resources` (Java).
     ///
+    /// Alternatively, [`RustHandle::set_field`](crate::handle::RustHandle::set_field)
+    /// also drops the Rust value once the Java object becomes unreachable.
+    ///
     /// **DO NOT** make a copy of the handle stored in one of these fields
     /// since that could lead to a use-after-free error if the Rust type is
     /// taken and dropped multiple times from Rust. If you need to copy an
@@ -4,6 +4,7 @@ use std::char::{CharTryFromError, DecodeUtf16Error};
 
 use thiserror::Error;
 
+use crate::JNIVersion;
 use crate::signature::RuntimeMethodSignature;
 use crate::sys;
 
@@ -93,6 +94,25 @@ pub enum Error {
         requested: String,
         cause: Option<jni::refs::Global<jni::objects::JThrowable<'static>>>,
     },
+    /// A class was found, but its module does not export the class's package to the module that
+    /// needs to access it.
+    ///
+    /// Accessing such a class (or its members) from Java code would fail with an
+    /// `IllegalAccessError`.
+    ///
+    /// `module` and `caller` are descriptions of the modules, as returned by
+    /// `java.lang.Module#toString()` (such as `"module java.base"`).
+    ///
+    /// See [`jni::refs::LoaderContext::load_exported_class`]
+    #[error(
+        "Java class '{requested}' was found in {module}, but package '{package}' is not exported to {caller}"
+    )]
+    ClassNotExported {
+        requested: String,
+        package: String,
+        module: String,
+        caller: String,
+    },
     #[error("Method not found: {name} {sig}")]
     MethodNotFound { name: String, sig: String },
     /// Represents a `NoSuchMethodError` exception
@@ -126,6 +146,11 @@ pub enum Error {
     ParseFailed(String),
     #[error("JNI call failed")]
     JniCall(#[source] JniError),
+    /// A JVMTI function returned an error code
+    ///
+    /// See [`crate::jvmti::JvmtiEnv`]
+    #[error("JVMTI call failed")]
+    JvmtiCall(#[source] JvmtiError),
 
     /// [`JValue::c_char`] or [`JValueOwned::c_char`] was used, and although the value does indeed contain a Java `char`, it is part of a UTF-16 [surrogate pair] and cannot be converted to a Rust `char` by itself.
     ///
@@ -153,6 +178,18 @@ pub enum Error {
     #[error("This Java virtual machine is too old; at least Java 1.4 is required")]
     UnsupportedVersion,
 
+    /// An API was used that requires a newer JNI version than the JVM supports.
+    ///
+    /// For example, `IsVirtualThread` requires JNI >= 21.
+    #[error(
+        "This JNI API requires JNI version {}.{} but the JVM only supports version {}.{}",
+        required.major(), required.minor(), actual.major(), actual.minor()
+    )]
+    UnsupportedJniVersion {
+        required: JNIVersion,
+        actual: JNIVersion,
+    },
+
     #[error("The thread can't be detached while AttachGuards exist")]
     ThreadAttachmentGuarded,
 
@@ -204,6 +241,81 @@ pub enum JniError {
     Other(sys::jint),
 }
 
+/// An error code returned by a JVMTI function
+///
+/// Note: this only has variants for the errors that are most likely to be seen via the
+/// [`crate::jvmti::JvmtiEnv`] API, and any other error code is represented as
+/// [`JvmtiError::Other`]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum JvmtiError {
+    #[error("Invalid thread")]
+    InvalidThread,
+    #[error("Thread is not alive")]
+    ThreadNotAlive,
+    #[error("Invalid object")]
+    InvalidObject,
+    #[error("Invalid class")]
+    InvalidClass,
+    #[error("Class is not prepared")]
+    ClassNotPrepared,
+    #[error("Invalid method ID")]
+    InvalidMethodId,
+    #[error("Invalid field ID")]
+    InvalidFieldId,
+    #[error("No more frames")]
+    NoMoreFrames,
+    #[error("Information is not available")]
+    AbsentInformation,
+    #[error("Functionality is not available in this virtual machine")]
+    NotAvailable,
+    #[error("The environment does not possess the required capability")]
+    MustPossessCapability,
+    #[error("Unexpected null pointer")]
+    NullPointer,
+    #[error("Illegal argument")]
+    IllegalArgument,
+    #[error("Out of memory")]
+    OutOfMemory,
+    #[error("The virtual machine is in the wrong phase for this function")]
+    WrongPhase,
+    #[error("The current thread is not attached to the virtual machine")]
+    UnattachedThread,
+    #[error("The JVMTI environment is no longer valid")]
+    InvalidEnvironment,
+    #[error("Unexpected internal error")]
+    Internal,
+    #[error("JVMTI error code {0}")]
+    Other(crate::jvmti::sys::jvmtiError),
+}
+
+pub(crate) fn jvmti_error_code_to_result(code: crate::jvmti::sys::jvmtiError) -> Result<()> {
+    use crate::jvmti::sys::*;
+    match code {
+        JVMTI_ERROR_NONE => Ok(()),
+        JVMTI_ERROR_INVALID_THREAD => Err(JvmtiError::InvalidThread),
+        JVMTI_ERROR_THREAD_NOT_ALIVE => Err(JvmtiError::ThreadNotAlive),
+        JVMTI_ERROR_INVALID_OBJECT => Err(JvmtiError::InvalidObject),
+        JVMTI_ERROR_INVALID_CLASS => Err(JvmtiError::InvalidClass),
+        JVMTI_ERROR_CLASS_NOT_PREPARED => Err(JvmtiError::ClassNotPrepared),
+        JVMTI_ERROR_INVALID_METHODID => Err(JvmtiError::InvalidMethodId),
+        JVMTI_ERROR_INVALID_FIELDID => Err(JvmtiError::InvalidFieldId),
+        JVMTI_ERROR_NO_MORE_FRAMES => Err(JvmtiError::NoMoreFrames),
+        JVMTI_ERROR_ABSENT_INFORMATION => Err(JvmtiError::AbsentInformation),
+        JVMTI_ERROR_NOT_AVAILABLE => Err(JvmtiError::NotAvailable),
+        JVMTI_ERROR_MUST_POSSESS_CAPABILITY => Err(JvmtiError::MustPossessCapability),
+        JVMTI_ERROR_NULL_POINTER => Err(JvmtiError::NullPointer),
+        JVMTI_ERROR_ILLEGAL_ARGUMENT => Err(JvmtiError::IllegalArgument),
+        JVMTI_ERROR_OUT_OF_MEMORY => Err(JvmtiError::OutOfMemory),
+        JVMTI_ERROR_WRONG_PHASE => Err(JvmtiError::WrongPhase),
+        JVMTI_ERROR_UNATTACHED_THREAD => Err(JvmtiError::UnattachedThread),
+        JVMTI_ERROR_INVALID_ENVIRONMENT => Err(JvmtiError::InvalidEnvironment),
+        JVMTI_ERROR_INTERNAL => Err(JvmtiError::Internal),
+        _ => Err(JvmtiError::Other(code)),
+    }
+    .map_err(Error::JvmtiCall)
+}
+
 impl<T> From<::std::sync::TryLockError<T>> for Error {
     fn from(_: ::std::sync::TryLockError<T>) -> Self {
         Error::TryLock
@@ -281,7 +393,35 @@ pub enum StartJvmError {
     #[error("Couldn't load the Java VM shared library ({0}): {1}")]
     LoadError(String, String),
 
-    /// The JNI function `JNI_CreateJavaVM` returned an error.
+    /// A [`JvmLocator`] was given a version requirement that couldn't be parsed.
+    ///
+    /// [`JvmLocator`]: crate::JvmLocator
+    #[cfg(not(target_os = "android"))]
+    #[error("Invalid Java version requirement: {0:?}")]
+    InvalidVersionRequirement(String),
+
+    /// A [`JvmLocator`] couldn't find a Java installation that satisfies its requirements.
+    ///
+    /// [`JvmLocator`]: crate::JvmLocator
+    #[cfg(not(target_os = "android"))]
+    #[error(
+        "Couldn't find a Java installation matching the requirements ({requirements}): {}",
+        describe_rejected_jdks(rejected)
+    )]
+    #[non_exhaustive]
+    NoMatchingJdk {
+        /// A description of the requirements.
+        requirements: String,
+        /// A description of each Java installation that was found, and why it was rejected.
+        rejected: Vec<String>,
+    },
+
+    /// The JVM shared library wasn't found within a Java installation.
+    #[cfg(not(target_os = "android"))]
+    #[error("Couldn't find the Java VM shared library in the Java installation at {}", .0.display())]
+    LibjvmNotFound(std::path::PathBuf),
+
+    /// The JNI function `JNI_CreateJavaVM` (or `JNI_GetCreatedJavaVMs`) returned an error.
     #[error("{0}")]
     Create(
         #[from]
@@ -290,6 +430,15 @@ pub enum StartJvmError {
     ),
 }
 
+#[cfg(all(feature = "invocation", not(target_os = "android")))]
+fn describe_rejected_jdks(rejected: &[String]) -> String {
+    if rejected.is_empty() {
+        "no Java installations were found".to_string()
+    } else {
+        format!("rejected {}", rejected.join("; "))
+    }
+}
+
 #[cfg(feature = "invocation")]
 pub type StartJvmResult<T> = std::result::Result<T, StartJvmError>;
 
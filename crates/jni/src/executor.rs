@@ -0,0 +1,338 @@
+//! A pool of threads that are permanently attached to the Java VM, for running jobs that need to
+//! use JNI.
+//!
+//! Attaching and detaching threads is relatively expensive, so code that frequently needs to call
+//! into Java from arbitrary (unattached) threads can instead dispatch jobs to an
+//! [`AttachedThreadPool`](crate::executor::AttachedThreadPool).
+
+use std::{
+    cell::OnceCell,
+    fmt,
+    panic::{AssertUnwindSafe, catch_unwind, resume_unwind},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+};
+
+use crate::{
+    AttachConfig, DEFAULT_LOCAL_FRAME_CAPACITY, Env, JavaVM,
+    errors::Error,
+    objects::{Global, JClassLoader, JObject, JThread},
+    strings::JNIString,
+};
+
+type Job = Box<dyn FnOnce(&JavaVM, usize) + Send>;
+
+/// Builder for an [`AttachedThreadPool`]
+#[derive(Debug)]
+pub struct AttachedThreadPoolBuilder {
+    threads: usize,
+    thread_name: String,
+    daemon: bool,
+    group: Option<Global<JObject<'static>>>,
+    context_class_loader: Option<Global<JClassLoader<'static>>>,
+    local_frame_capacity: usize,
+}
+
+impl AttachedThreadPoolBuilder {
+    /// Creates a new builder for a pool with the given number of worker `threads`.
+    ///
+    /// At least one thread is always created, even if `threads` is zero.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            thread_name: "jni-worker".to_string(),
+            daemon: false,
+            group: None,
+            context_class_loader: None,
+            local_frame_capacity: DEFAULT_LOCAL_FRAME_CAPACITY,
+        }
+    }
+
+    /// Sets the name prefix for the worker threads, as seen by Rust, the JVM and the operating
+    /// system.
+    ///
+    /// Each worker is named `<name>-<index>`.
+    ///
+    /// The default is `jni-worker`.
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = name.into();
+        self
+    }
+
+    /// Sets whether the worker threads are attached as daemon threads.
+    ///
+    /// The default is `false`, which means that [`JavaVM::destroy`] will wait for the pool to be
+    /// dropped. See [`AttachConfig::daemon`].
+    pub fn daemon(mut self, daemon: bool) -> Self {
+        self.daemon = daemon;
+        self
+    }
+
+    /// Sets a `ThreadGroup` that the worker threads are associated with.
+    ///
+    /// See [`AttachConfig::group`].
+    pub fn group(mut self, group: Global<JObject<'static>>) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Sets the context class loader for the worker threads.
+    ///
+    /// By default, the context class loader is left as whatever the JVM assigns to newly attached
+    /// threads (normally the system class loader).
+    pub fn context_class_loader(mut self, loader: Global<JClassLoader<'static>>) -> Self {
+        self.context_class_loader = Some(loader);
+        self
+    }
+
+    /// Sets the capacity of the local frame that's created for each job.
+    ///
+    /// The default is [`DEFAULT_LOCAL_FRAME_CAPACITY`].
+    pub fn local_frame_capacity(mut self, capacity: usize) -> Self {
+        self.local_frame_capacity = capacity;
+        self
+    }
+
+    /// Spawns the worker threads and attaches them to the given Java VM.
+    ///
+    /// Threads are attached asynchronously, and if a worker fails to apply its configuration
+    /// (for example to set its context class loader) then the error is logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a worker thread couldn't be spawned.
+    pub fn build(self, vm: &JavaVM) -> std::io::Result<AttachedThreadPool> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let config = Arc::new(WorkerConfig {
+            daemon: self.daemon,
+            group: self.group,
+            context_class_loader: self.context_class_loader,
+            local_frame_capacity: self.local_frame_capacity,
+        });
+
+        let mut workers = Vec::with_capacity(self.threads);
+        for index in 0..self.threads {
+            let name = format!("{}-{index}", self.thread_name);
+            let vm = vm.clone();
+            let config = config.clone();
+            let receiver = receiver.clone();
+            let worker = thread::Builder::new()
+                .name(name.clone())
+                .spawn(move || worker_main(vm, &name, &config, &receiver))?;
+            workers.push(worker);
+        }
+
+        Ok(AttachedThreadPool {
+            sender: Some(sender),
+            workers,
+        })
+    }
+}
+
+/// The configuration that's shared by all the workers in a pool
+struct WorkerConfig {
+    daemon: bool,
+    group: Option<Global<JObject<'static>>>,
+    context_class_loader: Option<Global<JClassLoader<'static>>>,
+    local_frame_capacity: usize,
+}
+
+fn worker_main(vm: JavaVM, name: &str, config: &WorkerConfig, receiver: &Mutex<Receiver<Job>>) {
+    // Permanently attach the thread, so that the attachment is re-used for every job
+    let thread_name = JNIString::from(name);
+    let res = vm.attach_current_thread_with_config(
+        || {
+            let attach_config = AttachConfig::new()
+                .thread_name(&thread_name)
+                .daemon(config.daemon);
+            match &config.group {
+                Some(group) => attach_config.group(group),
+                None => attach_config,
+            }
+        },
+        Some(DEFAULT_LOCAL_FRAME_CAPACITY),
+        |env| -> crate::errors::Result<()> {
+            if let Some(loader) = &config.context_class_loader {
+                let thread = JThread::current_thread(env)?;
+                thread.set_context_class_loader(env, loader)?;
+            }
+            Ok(())
+        },
+    );
+    if let Err(err) = res {
+        log::error!("Failed to initialize JNI worker thread {name}: {err}");
+    }
+
+    loop {
+        // Only hold the lock while waiting for the next job
+        let job = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(poisoned) => poisoned.into_inner().recv(),
+        };
+        match job {
+            Ok(job) => job(&vm, config.local_frame_capacity),
+            // The pool has been dropped and there are no more queued jobs
+            Err(_) => break,
+        }
+    }
+}
+
+/// A pool of threads that are permanently attached to the Java VM
+///
+/// Jobs are closures that are given an [`Env`] reference for the worker thread they run on. Each
+/// job runs inside a new local frame, so any local references it creates are deleted when the
+/// job returns.
+///
+/// Jobs have the same exception handling as [`JavaVM::attach_current_thread`], which means that
+/// any Java exception that's left pending by a job is caught and returned as an
+/// [`Error::CaughtJavaException`] error.
+///
+/// Dropping the pool will wait for any queued jobs to finish, and then the worker threads will
+/// detach from the JVM and exit.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{JavaVM, executor::AttachedThreadPoolBuilder, objects::JString};
+/// # fn example(vm: &JavaVM) -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = AttachedThreadPoolBuilder::new(4)
+///     .thread_name("java-calls")
+///     .build(vm)?;
+///
+/// let handle = pool.spawn(|env| -> jni::errors::Result<String> {
+///     let s = JString::from_str(env, "hello")?;
+///     s.try_to_string(env)
+/// });
+/// assert_eq!(handle.join()?, "hello");
+/// # Ok(())
+/// # }
+/// ```
+pub struct AttachedThreadPool {
+    sender: Option<Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl fmt::Debug for AttachedThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachedThreadPool")
+            .field("threads", &self.workers.len())
+            .finish()
+    }
+}
+
+impl AttachedThreadPool {
+    /// Creates a pool with the given number of worker `threads`, with default configuration.
+    ///
+    /// See [`AttachedThreadPoolBuilder`] for configuring the workers.
+    pub fn new(vm: &JavaVM, threads: usize) -> std::io::Result<Self> {
+        AttachedThreadPoolBuilder::new(threads).build(vm)
+    }
+
+    /// Returns the number of worker threads.
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Queues a job to run on one of the worker threads, returning a [`JobHandle`] that can be
+    /// used to wait for its result.
+    ///
+    /// The job runs within a new local frame (see
+    /// [`AttachedThreadPoolBuilder::local_frame_capacity`]).
+    ///
+    /// If the job panics, the panic is caught so the worker can continue running other jobs, and
+    /// it's resumed when calling [`JobHandle::join`].
+    pub fn spawn<F, T, E>(&self, job: F) -> JobHandle<T, E>
+    where
+        F: FnOnce(&mut Env) -> std::result::Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: From<Error> + Send + 'static,
+    {
+        let (result_sender, result_receiver) = mpsc::sync_channel(1);
+        let job: Job = Box::new(move |vm: &JavaVM, capacity: usize| {
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                // The thread is already attached, so this won't re-attach, and just creates a new
+                // local frame and catches any pending exception
+                vm.attach_current_thread_with_config(AttachConfig::default, Some(capacity), job)
+            }));
+            // The handle may have been dropped, if the caller isn't interested in the result
+            let _ = result_sender.send(result);
+        });
+
+        // Note: the sender is only taken when the pool is dropped
+        if let Some(sender) = &self.sender {
+            // This can't fail while the pool is alive, since the workers don't exit until the
+            // sender is dropped
+            let _ = sender.send(job);
+        }
+
+        JobHandle {
+            receiver: result_receiver,
+            received: OnceCell::new(),
+        }
+    }
+}
+
+impl Drop for AttachedThreadPool {
+    fn drop(&mut self) {
+        // Closing the channel lets each worker exit after the queued jobs have finished
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// A handle for waiting on the result of a job that was spawned on an [`AttachedThreadPool`]
+///
+/// Dropping the handle doesn't cancel the job.
+#[derive(Debug)]
+pub struct JobHandle<T, E> {
+    receiver: Receiver<JobResult<T, E>>,
+    // A result that was received by `is_finished`
+    received: OnceCell<JobResult<T, E>>,
+}
+
+type JobResult<T, E> = thread::Result<std::result::Result<T, E>>;
+
+impl<T, E> JobHandle<T, E> {
+    /// Waits for the job to finish and returns its result.
+    ///
+    /// # Panics
+    ///
+    /// If the job panicked then the panic is resumed on the calling thread.
+    pub fn join(self) -> std::result::Result<T, E> {
+        let result = match self.received.into_inner() {
+            Some(result) => result,
+            None => self
+                .receiver
+                .recv()
+                .expect("AttachedThreadPool job was dropped without running"),
+        };
+        match result {
+            Ok(result) => result,
+            Err(payload) => resume_unwind(payload),
+        }
+    }
+
+    /// Returns `true` if the job has finished running.
+    ///
+    /// If this returns `true` then [`JobHandle::join`] won't block.
+    pub fn is_finished(&self) -> bool {
+        if self.received.get().is_some() {
+            return true;
+        }
+        match self.receiver.try_recv() {
+            Ok(result) => {
+                let _ = self.received.set(result);
+                true
+            }
+            Err(mpsc::TryRecvError::Empty) => false,
+            Err(mpsc::TryRecvError::Disconnected) => true,
+        }
+    }
+}
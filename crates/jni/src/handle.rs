@@ -0,0 +1,287 @@
+//! Rust values that are owned by Java objects, and are dropped automatically once their owner
+//! becomes unreachable.
+//!
+//! [`Env::set_rust_field`] leaks the Rust value unless [`Env::take_rust_field`] is called, which
+//! typically relies on Java code remembering to call something like `close()`. A
+//! [`RustHandle`](crate::handle::RustHandle) is instead registered with a `java.lang.ref.Cleaner`
+//! (shared by all handles) that drops the Rust value once the owning Java object has been garbage
+//! collected, unless it was already taken back explicitly.
+//!
+//! # Portability Note
+//!
+//! The cleaning action is implemented with a Java class that's defined at runtime via
+//! [`Env::define_class`] (which isn't supported on Android), and `java.lang.ref.Cleaner` requires
+//! Java 9 or later.
+
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::{
+    Env,
+    errors::{Error, Result},
+    native_classes::{RustCleanup, RustCleanupAPI, box_drop_fn},
+    objects::JObject,
+    refs::LoaderContext,
+    signature::{Primitive, ReturnType},
+    strings::JNIStr,
+    sys::jlong,
+};
+
+/// A Rust value that's owned by a Java object, and is dropped (on the cleaner thread) once that
+/// object becomes unreachable
+///
+/// A handle is created with [`RustHandle::register`], which returns a raw `jlong` handle that can
+/// be stored by Java code (e.g. in a `private final long` field) and passed back to native
+/// methods, or with [`RustHandle::set_field`] which stores the handle in a `long` field of the
+/// owner.
+///
+/// The value can be taken back explicitly with [`RustHandle::take`] (e.g. from a `close()`
+/// method), in which case the cleaner only releases the (empty) allocation for the handle. Either
+/// way the value is dropped exactly once.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, handle::RustHandle, jni_str, objects::JObject};
+/// struct Connection {
+///     // ...
+/// }
+///
+/// # fn example<'local>(env: &mut Env<'local>, this: JObject<'local>) -> Result<()> {
+/// // Stored in `private long nativeHandle;`
+/// unsafe { RustHandle::set_field(env, &this, jni_str!("nativeHandle"), Connection {})? };
+///
+/// {
+///     let connection = unsafe {
+///         RustHandle::<Connection>::lock_field(env, &this, jni_str!("nativeHandle"))?
+///     };
+///     // ...
+/// }
+///
+/// // From `close()` - otherwise the connection is dropped once `this` is garbage collected
+/// let connection: Connection =
+///     unsafe { RustHandle::take_field(env, &this, jni_str!("nativeHandle"))? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RustHandle<T> {
+    value: Mutex<Option<T>>,
+}
+
+impl<T: Send + 'static> RustHandle<T> {
+    /// Gives ownership of `value` to the `owner` object, and returns a raw handle for it.
+    ///
+    /// The value will be dropped once `owner` becomes unreachable, unless it's taken back first via
+    /// [`RustHandle::take`].
+    pub fn register<'other_local, O>(env: &mut Env<'_>, owner: O, value: T) -> Result<jlong>
+    where
+        O: AsRef<JObject<'other_local>>,
+    {
+        RustCleanupAPI::get(env, &LoaderContext::None)?;
+
+        let handle = Box::into_raw(Box::new(RustHandle {
+            value: Mutex::new(Some(value)),
+        }));
+        let registered = RustCleanup::register(
+            env,
+            owner.as_ref(),
+            handle as jlong,
+            box_drop_fn::<RustHandle<T>>(),
+        );
+        if let Err(err) = registered {
+            // Safety: the cleaning action wasn't registered, so the handle won't be released by
+            // Java
+            drop(unsafe { Box::from_raw(handle) });
+            return Err(err);
+        }
+        Ok(handle as jlong)
+    }
+
+    /// Borrows the handle for a raw `handle` that was returned by [`RustHandle::register`].
+    ///
+    /// Returns [`Error::NullPtr`] if the `handle` is zero.
+    ///
+    /// # Safety
+    ///
+    /// The `handle` must have been returned by [`RustHandle::register`] for the same type `T`, and
+    /// the owner must stay reachable for the lifetime `'a`, since the handle is released once the
+    /// owner is unreachable. For example, an instance native method may borrow a handle that's
+    /// owned by `this` for the duration of the call.
+    pub unsafe fn from_raw<'a>(handle: jlong) -> Result<&'a RustHandle<T>> {
+        let ptr = handle as *const RustHandle<T>;
+        null_check!(ptr, "rust handle")?;
+        // Safety: the caller guarantees that the handle is valid for `'a`
+        Ok(unsafe { &*ptr })
+    }
+
+    /// Locks the value.
+    ///
+    /// Returns [`Error::NullPtr`] if the value has already been taken.
+    pub fn lock(&self) -> Result<RustHandleGuard<'_, T>> {
+        let guard = self.value.lock().unwrap_or_else(|e| e.into_inner());
+        if guard.is_none() {
+            return Err(Error::NullPtr("rust handle value"));
+        }
+        Ok(RustHandleGuard { guard })
+    }
+
+    /// Takes the value back from Java, so that it won't be dropped by the cleaner.
+    ///
+    /// Returns [`Error::TryLock`] if the value is currently locked, or [`Error::NullPtr`] if it has
+    /// already been taken.
+    pub fn take(&self) -> Result<T> {
+        let mut guard = match self.value.try_lock() {
+            Ok(guard) => guard,
+            Err(std::sync::TryLockError::Poisoned(err)) => err.into_inner(),
+            Err(std::sync::TryLockError::WouldBlock) => return Err(Error::TryLock),
+        };
+        guard.take().ok_or(Error::NullPtr("rust handle value"))
+    }
+
+    /// Gives ownership of `value` to `obj`, and stores its handle in the given `long` field.
+    ///
+    /// This is like [`Env::set_rust_field`], except that the value is dropped once `obj`
+    /// becomes unreachable, unless it's taken back first via [`RustHandle::take_field`].
+    ///
+    /// The Java object will be locked while changing the field value.
+    ///
+    /// Returns [`Error::FieldAlreadySet`] if the field is non-zero.
+    ///
+    /// # Safety
+    ///
+    /// This will lead to undefined behaviour if the specified field doesn't have a type of `long`.
+    ///
+    /// **DO NOT** make a copy of the handle stored in the field into another object, since the
+    /// handle is released once `obj` is unreachable.
+    pub unsafe fn set_field<'other_local, O, S>(
+        env: &mut Env<'_>,
+        obj: O,
+        field: S,
+        value: T,
+    ) -> Result<()>
+    where
+        O: AsRef<JObject<'other_local>>,
+        S: AsRef<JNIStr>,
+    {
+        let obj = obj.as_ref();
+        let (_guard, field_id) = env.lock_rust_field(obj, &field)?;
+
+        // It's OK that we don't push a new stack frame for the field accesses since we know we
+        // are dealing with a `jlong` field and we have already looked up the field ID.
+
+        // Safety: the requirement that the given field must be a `long` is documented in the
+        // 'Safety' section of this function
+        let current = unsafe {
+            env.get_field_unchecked(obj, field_id, ReturnType::Primitive(Primitive::Long))?
+                .j()?
+        };
+        if current != 0 {
+            return Err(Error::FieldAlreadySet(field.as_ref().to_str().into()));
+        }
+
+        let handle = Self::register(env, obj, value)?;
+
+        // Safety: the requirement that the given field must be a `long` is documented in the
+        // 'Safety' section of this function
+        unsafe { env.set_field_unchecked(obj, field_id, handle.into()) }
+    }
+
+    /// Locks the value whose handle is stored in the given `long` field of `obj`.
+    ///
+    /// The lock borrows `obj`, which keeps the owner reachable (and so the handle valid) while the
+    /// value is locked.
+    ///
+    /// Returns [`Error::NullPtr`] if the field is zero.
+    ///
+    /// # Safety
+    ///
+    /// This will lead to undefined behaviour if the specified field doesn't have a type of `long`.
+    ///
+    /// If the field contains a non-zero value then it is assumed to be a valid handle that was set
+    /// via [`RustHandle::set_field`] for the same type `T`, and will lead to undefined behaviour
+    /// if that is not true.
+    pub unsafe fn lock_field<'a, 'other_local, O, S>(
+        env: &mut Env<'_>,
+        obj: &'a O,
+        field: S,
+    ) -> Result<RustHandleGuard<'a, T>>
+    where
+        O: AsRef<JObject<'other_local>>,
+        S: AsRef<JNIStr>,
+    {
+        let handle = {
+            let (_guard, field_id) = env.lock_rust_field(obj, &field)?;
+            // Safety: the requirement that the given field must be a `long` is documented in the
+            // 'Safety' section of this function
+            unsafe {
+                env.get_field_unchecked(obj, field_id, ReturnType::Primitive(Primitive::Long))?
+                    .j()?
+            }
+        };
+        // Safety: the handle was set via `set_field`, and `obj` is borrowed for `'a`
+        let handle = unsafe { Self::from_raw(handle)? };
+        handle.lock()
+    }
+
+    /// Takes the value whose handle is stored in the given `long` field of `obj` back from Java,
+    /// and sets the field to zero.
+    ///
+    /// The Java object will be locked before taking the field value.
+    ///
+    /// Returns [`Error::NullPtr`] if the field is zero, or [`Error::TryLock`] if the value is
+    /// currently locked.
+    ///
+    /// # Safety
+    ///
+    /// This will lead to undefined behaviour if the specified field doesn't have a type of `long`.
+    ///
+    /// If the field contains a non-zero value then it is assumed to be a valid handle that was set
+    /// via [`RustHandle::set_field`] for the same type `T`, and will lead to undefined behaviour
+    /// if that is not true.
+    pub unsafe fn take_field<'other_local, O, S>(env: &mut Env<'_>, obj: O, field: S) -> Result<T>
+    where
+        O: AsRef<JObject<'other_local>>,
+        S: AsRef<JNIStr>,
+    {
+        let obj = obj.as_ref();
+        let (_guard, field_id) = env.lock_rust_field(obj, &field)?;
+
+        // Safety: the requirement that the given field must be a `long` is documented in the
+        // 'Safety' section of this function
+        let handle = unsafe {
+            env.get_field_unchecked(obj, field_id, ReturnType::Primitive(Primitive::Long))?
+                .j()?
+        };
+        // Safety: the handle was set via `set_field`, and `obj` is borrowed for this call
+        let value = unsafe { Self::from_raw(handle)? }.take()?;
+
+        // Safety: the requirement that the given field must be a `long` is documented in the
+        // 'Safety' section of this function
+        unsafe { env.set_field_unchecked(obj, field_id, (0 as jlong).into())? };
+        Ok(value)
+    }
+}
+
+/// A lock on the value of a [`RustHandle`]
+#[derive(Debug)]
+pub struct RustHandleGuard<'a, T> {
+    // Always `Some`, since `RustHandle::lock` checks that the value hasn't been taken
+    guard: MutexGuard<'a, Option<T>>,
+}
+
+impl<T> Deref for RustHandleGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for RustHandleGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.guard.as_mut().unwrap()
+    }
+}
@@ -5,10 +5,9 @@ use std::fmt::Debug;
 use log::trace;
 
 use crate::signature::JavaType;
-use crate::{errors::*, objects::JObject, signature::Primitive, sys::*};
-
-#[cfg(doc)]
-use crate::env::Env;
+use crate::{
+    convert::FromJava, env::Env, errors::*, objects::JObject, signature::Primitive, sys::*,
+};
 
 /// A Java owned local reference or primitive value.
 ///
@@ -122,6 +121,14 @@ impl<'local> JValueOwned<'local> {
         self.l()
     }
 
+    /// Converts the value into a Rust type, via its [`FromJava`] implementation.
+    ///
+    /// For example, a `java.util.List<String>` return value can be converted into a
+    /// `Vec<String>`. See the [`crate::convert`] module for the built-in conversions.
+    pub fn into_rust<T: FromJava<'local>>(self, env: &mut Env<'local>) -> Result<T> {
+        T::from_java(env, self)
+    }
+
     /// Try to unwrap to a boolean.
     ///
     /// See [`Self::into_bool`] as a more-ergonomic alias.
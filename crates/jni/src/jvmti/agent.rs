@@ -0,0 +1,258 @@
+use std::{
+    ffi::CStr,
+    os::raw::c_char,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use crate::{
+    EnvUnowned,
+    errors::Error,
+    sys::{self, jint},
+    vm::{JavaVM, log_entry_point_panic},
+};
+
+/// The options string that was passed to an agent
+///
+/// For an agent that's loaded via `-agentpath:<path>=<options>` (or `-agentlib:`) this is the
+/// `<options>` string, and for an agent that's dynamically attached this is the options string
+/// that was given to the attach API.
+///
+/// Options are conventionally formatted as a comma separated list of `key=value` pairs or flags,
+/// which can be iterated via [`AgentOptions::iter`] or queried via [`AgentOptions::get`].
+///
+/// See [`jni::agent`](crate::agent)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AgentOptions {
+    options: String,
+}
+
+impl AgentOptions {
+    /// Creates `AgentOptions` from an options string.
+    pub fn new(options: impl Into<String>) -> Self {
+        Self {
+            options: options.into(),
+        }
+    }
+
+    /// Creates `AgentOptions` from a raw options string, as passed to `Agent_OnLoad` or
+    /// `Agent_OnAttach`.
+    ///
+    /// A null pointer is treated as an empty string, and any invalid UTF-8 will be replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// # Safety
+    ///
+    /// `options` must be null or a valid pointer to a nul-terminated string.
+    pub unsafe fn from_raw(options: *const c_char) -> Self {
+        if options.is_null() {
+            Self::default()
+        } else {
+            // Note: the options string is passed through as-is from the command line (or attach
+            // API), and is not encoded as modified UTF-8
+            let options = unsafe { CStr::from_ptr(options) };
+            Self::new(options.to_string_lossy())
+        }
+    }
+
+    /// Returns the full options string.
+    pub fn as_str(&self) -> &str {
+        &self.options
+    }
+
+    /// Returns `true` if the options string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    /// Iterates over the comma separated options as `(key, value)` pairs.
+    ///
+    /// An option without an `=` is returned with a `None` value and empty options are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.options
+            .split(',')
+            .filter(|option| !option.is_empty())
+            .map(|option| match option.split_once('=') {
+                Some((key, value)) => (key, Some(value)),
+                None => (option, None),
+            })
+    }
+
+    /// Returns the value of the first option with the given `key`.
+    ///
+    /// Returns `Some("")` for an option that's given as a flag (without an `=`), and `None` if the
+    /// option isn't present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value.unwrap_or(""))
+    }
+
+    /// Returns `true` if an option with the given `key` is present.
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+impl std::fmt::Display for AgentOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.options)
+    }
+}
+
+/// The status that an agent returns from `Agent_OnLoad` or `Agent_OnAttach`
+///
+/// The [`Default`] status is [`AgentStatus::ERR`], so that an
+/// [`ErrorPolicy`](crate::errors::ErrorPolicy) that maps an error
+/// or panic to a default value will report a failure to the JVM.
+///
+/// See [`jni::agent`](crate::agent)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AgentStatus(jint);
+
+impl AgentStatus {
+    /// The agent was successfully initialized (`JNI_OK`)
+    pub const OK: Self = AgentStatus(sys::JNI_OK);
+    /// The agent failed to initialize (`JNI_ERR`)
+    ///
+    /// When returned from `Agent_OnLoad` this will cause the JVM to terminate.
+    pub const ERR: Self = AgentStatus(sys::JNI_ERR);
+
+    /// Returns the raw status code.
+    pub fn into_raw(self) -> jint {
+        self.0
+    }
+}
+
+impl Default for AgentStatus {
+    fn default() -> Self {
+        Self::ERR
+    }
+}
+
+/// Implements an `Agent_OnLoad` entry point.
+///
+/// This is used by code generated by the [`jni::agent`](crate::agent) attribute macro.
+///
+/// Since the JVM hasn't finished initializing while `Agent_OnLoad` is called, JNI isn't available
+/// and so no [`Env`](crate::Env) is passed to `f`. Any error or panic is logged before returning
+/// [`AgentStatus::ERR`].
+///
+/// # Safety
+///
+/// `vm` must be a valid `JavaVM` pointer and `options` must be null or a valid pointer to a
+/// nul-terminated string, as passed to `Agent_OnLoad`.
+#[doc(hidden)]
+pub unsafe fn __agent_on_load<F, E>(vm: *mut sys::JavaVM, options: *const c_char, f: F) -> jint
+where
+    F: FnOnce(&JavaVM, &AgentOptions) -> std::result::Result<(), E>,
+    E: std::fmt::Display,
+{
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let vm = unsafe { JavaVM::from_raw(vm) };
+        let options = unsafe { AgentOptions::from_raw(options) };
+        f(&vm, &options)
+    }));
+    match res {
+        Ok(Ok(())) => AgentStatus::OK,
+        Ok(Err(err)) => {
+            log::error!("Rust error in Agent_OnLoad: {err}");
+            AgentStatus::ERR
+        }
+        Err(payload) => {
+            log_entry_point_panic("Agent_OnLoad", payload);
+            AgentStatus::ERR
+        }
+    }
+    .into_raw()
+}
+
+/// Implements an `Agent_OnAttach` entry point.
+///
+/// This is used by code generated by the [`jni::agent`](crate::agent) attribute macro.
+///
+/// `f` is called with an [`EnvUnowned`] for the current thread, which the generated code upgrades
+/// to an [`Env`](crate::Env) via [`EnvUnowned::with_env`] before resolving the outcome into an
+/// [`AgentStatus`] via an [`ErrorPolicy`](crate::errors::ErrorPolicy), in the same way as for
+/// native methods.
+///
+/// Any panic outside of `with_env` is logged before returning [`AgentStatus::ERR`].
+///
+/// # Safety
+///
+/// `vm` must be a valid `JavaVM` pointer and `options` must be null or a valid pointer to a
+/// nul-terminated string, as passed to `Agent_OnAttach`.
+#[doc(hidden)]
+pub unsafe fn __agent_on_attach<F>(vm: *mut sys::JavaVM, options: *const c_char, f: F) -> jint
+where
+    F: for<'local> FnOnce(&JavaVM, EnvUnowned<'local>, &AgentOptions) -> AgentStatus,
+{
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let vm = unsafe { JavaVM::from_raw(vm) };
+        let options = unsafe { AgentOptions::from_raw(options) };
+
+        // The attach listener thread is always attached to the JVM while calling Agent_OnAttach
+        //
+        // Safety: the EnvUnowned is only used while Agent_OnAttach is running
+        let unowned_env = unsafe { vm.sys_get_env_unowned()? };
+        Ok::<_, Error>(f(&vm, unowned_env, &options))
+    }));
+    match res {
+        Ok(Ok(status)) => status,
+        Ok(Err(err)) => {
+            log::error!("Failed to get Env in Agent_OnAttach: {err}");
+            AgentStatus::ERR
+        }
+        Err(payload) => {
+            log_entry_point_panic("Agent_OnAttach", payload);
+            AgentStatus::ERR
+        }
+    }
+    .into_raw()
+}
+
+/// Implements an `Agent_OnUnload` entry point.
+///
+/// This is used by code generated by the [`jni::agent`](crate::agent) attribute macro.
+///
+/// Any panic is caught and logged.
+///
+/// # Safety
+///
+/// `vm` must be a valid `JavaVM` pointer, as passed to `Agent_OnUnload`.
+#[doc(hidden)]
+pub unsafe fn __agent_on_unload<F>(vm: *mut sys::JavaVM, f: F)
+where
+    F: FnOnce(&JavaVM),
+{
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let vm = unsafe { JavaVM::from_raw(vm) };
+        f(&vm)
+    }));
+    if let Err(payload) = res {
+        log_entry_point_panic("Agent_OnUnload", payload);
+    }
+}
+
+#[test]
+fn agent_options_iter() {
+    let options = AgentOptions::new("verbose,port=8000,,path=a=b,empty=");
+    assert_eq!(
+        options.iter().collect::<Vec<_>>(),
+        vec![
+            ("verbose", None),
+            ("port", Some("8000")),
+            ("path", Some("a=b")),
+            ("empty", Some("")),
+        ]
+    );
+    assert_eq!(options.get("verbose"), Some(""));
+    assert_eq!(options.get("port"), Some("8000"));
+    assert_eq!(options.get("missing"), None);
+    assert!(options.contains("empty"));
+    assert!(!options.contains("missing"));
+
+    let options = unsafe { AgentOptions::from_raw(std::ptr::null()) };
+    assert!(options.is_empty());
+    assert_eq!(options.iter().count(), 0);
+}
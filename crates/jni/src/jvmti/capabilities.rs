@@ -0,0 +1,149 @@
+use super::sys;
+
+/// A set of JVMTI capabilities
+///
+/// This is a safe wrapper for the [`sys::jvmtiCapabilities`] bitfield, with a getter and setter
+/// for each named capability.
+///
+/// For example, to add the capability to tag objects:
+///
+/// ```rust,no_run
+/// # use jni::jvmti::{Capabilities, JvmtiEnv};
+/// # fn f(jvmti: &JvmtiEnv) -> jni::errors::Result<()> {
+/// let mut caps = Capabilities::new();
+/// caps.set_can_tag_objects(true);
+/// jvmti.add_capabilities(&caps)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [JVMTI capabilities documentation] for details about each capability.
+///
+/// [JVMTI capabilities documentation]:
+///     https://docs.oracle.com/en/java/javase/21/docs/specs/jvmti.html#capability
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Capabilities {
+    raw: sys::jvmtiCapabilities,
+}
+
+/// Get the (word, mask) for a bit in a C bitfield of `unsigned int`s
+///
+/// Note: bitfields are allocated from the least significant bit on little-endian targets and
+/// from the most significant bit on big-endian targets.
+const fn bit_mask(bit: usize) -> (usize, u32) {
+    let word = bit / 32;
+    let bit = bit % 32;
+    if cfg!(target_endian = "little") {
+        (word, 1 << bit)
+    } else {
+        (word, 1 << (31 - bit))
+    }
+}
+
+macro_rules! capabilities {
+    ($(($bit:literal, $name:ident, $setter:ident),)*) => {
+        impl Capabilities {
+            $(
+                #[doc = concat!("Returns `true` if the `", stringify!($name), "` capability is set")]
+                pub fn $name(&self) -> bool {
+                    self.get($bit)
+                }
+
+                #[doc = concat!("Sets the `", stringify!($name), "` capability")]
+                pub fn $setter(&mut self, value: bool) -> &mut Self {
+                    self.set($bit, value);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+impl Capabilities {
+    /// Creates an empty set of capabilities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps a raw [`sys::jvmtiCapabilities`] bitfield.
+    pub fn from_raw(raw: sys::jvmtiCapabilities) -> Self {
+        Self { raw }
+    }
+
+    /// Returns the raw [`sys::jvmtiCapabilities`] bitfield.
+    pub fn into_raw(self) -> sys::jvmtiCapabilities {
+        self.raw
+    }
+
+    /// Returns a pointer to the raw [`sys::jvmtiCapabilities`] bitfield.
+    pub fn as_raw(&self) -> *const sys::jvmtiCapabilities {
+        &self.raw
+    }
+
+    /// Returns `true` if no capabilities are set.
+    pub fn is_empty(&self) -> bool {
+        self.raw.bits.iter().all(|w| *w == 0)
+    }
+
+    fn get(&self, bit: usize) -> bool {
+        let (word, mask) = bit_mask(bit);
+        self.raw.bits[word] & mask != 0
+    }
+
+    fn set(&mut self, bit: usize, value: bool) {
+        let (word, mask) = bit_mask(bit);
+        if value {
+            self.raw.bits[word] |= mask;
+        } else {
+            self.raw.bits[word] &= !mask;
+        }
+    }
+}
+
+capabilities! {
+    (0, can_tag_objects, set_can_tag_objects),
+    (1, can_generate_field_modification_events, set_can_generate_field_modification_events),
+    (2, can_generate_field_access_events, set_can_generate_field_access_events),
+    (3, can_get_bytecodes, set_can_get_bytecodes),
+    (4, can_get_synthetic_attribute, set_can_get_synthetic_attribute),
+    (5, can_get_owned_monitor_info, set_can_get_owned_monitor_info),
+    (6, can_get_current_contended_monitor, set_can_get_current_contended_monitor),
+    (7, can_get_monitor_info, set_can_get_monitor_info),
+    (8, can_pop_frame, set_can_pop_frame),
+    (9, can_redefine_classes, set_can_redefine_classes),
+    (10, can_signal_thread, set_can_signal_thread),
+    (11, can_get_source_file_name, set_can_get_source_file_name),
+    (12, can_get_line_numbers, set_can_get_line_numbers),
+    (13, can_get_source_debug_extension, set_can_get_source_debug_extension),
+    (14, can_access_local_variables, set_can_access_local_variables),
+    (15, can_maintain_original_method_order, set_can_maintain_original_method_order),
+    (16, can_generate_single_step_events, set_can_generate_single_step_events),
+    (17, can_generate_exception_events, set_can_generate_exception_events),
+    (18, can_generate_frame_pop_events, set_can_generate_frame_pop_events),
+    (19, can_generate_breakpoint_events, set_can_generate_breakpoint_events),
+    (20, can_suspend, set_can_suspend),
+    (21, can_redefine_any_class, set_can_redefine_any_class),
+    (22, can_get_current_thread_cpu_time, set_can_get_current_thread_cpu_time),
+    (23, can_get_thread_cpu_time, set_can_get_thread_cpu_time),
+    (24, can_generate_method_entry_events, set_can_generate_method_entry_events),
+    (25, can_generate_method_exit_events, set_can_generate_method_exit_events),
+    (26, can_generate_all_class_hook_events, set_can_generate_all_class_hook_events),
+    (27, can_generate_compiled_method_load_events, set_can_generate_compiled_method_load_events),
+    (28, can_generate_monitor_events, set_can_generate_monitor_events),
+    (29, can_generate_vm_object_alloc_events, set_can_generate_vm_object_alloc_events),
+    (30, can_generate_native_method_bind_events, set_can_generate_native_method_bind_events),
+    (31, can_generate_garbage_collection_events, set_can_generate_garbage_collection_events),
+    (32, can_generate_object_free_events, set_can_generate_object_free_events),
+    (33, can_force_early_return, set_can_force_early_return),
+    (34, can_get_owned_monitor_stack_depth_info, set_can_get_owned_monitor_stack_depth_info),
+    (35, can_get_constant_pool, set_can_get_constant_pool),
+    (36, can_set_native_method_prefix, set_can_set_native_method_prefix),
+    (37, can_retransform_classes, set_can_retransform_classes),
+    (38, can_retransform_any_class, set_can_retransform_any_class),
+    (39, can_generate_resource_exhaustion_heap_events, set_can_generate_resource_exhaustion_heap_events),
+    (40, can_generate_resource_exhaustion_threads_events, set_can_generate_resource_exhaustion_threads_events),
+    (41, can_generate_early_vmstart, set_can_generate_early_vmstart),
+    (42, can_generate_early_class_hook_events, set_can_generate_early_class_hook_events),
+    (43, can_generate_sampled_object_alloc_events, set_can_generate_sampled_object_alloc_events),
+}
@@ -0,0 +1,455 @@
+use std::{
+    os::raw::{c_char, c_void},
+    panic::{AssertUnwindSafe, catch_unwind, resume_unwind},
+    ptr::{self, NonNull},
+};
+
+use jni_sys::{jint, jlong, jmethodID};
+
+use crate::{
+    Env,
+    errors::{Error, JniError, Result, jvmti_error_code_to_result},
+    objects::{JClass, JClassLoader, JObject, JThread},
+    strings::JNIStr,
+};
+
+use super::{Capabilities, HeapFilter, HeapObject, HeapVisit, JvmtiMemory, JvmtiVersion, sys};
+
+/// Calls a JVMTI function via the function table for a [`JvmtiEnv`] and maps the returned error
+/// code to a [`Result`]
+///
+/// # Safety
+///
+/// You must ensure that the arguments you pass are valid for the particular JVMTI function you are
+/// calling.
+macro_rules! jvmti_call {
+    ( $jvmti:expr, $name:ident $(, $args:expr )* ) => {{
+        let env: *mut sys::jvmtiEnv = $jvmti.get_raw();
+        let interface: *const sys::jvmtiInterface_1 = *env;
+        jvmti_error_code_to_result(((*interface).$name)(env $(, $args)*))
+    }};
+}
+
+/// A JVMTI (JVM Tool Interface) environment
+///
+/// A `JvmtiEnv` can be acquired via [`JavaVM::get_jvmti_env`](crate::JavaVM::get_jvmti_env).
+///
+/// Unlike a JNI [`Env`], a JVMTI environment is not associated with a specific thread and can be
+/// copied and shared between threads. Each JVMTI environment has its own set of capabilities,
+/// object tags and callbacks.
+///
+/// Functions that return local references require an [`Env`] reference, which must belong to the
+/// current thread.
+///
+/// Any memory allocated by JVMTI, for strings or arrays that are returned, is automatically
+/// released via `Deallocate` (see [`JvmtiMemory`]).
+///
+/// For JVMTI functions that aren't wrapped by this API, the raw environment pointer can be accessed
+/// via [`JvmtiEnv::get_raw`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct JvmtiEnv {
+    raw: NonNull<sys::jvmtiEnv>,
+}
+
+// Safety: JVMTI environments are not tied to a specific thread
+unsafe impl Send for JvmtiEnv {}
+unsafe impl Sync for JvmtiEnv {}
+
+/// A single frame in a stack trace
+///
+/// See [`JvmtiEnv::get_stack_trace`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FrameInfo {
+    /// The method executing in this frame
+    ///
+    /// Note: this may be the ID of a static or non-static method
+    pub method: jmethodID,
+    /// The index of the instruction executing in this frame, or `-1` for a native method
+    pub location: i64,
+}
+
+/// Information about a thread
+///
+/// See [`JvmtiEnv::get_thread_info`]
+#[derive(Debug)]
+pub struct ThreadInfo<'local> {
+    /// The thread name
+    pub name: String,
+    /// The thread priority
+    pub priority: i32,
+    /// Whether the thread is a daemon thread
+    pub is_daemon: bool,
+    /// The thread group that the thread belongs to (`null` if the thread has terminated)
+    pub thread_group: JObject<'local>,
+    /// The context class loader associated with the thread (may be `null`)
+    pub context_class_loader: JClassLoader<'local>,
+}
+
+impl JvmtiEnv {
+    /// Wraps a raw JVMTI environment pointer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NullPtr`] if `raw` is null.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid JVMTI environment pointer, such as returned by `GetEnv`.
+    pub unsafe fn from_raw(raw: *mut sys::jvmtiEnv) -> Result<Self> {
+        let raw = NonNull::new(raw).ok_or(Error::NullPtr("from_raw jvmtiEnv argument"))?;
+        Ok(Self { raw })
+    }
+
+    /// Returns the raw JVMTI environment pointer.
+    pub fn get_raw(&self) -> *mut sys::jvmtiEnv {
+        self.raw.as_ptr()
+    }
+
+    /// Returns the JVMTI version of this environment (via `GetVersionNumber`).
+    pub fn version(&self) -> Result<JvmtiVersion> {
+        let mut version: jint = 0;
+        unsafe { jvmti_call!(self, GetVersionNumber, &mut version)? };
+        Ok(JvmtiVersion::new(version))
+    }
+
+    /// Returns the capabilities that this environment could possibly add, at this time (via
+    /// `GetPotentialCapabilities`).
+    pub fn get_potential_capabilities(&self) -> Result<Capabilities> {
+        let mut caps = sys::jvmtiCapabilities::default();
+        unsafe { jvmti_call!(self, GetPotentialCapabilities, &mut caps)? };
+        Ok(Capabilities::from_raw(caps))
+    }
+
+    /// Returns the capabilities that this environment currently possesses (via
+    /// `GetCapabilities`).
+    pub fn get_capabilities(&self) -> Result<Capabilities> {
+        let mut caps = sys::jvmtiCapabilities::default();
+        unsafe { jvmti_call!(self, GetCapabilities, &mut caps)? };
+        Ok(Capabilities::from_raw(caps))
+    }
+
+    /// Adds the given capabilities to this environment (via `AddCapabilities`).
+    ///
+    /// Returns [`crate::errors::JvmtiError::NotAvailable`] if any of the capabilities aren't
+    /// potentially available (see [`Self::get_potential_capabilities`]).
+    pub fn add_capabilities(&self, capabilities: &Capabilities) -> Result<()> {
+        unsafe { jvmti_call!(self, AddCapabilities, capabilities.as_raw()) }
+    }
+
+    /// Relinquishes the given capabilities from this environment (via
+    /// `RelinquishCapabilities`).
+    pub fn relinquish_capabilities(&self, capabilities: &Capabilities) -> Result<()> {
+        unsafe { jvmti_call!(self, RelinquishCapabilities, capabilities.as_raw()) }
+    }
+
+    /// Allocates `len` bytes of memory via JVMTI (via `Allocate`).
+    ///
+    /// The memory will be released via `Deallocate` when the returned [`JvmtiMemory`] is dropped.
+    ///
+    /// Note: the memory is not initialized, and so it's zeroed before being returned.
+    pub fn allocate(&self, len: usize) -> Result<JvmtiMemory<u8>> {
+        let size: jlong = len
+            .try_into()
+            .map_err(|_| Error::JniCall(JniError::InvalidArguments))?;
+        let mut mem: *mut u8 = ptr::null_mut();
+        unsafe {
+            jvmti_call!(self, Allocate, size, &mut mem)?;
+            if !mem.is_null() {
+                ptr::write_bytes(mem, 0, len);
+            }
+            Ok(JvmtiMemory::from_raw(*self, mem, len))
+        }
+    }
+
+    /// Deallocates memory that was allocated by JVMTI (via `Deallocate`).
+    ///
+    /// Prefer wrapping JVMTI allocations with [`JvmtiMemory`] instead of calling this directly.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be null or a pointer that was allocated by JVMTI for this environment, and must
+    /// not be used after this call.
+    pub unsafe fn deallocate(&self, mem: *mut u8) -> Result<()> {
+        unsafe { jvmti_call!(self, Deallocate, mem) }
+    }
+
+    /// Returns the tag associated with an object (via `GetTag`).
+    ///
+    /// Returns zero if the object isn't tagged.
+    ///
+    /// Requires the `can_tag_objects` capability.
+    pub fn get_tag<'any_local>(&self, obj: impl AsRef<JObject<'any_local>>) -> Result<i64> {
+        let obj = null_check!(obj.as_ref(), "get_tag obj argument")?;
+        let mut tag: jlong = 0;
+        unsafe { jvmti_call!(self, GetTag, obj.as_raw(), &mut tag)? };
+        Ok(tag)
+    }
+
+    /// Sets the tag associated with an object (via `SetTag`).
+    ///
+    /// A tag of zero will untag the object.
+    ///
+    /// Requires the `can_tag_objects` capability.
+    pub fn set_tag<'any_local>(
+        &self,
+        obj: impl AsRef<JObject<'any_local>>,
+        tag: i64,
+    ) -> Result<()> {
+        let obj = null_check!(obj.as_ref(), "set_tag obj argument")?;
+        unsafe { jvmti_call!(self, SetTag, obj.as_raw(), tag) }
+    }
+
+    /// Returns local references to all the objects in the heap with any of the given (non-zero)
+    /// `tags`, along with their tags (via `GetObjectsWithTags`).
+    ///
+    /// Requires the `can_tag_objects` capability.
+    ///
+    /// Note: this creates a new local reference for each object found, so consider using
+    /// [`Env::with_local_frame`] if there could be a large number of objects.
+    pub fn get_objects_with_tags<'local>(
+        &self,
+        env: &mut Env<'local>,
+        tags: &[i64],
+    ) -> Result<Vec<(JObject<'local>, i64)>> {
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        env.assert_top();
+        let tag_count: jint = tags
+            .len()
+            .try_into()
+            .map_err(|_| Error::JniCall(JniError::InvalidArguments))?;
+        let mut count: jint = 0;
+        let mut objects = ptr::null_mut();
+        let mut object_tags = ptr::null_mut();
+        unsafe {
+            jvmti_call!(
+                self,
+                GetObjectsWithTags,
+                tag_count,
+                tags.as_ptr(),
+                &mut count,
+                &mut objects,
+                &mut object_tags
+            )?;
+            let objects = JvmtiMemory::from_raw(*self, objects, count as usize);
+            let object_tags = JvmtiMemory::from_raw(*self, object_tags, count as usize);
+            Ok(objects
+                .iter()
+                .zip(object_tags.iter())
+                .map(|(obj, tag)| (JObject::from_raw(env, *obj), *tag))
+                .collect())
+        }
+    }
+
+    /// Iterates over all the objects in the heap (via `IterateThroughHeap`), calling `callback`
+    /// for each object that isn't excluded by the `filter`.
+    ///
+    /// If `class` is given, then only objects that are instances of that class are reported.
+    ///
+    /// The `callback` can re-tag the object by modifying [`HeapObject::tag`] and can return
+    /// [`HeapVisit::Abort`] to stop the iteration.
+    ///
+    /// Requires the `can_tag_objects` capability.
+    ///
+    /// **Note:** The callback is called while the JVM is in a restricted state and it must not
+    /// call any JNI functions or any JVMTI functions (other than those documented as being
+    /// "callback safe", such as the raw monitor functions). Any panic within the callback will
+    /// abort the iteration and will be resumed after `IterateThroughHeap` returns.
+    pub fn iterate_through_heap<F>(
+        &self,
+        filter: HeapFilter,
+        class: Option<&JClass>,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&mut HeapObject) -> HeapVisit,
+    {
+        struct UserData<F> {
+            callback: F,
+            panic: Option<Box<dyn std::any::Any + Send + 'static>>,
+        }
+
+        unsafe extern "system" fn heap_iteration_callback<F>(
+            class_tag: jlong,
+            size: jlong,
+            tag_ptr: *mut jlong,
+            length: jint,
+            user_data: *mut c_void,
+        ) -> jint
+        where
+            F: FnMut(&mut HeapObject) -> HeapVisit,
+        {
+            // Safety: `user_data` is a pointer to the `UserData` on the stack of
+            // `iterate_through_heap` and `tag_ptr` is valid for the duration of the callback
+            let user_data = unsafe { &mut *(user_data as *mut UserData<F>) };
+            let mut object = HeapObject {
+                class_tag,
+                size,
+                tag: unsafe { *tag_ptr },
+                array_length: (length >= 0).then_some(length),
+            };
+            match catch_unwind(AssertUnwindSafe(|| (user_data.callback)(&mut object))) {
+                Ok(visit) => {
+                    unsafe { *tag_ptr = object.tag };
+                    match visit {
+                        HeapVisit::Continue => 0,
+                        HeapVisit::Abort => sys::JVMTI_VISIT_ABORT,
+                    }
+                }
+                Err(payload) => {
+                    user_data.panic = Some(payload);
+                    sys::JVMTI_VISIT_ABORT
+                }
+            }
+        }
+
+        let callbacks = sys::jvmtiHeapCallbacks {
+            heap_iteration_callback: Some(heap_iteration_callback::<F>),
+            heap_reference_callback: ptr::null_mut(),
+            primitive_field_callback: ptr::null_mut(),
+            array_primitive_value_callback: ptr::null_mut(),
+            string_primitive_value_callback: ptr::null_mut(),
+            reserved: [ptr::null_mut(); 11],
+        };
+        let mut user_data = UserData {
+            callback,
+            panic: None,
+        };
+        let class = class.map_or(ptr::null_mut(), |c| c.as_raw());
+        let res = unsafe {
+            jvmti_call!(
+                self,
+                IterateThroughHeap,
+                filter.bits(),
+                class,
+                &callbacks,
+                &mut user_data as *mut UserData<F> as *const c_void
+            )
+        };
+        if let Some(payload) = user_data.panic {
+            resume_unwind(payload);
+        }
+        res
+    }
+
+    /// Returns local references to all the classes loaded in the virtual machine (via
+    /// `GetLoadedClasses`).
+    ///
+    /// Note: this creates a new local reference for each class, so consider using
+    /// [`Env::with_local_frame`] to avoid leaking a large number of local references.
+    pub fn get_loaded_classes<'local>(&self, env: &mut Env<'local>) -> Result<Vec<JClass<'local>>> {
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        env.assert_top();
+        let mut count: jint = 0;
+        let mut classes = ptr::null_mut();
+        unsafe {
+            jvmti_call!(self, GetLoadedClasses, &mut count, &mut classes)?;
+            let classes = JvmtiMemory::from_raw(*self, classes, count as usize);
+            Ok(classes
+                .iter()
+                .map(|class| JClass::from_raw(env, *class))
+                .collect())
+        }
+    }
+
+    /// Returns up to `max_frames` frames of the stack trace for a thread, starting `start_depth`
+    /// frames from the top of the stack (via `GetStackTrace`).
+    ///
+    /// If `start_depth` is negative, then the frames are counted from the bottom of the stack
+    /// instead.
+    ///
+    /// If `thread` is `None` then the stack trace for the current thread is returned.
+    pub fn get_stack_trace(
+        &self,
+        thread: Option<&JThread>,
+        start_depth: i32,
+        max_frames: usize,
+    ) -> Result<Vec<FrameInfo>> {
+        let max_frame_count: jint = max_frames
+            .try_into()
+            .map_err(|_| Error::JniCall(JniError::InvalidArguments))?;
+        let mut frames = Vec::<sys::jvmtiFrameInfo>::with_capacity(max_frames);
+        let mut count: jint = 0;
+        let thread = thread.map_or(ptr::null_mut(), |t| t.as_raw());
+        unsafe {
+            jvmti_call!(
+                self,
+                GetStackTrace,
+                thread,
+                start_depth,
+                max_frame_count,
+                frames.as_mut_ptr(),
+                &mut count
+            )?;
+            frames.set_len((count as usize).min(max_frames));
+        }
+        Ok(frames
+            .into_iter()
+            .map(|frame| FrameInfo {
+                method: frame.method,
+                location: frame.location,
+            })
+            .collect())
+    }
+
+    /// Returns information about a thread (via `GetThreadInfo`).
+    ///
+    /// If `thread` is `None` then information about the current thread is returned.
+    pub fn get_thread_info<'local>(
+        &self,
+        env: &mut Env<'local>,
+        thread: Option<&JThread>,
+    ) -> Result<ThreadInfo<'local>> {
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        env.assert_top();
+        let thread = thread.map_or(ptr::null_mut(), |t| t.as_raw());
+        let mut info = sys::jvmtiThreadInfo {
+            name: ptr::null_mut(),
+            priority: 0,
+            is_daemon: false,
+            thread_group: ptr::null_mut(),
+            context_class_loader: ptr::null_mut(),
+        };
+        unsafe {
+            jvmti_call!(self, GetThreadInfo, thread, &mut info)?;
+            let thread_group = JObject::from_raw(env, info.thread_group);
+            let context_class_loader = JClassLoader::from_raw(env, info.context_class_loader);
+            // Note: the name is a modified UTF-8 string
+            let name = JvmtiMemory::<c_char>::from_raw(*self, info.name, 0);
+            let name = if name.as_ptr().is_null() {
+                String::new()
+            } else {
+                JNIStr::from_ptr(name.as_ptr()).to_str().into_owned()
+            };
+            Ok(ThreadInfo {
+                name,
+                priority: info.priority,
+                is_daemon: info.is_daemon,
+                thread_group,
+                context_class_loader,
+            })
+        }
+    }
+
+    /// Forces the virtual machine to perform a garbage collection (via
+    /// `ForceGarbageCollection`).
+    pub fn force_garbage_collection(&self) -> Result<()> {
+        unsafe { jvmti_call!(self, ForceGarbageCollection) }
+    }
+
+    /// Disposes of this JVMTI environment (via `DisposeEnvironment`).
+    ///
+    /// This relinquishes all capabilities and releases all the resources associated with the
+    /// environment, including object tags.
+    ///
+    /// # Safety
+    ///
+    /// The environment (including any copies of this `JvmtiEnv`) must not be used after it has
+    /// been disposed, and there must be no remaining [`JvmtiMemory`] allocations.
+    pub unsafe fn dispose(self) -> Result<()> {
+        unsafe { jvmti_call!(self, DisposeEnvironment) }
+    }
+}
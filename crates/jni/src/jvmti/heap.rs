@@ -0,0 +1,63 @@
+use jni_sys::jint;
+
+use super::sys;
+
+/// Filters for which objects are reported while iterating the heap
+///
+/// Filters can be combined with `|`.
+///
+/// See [`JvmtiEnv::iterate_through_heap`](super::JvmtiEnv::iterate_through_heap)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HeapFilter(jint);
+
+impl HeapFilter {
+    /// Report all objects
+    pub const NONE: Self = HeapFilter(0);
+    /// Filter out tagged objects
+    pub const TAGGED: Self = HeapFilter(sys::JVMTI_HEAP_FILTER_TAGGED);
+    /// Filter out untagged objects
+    pub const UNTAGGED: Self = HeapFilter(sys::JVMTI_HEAP_FILTER_UNTAGGED);
+    /// Filter out objects with tagged classes
+    pub const CLASS_TAGGED: Self = HeapFilter(sys::JVMTI_HEAP_FILTER_CLASS_TAGGED);
+    /// Filter out objects with untagged classes
+    pub const CLASS_UNTAGGED: Self = HeapFilter(sys::JVMTI_HEAP_FILTER_CLASS_UNTAGGED);
+
+    /// Returns the raw `heap_filter` flags
+    pub fn bits(&self) -> jint {
+        self.0
+    }
+}
+
+impl std::ops::BitOr for HeapFilter {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        HeapFilter(self.0 | rhs.0)
+    }
+}
+
+/// Whether heap iteration should continue after visiting an object
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HeapVisit {
+    /// Continue iterating the heap
+    Continue,
+    /// Stop iterating the heap
+    Abort,
+}
+
+/// Information about an object that is visited while iterating the heap
+///
+/// See [`JvmtiEnv::iterate_through_heap`](super::JvmtiEnv::iterate_through_heap)
+#[derive(Debug)]
+pub struct HeapObject {
+    /// The tag of the object's class (zero if the class isn't tagged)
+    pub class_tag: i64,
+    /// The size of the object, in bytes
+    pub size: i64,
+    /// The tag of the object (zero if the object isn't tagged)
+    ///
+    /// The object will be re-tagged if this is modified (setting zero will untag the object).
+    pub tag: i64,
+    /// The length of the array, if the object is an array
+    pub array_length: Option<i32>,
+}
@@ -0,0 +1,114 @@
+use std::ptr::NonNull;
+
+use log::error;
+
+use super::JvmtiEnv;
+
+/// An auto-release wrapper for memory that was allocated by JVMTI
+///
+/// Many JVMTI functions return arrays (or strings) that were allocated by the JVM and must be
+/// freed via the JVMTI `Deallocate` function. This wraps such an allocation as a slice of `len`
+/// elements of type `T`, and will call `Deallocate` when dropped.
+///
+/// This can be dereferenced to obtain a `[T]` slice.
+///
+/// A `JvmtiMemory` can be allocated via [`JvmtiEnv::allocate`], or used to take ownership of
+/// memory that was returned from a raw JVMTI function via [`JvmtiMemory::from_raw`].
+pub struct JvmtiMemory<T> {
+    env: JvmtiEnv,
+    ptr: Option<NonNull<T>>,
+    len: usize,
+}
+
+impl<T> std::fmt::Debug for JvmtiMemory<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JvmtiMemory")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+// Safety: JVMTI memory can be freed from any thread
+unsafe impl<T: Send> Send for JvmtiMemory<T> {}
+unsafe impl<T: Sync> Sync for JvmtiMemory<T> {}
+
+impl<T> JvmtiMemory<T> {
+    /// Takes ownership of memory that was allocated by JVMTI, which will be released via
+    /// `Deallocate` when dropped.
+    ///
+    /// `ptr` may be null, in which case `len` is ignored and this represents an empty slice.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must either be null or a pointer to `len` initialized elements of type `T` that was
+    /// allocated by JVMTI (such as via `Allocate`, or returned from another JVMTI function) for
+    /// the given `env`, and must not be deallocated by anything else.
+    pub unsafe fn from_raw(env: JvmtiEnv, ptr: *mut T, len: usize) -> Self {
+        let ptr = NonNull::new(ptr);
+        Self {
+            env,
+            len: if ptr.is_some() { len } else { 0 },
+            ptr,
+        }
+    }
+
+    /// Returns a raw pointer to the memory, or null for an empty allocation.
+    ///
+    /// The pointer is only valid while this wrapper is alive.
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.map_or(std::ptr::null_mut(), |p| p.as_ptr())
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Releases ownership of the memory without deallocating it.
+    ///
+    /// The caller becomes responsible for deallocating the memory via [`JvmtiEnv::deallocate`].
+    pub fn into_raw(self) -> *mut T {
+        let ptr = self.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+}
+
+impl<T> std::ops::Deref for JvmtiMemory<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        match self.ptr {
+            // SAFETY: `from_raw` requires that the pointer is valid for `len` elements
+            Some(ptr) => unsafe { std::slice::from_raw_parts(ptr.as_ptr(), self.len) },
+            None => &[],
+        }
+    }
+}
+
+impl<T> std::ops::DerefMut for JvmtiMemory<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self.ptr {
+            // SAFETY: `from_raw` requires that the pointer is valid for `len` elements
+            Some(ptr) => unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr(), self.len) },
+            None => &mut [],
+        }
+    }
+}
+
+impl<T> Drop for JvmtiMemory<T> {
+    fn drop(&mut self) {
+        if let Some(ptr) = self.ptr {
+            // Safety: `from_raw` requires that the memory was allocated by JVMTI for `env`
+            if let Err(err) = unsafe { self.env.deallocate(ptr.as_ptr() as *mut u8) } {
+                error!("error deallocating JVMTI memory: {:#?}", err);
+            }
+        }
+    }
+}
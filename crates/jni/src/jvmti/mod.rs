@@ -0,0 +1,43 @@
+//! JVMTI is the native programming interface used by tools such as profilers, debuggers and
+//! monitoring agents. It provides access to VM internals that aren't exposed via JNI, such as
+//! tagging objects, iterating the heap, enumerating loaded classes and inspecting stack traces.
+//!
+//! A [`JvmtiEnv`](crate::jvmti::JvmtiEnv) can be acquired from a [`JavaVM`](crate::JavaVM) via
+//! [`JavaVM::get_jvmti_env`](crate::JavaVM::get_jvmti_env). Most functionality requires the
+//! environment to first add the relevant
+//! [`Capabilities`](crate::jvmti::Capabilities).
+//!
+//! ```rust,no_run
+//! # use jni::{JavaVM, jvmti::{Capabilities, JvmtiVersion}};
+//! # fn f(vm: &JavaVM) -> jni::errors::Result<()> {
+//! let jvmti = vm.get_jvmti_env(JvmtiVersion::V1_2)?;
+//! let mut caps = Capabilities::new();
+//! caps.set_can_tag_objects(true);
+//! jvmti.add_capabilities(&caps)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See the [JVMTI specification] for more details.
+//!
+//! [JVMTI specification]: https://docs.oracle.com/en/java/javase/21/docs/specs/jvmti.html
+
+pub mod sys;
+
+mod version;
+pub use self::version::*;
+
+mod capabilities;
+pub use self::capabilities::*;
+
+mod memory;
+pub use self::memory::*;
+
+mod heap;
+pub use self::heap::*;
+
+mod env;
+pub use self::env::*;
+
+mod agent;
+pub use self::agent::*;
@@ -0,0 +1,357 @@
+//! Raw JVMTI FFI definitions
+//!
+//! This only covers the subset of `jvmti.h` that is needed by the safe [`JvmtiEnv`] API. The
+//! function table is complete (so that it has the correct layout) but functions that aren't
+//! used by this crate are left untyped (as `*mut c_void`) and would need to be transmuted to the
+//! correct function pointer type before being called.
+//!
+//! [`JvmtiEnv`]: super::JvmtiEnv
+
+#![allow(non_snake_case, non_camel_case_types, missing_docs)]
+
+use std::os::raw::{c_char, c_uchar, c_void};
+
+use jni_sys::{jboolean, jclass, jint, jlong, jmethodID, jobject};
+
+pub type jthread = jobject;
+pub type jthreadGroup = jobject;
+pub type jlocation = jlong;
+
+pub type jvmtiError = u32;
+
+pub const JVMTI_VERSION_1: jint = 0x30010000;
+pub const JVMTI_VERSION_1_0: jint = 0x30010000;
+pub const JVMTI_VERSION_1_1: jint = 0x30010100;
+pub const JVMTI_VERSION_1_2: jint = 0x30010200;
+pub const JVMTI_VERSION_9: jint = 0x30090000;
+pub const JVMTI_VERSION_11: jint = 0x300B0000;
+pub const JVMTI_VERSION_19: jint = 0x30130000;
+pub const JVMTI_VERSION_21: jint = 0x30150000;
+
+pub const JVMTI_ERROR_NONE: jvmtiError = 0;
+pub const JVMTI_ERROR_INVALID_THREAD: jvmtiError = 10;
+pub const JVMTI_ERROR_INVALID_THREAD_GROUP: jvmtiError = 11;
+pub const JVMTI_ERROR_INVALID_PRIORITY: jvmtiError = 12;
+pub const JVMTI_ERROR_THREAD_NOT_SUSPENDED: jvmtiError = 13;
+pub const JVMTI_ERROR_THREAD_SUSPENDED: jvmtiError = 14;
+pub const JVMTI_ERROR_THREAD_NOT_ALIVE: jvmtiError = 15;
+pub const JVMTI_ERROR_INVALID_OBJECT: jvmtiError = 20;
+pub const JVMTI_ERROR_INVALID_CLASS: jvmtiError = 21;
+pub const JVMTI_ERROR_CLASS_NOT_PREPARED: jvmtiError = 22;
+pub const JVMTI_ERROR_INVALID_METHODID: jvmtiError = 23;
+pub const JVMTI_ERROR_INVALID_LOCATION: jvmtiError = 24;
+pub const JVMTI_ERROR_INVALID_FIELDID: jvmtiError = 25;
+pub const JVMTI_ERROR_INVALID_MODULE: jvmtiError = 26;
+pub const JVMTI_ERROR_NO_MORE_FRAMES: jvmtiError = 31;
+pub const JVMTI_ERROR_OPAQUE_FRAME: jvmtiError = 32;
+pub const JVMTI_ERROR_TYPE_MISMATCH: jvmtiError = 34;
+pub const JVMTI_ERROR_INVALID_SLOT: jvmtiError = 35;
+pub const JVMTI_ERROR_DUPLICATE: jvmtiError = 40;
+pub const JVMTI_ERROR_NOT_FOUND: jvmtiError = 41;
+pub const JVMTI_ERROR_INVALID_MONITOR: jvmtiError = 50;
+pub const JVMTI_ERROR_NOT_MONITOR_OWNER: jvmtiError = 51;
+pub const JVMTI_ERROR_INTERRUPT: jvmtiError = 52;
+pub const JVMTI_ERROR_INVALID_CLASS_FORMAT: jvmtiError = 60;
+pub const JVMTI_ERROR_CIRCULAR_CLASS_DEFINITION: jvmtiError = 61;
+pub const JVMTI_ERROR_FAILS_VERIFICATION: jvmtiError = 62;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_ADDED: jvmtiError = 63;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_SCHEMA_CHANGED: jvmtiError = 64;
+pub const JVMTI_ERROR_INVALID_TYPESTATE: jvmtiError = 65;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_HIERARCHY_CHANGED: jvmtiError = 66;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_DELETED: jvmtiError = 67;
+pub const JVMTI_ERROR_UNSUPPORTED_VERSION: jvmtiError = 68;
+pub const JVMTI_ERROR_NAMES_DONT_MATCH: jvmtiError = 69;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_CLASS_MODIFIERS_CHANGED: jvmtiError = 70;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_MODIFIERS_CHANGED: jvmtiError = 71;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_CLASS_ATTRIBUTE_CHANGED: jvmtiError = 72;
+pub const JVMTI_ERROR_UNMODIFIABLE_CLASS: jvmtiError = 79;
+pub const JVMTI_ERROR_UNMODIFIABLE_MODULE: jvmtiError = 80;
+pub const JVMTI_ERROR_NOT_AVAILABLE: jvmtiError = 98;
+pub const JVMTI_ERROR_MUST_POSSESS_CAPABILITY: jvmtiError = 99;
+pub const JVMTI_ERROR_NULL_POINTER: jvmtiError = 100;
+pub const JVMTI_ERROR_ABSENT_INFORMATION: jvmtiError = 101;
+pub const JVMTI_ERROR_INVALID_EVENT_TYPE: jvmtiError = 102;
+pub const JVMTI_ERROR_ILLEGAL_ARGUMENT: jvmtiError = 103;
+pub const JVMTI_ERROR_NATIVE_METHOD: jvmtiError = 104;
+pub const JVMTI_ERROR_CLASS_LOADER_UNSUPPORTED: jvmtiError = 106;
+pub const JVMTI_ERROR_OUT_OF_MEMORY: jvmtiError = 110;
+pub const JVMTI_ERROR_ACCESS_DENIED: jvmtiError = 111;
+pub const JVMTI_ERROR_WRONG_PHASE: jvmtiError = 112;
+pub const JVMTI_ERROR_INTERNAL: jvmtiError = 113;
+pub const JVMTI_ERROR_UNATTACHED_THREAD: jvmtiError = 115;
+pub const JVMTI_ERROR_INVALID_ENVIRONMENT: jvmtiError = 116;
+
+pub const JVMTI_HEAP_FILTER_TAGGED: jint = 0x4;
+pub const JVMTI_HEAP_FILTER_UNTAGGED: jint = 0x8;
+pub const JVMTI_HEAP_FILTER_CLASS_TAGGED: jint = 0x10;
+pub const JVMTI_HEAP_FILTER_CLASS_UNTAGGED: jint = 0x20;
+
+pub const JVMTI_VISIT_OBJECTS: jint = 0x100;
+pub const JVMTI_VISIT_ABORT: jint = 0x8000;
+
+/// The set of JVMTI capabilities, represented as a 128-bit C bitfield.
+///
+/// See [`Capabilities`](super::Capabilities) for a safe wrapper.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct jvmtiCapabilities {
+    pub bits: [u32; 4],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct jvmtiThreadInfo {
+    pub name: *mut c_char,
+    pub priority: jint,
+    pub is_daemon: jboolean,
+    pub thread_group: jthreadGroup,
+    pub context_class_loader: jobject,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct jvmtiFrameInfo {
+    pub method: jmethodID,
+    pub location: jlocation,
+}
+
+pub type jvmtiHeapIterationCallback = Option<
+    unsafe extern "system" fn(
+        class_tag: jlong,
+        size: jlong,
+        tag_ptr: *mut jlong,
+        length: jint,
+        user_data: *mut c_void,
+    ) -> jint,
+>;
+
+/// Heap callbacks
+///
+/// Only the `heap_iteration_callback` is typed, since that's the only callback used by this crate.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct jvmtiHeapCallbacks {
+    pub heap_iteration_callback: jvmtiHeapIterationCallback,
+    pub heap_reference_callback: *mut c_void,
+    pub primitive_field_callback: *mut c_void,
+    pub array_primitive_value_callback: *mut c_void,
+    pub string_primitive_value_callback: *mut c_void,
+    pub reserved: [*mut c_void; 11],
+}
+
+pub type jvmtiEnv = *const jvmtiInterface_1;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct jvmtiInterface_1 {
+    pub reserved1: *mut c_void,
+    pub SetEventNotificationMode: *mut c_void,
+    pub GetAllModules: *mut c_void,
+    pub GetAllThreads: *mut c_void,
+    pub SuspendThread: *mut c_void,
+    pub ResumeThread: *mut c_void,
+    pub StopThread: *mut c_void,
+    pub InterruptThread: *mut c_void,
+    pub GetThreadInfo: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        thread: jthread,
+        info_ptr: *mut jvmtiThreadInfo,
+    ) -> jvmtiError,
+    pub GetOwnedMonitorInfo: *mut c_void,
+    pub GetCurrentContendedMonitor: *mut c_void,
+    pub RunAgentThread: *mut c_void,
+    pub GetTopThreadGroups: *mut c_void,
+    pub GetThreadGroupInfo: *mut c_void,
+    pub GetThreadGroupChildren: *mut c_void,
+    pub GetFrameCount: *mut c_void,
+    pub GetThreadState: *mut c_void,
+    pub GetCurrentThread: *mut c_void,
+    pub GetFrameLocation: *mut c_void,
+    pub NotifyFramePop: *mut c_void,
+    pub GetLocalObject: *mut c_void,
+    pub GetLocalInt: *mut c_void,
+    pub GetLocalLong: *mut c_void,
+    pub GetLocalFloat: *mut c_void,
+    pub GetLocalDouble: *mut c_void,
+    pub SetLocalObject: *mut c_void,
+    pub SetLocalInt: *mut c_void,
+    pub SetLocalLong: *mut c_void,
+    pub SetLocalFloat: *mut c_void,
+    pub SetLocalDouble: *mut c_void,
+    pub CreateRawMonitor: *mut c_void,
+    pub DestroyRawMonitor: *mut c_void,
+    pub RawMonitorEnter: *mut c_void,
+    pub RawMonitorExit: *mut c_void,
+    pub RawMonitorWait: *mut c_void,
+    pub RawMonitorNotify: *mut c_void,
+    pub RawMonitorNotifyAll: *mut c_void,
+    pub SetBreakpoint: *mut c_void,
+    pub ClearBreakpoint: *mut c_void,
+    pub GetNamedModule: *mut c_void,
+    pub SetFieldAccessWatch: *mut c_void,
+    pub ClearFieldAccessWatch: *mut c_void,
+    pub SetFieldModificationWatch: *mut c_void,
+    pub ClearFieldModificationWatch: *mut c_void,
+    pub IsModifiableClass: *mut c_void,
+    pub Allocate: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        size: jlong,
+        mem_ptr: *mut *mut c_uchar,
+    ) -> jvmtiError,
+    pub Deallocate: unsafe extern "system" fn(env: *mut jvmtiEnv, mem: *mut c_uchar) -> jvmtiError,
+    pub GetClassSignature: *mut c_void,
+    pub GetClassStatus: *mut c_void,
+    pub GetSourceFileName: *mut c_void,
+    pub GetClassModifiers: *mut c_void,
+    pub GetClassMethods: *mut c_void,
+    pub GetClassFields: *mut c_void,
+    pub GetImplementedInterfaces: *mut c_void,
+    pub IsInterface: *mut c_void,
+    pub IsArrayClass: *mut c_void,
+    pub GetClassLoader: *mut c_void,
+    pub GetObjectHashCode: *mut c_void,
+    pub GetObjectMonitorUsage: *mut c_void,
+    pub GetFieldName: *mut c_void,
+    pub GetFieldDeclaringClass: *mut c_void,
+    pub GetFieldModifiers: *mut c_void,
+    pub IsFieldSynthetic: *mut c_void,
+    pub GetMethodName: *mut c_void,
+    pub GetMethodDeclaringClass: *mut c_void,
+    pub GetMethodModifiers: *mut c_void,
+    pub reserved67: *mut c_void,
+    pub GetMaxLocals: *mut c_void,
+    pub GetArgumentsSize: *mut c_void,
+    pub GetLineNumberTable: *mut c_void,
+    pub GetMethodLocation: *mut c_void,
+    pub GetLocalVariableTable: *mut c_void,
+    pub SetNativeMethodPrefix: *mut c_void,
+    pub SetNativeMethodPrefixes: *mut c_void,
+    pub GetBytecodes: *mut c_void,
+    pub IsMethodNative: *mut c_void,
+    pub IsMethodSynthetic: *mut c_void,
+    pub GetLoadedClasses: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        class_count_ptr: *mut jint,
+        classes_ptr: *mut *mut jclass,
+    ) -> jvmtiError,
+    pub GetClassLoaderClasses: *mut c_void,
+    pub PopFrame: *mut c_void,
+    pub ForceEarlyReturnObject: *mut c_void,
+    pub ForceEarlyReturnInt: *mut c_void,
+    pub ForceEarlyReturnLong: *mut c_void,
+    pub ForceEarlyReturnFloat: *mut c_void,
+    pub ForceEarlyReturnDouble: *mut c_void,
+    pub ForceEarlyReturnVoid: *mut c_void,
+    pub RedefineClasses: *mut c_void,
+    pub GetVersionNumber:
+        unsafe extern "system" fn(env: *mut jvmtiEnv, version_ptr: *mut jint) -> jvmtiError,
+    pub GetCapabilities: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        capabilities_ptr: *mut jvmtiCapabilities,
+    ) -> jvmtiError,
+    pub GetSourceDebugExtension: *mut c_void,
+    pub IsMethodObsolete: *mut c_void,
+    pub SuspendThreadList: *mut c_void,
+    pub ResumeThreadList: *mut c_void,
+    pub AddModuleReads: *mut c_void,
+    pub AddModuleExports: *mut c_void,
+    pub AddModuleOpens: *mut c_void,
+    pub AddModuleUses: *mut c_void,
+    pub AddModuleProvides: *mut c_void,
+    pub IsModifiableModule: *mut c_void,
+    pub GetAllStackTraces: *mut c_void,
+    pub GetThreadListStackTraces: *mut c_void,
+    pub GetThreadLocalStorage: *mut c_void,
+    pub SetThreadLocalStorage: *mut c_void,
+    pub GetStackTrace: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        thread: jthread,
+        start_depth: jint,
+        max_frame_count: jint,
+        frame_buffer: *mut jvmtiFrameInfo,
+        count_ptr: *mut jint,
+    ) -> jvmtiError,
+    pub reserved105: *mut c_void,
+    pub GetTag: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        object: jobject,
+        tag_ptr: *mut jlong,
+    ) -> jvmtiError,
+    pub SetTag:
+        unsafe extern "system" fn(env: *mut jvmtiEnv, object: jobject, tag: jlong) -> jvmtiError,
+    pub ForceGarbageCollection: unsafe extern "system" fn(env: *mut jvmtiEnv) -> jvmtiError,
+    pub IterateOverObjectsReachableFromObject: *mut c_void,
+    pub IterateOverReachableObjects: *mut c_void,
+    pub IterateOverHeap: *mut c_void,
+    pub IterateOverInstancesOfClass: *mut c_void,
+    pub reserved113: *mut c_void,
+    pub GetObjectsWithTags: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        tag_count: jint,
+        tags: *const jlong,
+        count_ptr: *mut jint,
+        object_result_ptr: *mut *mut jobject,
+        tag_result_ptr: *mut *mut jlong,
+    ) -> jvmtiError,
+    pub FollowReferences: *mut c_void,
+    pub IterateThroughHeap: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        heap_filter: jint,
+        klass: jclass,
+        callbacks: *const jvmtiHeapCallbacks,
+        user_data: *const c_void,
+    ) -> jvmtiError,
+    pub reserved117: *mut c_void,
+    pub reserved118: *mut c_void,
+    pub reserved119: *mut c_void,
+    pub SetJNIFunctionTable: *mut c_void,
+    pub GetJNIFunctionTable: *mut c_void,
+    pub SetEventCallbacks: *mut c_void,
+    pub GenerateEvents: *mut c_void,
+    pub GetExtensionFunctions: *mut c_void,
+    pub GetExtensionEvents: *mut c_void,
+    pub SetExtensionEventCallback: *mut c_void,
+    pub DisposeEnvironment: unsafe extern "system" fn(env: *mut jvmtiEnv) -> jvmtiError,
+    pub GetErrorName: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        error: jvmtiError,
+        name_ptr: *mut *mut c_char,
+    ) -> jvmtiError,
+    pub GetJLocationFormat: *mut c_void,
+    pub GetSystemProperties: *mut c_void,
+    pub GetSystemProperty: *mut c_void,
+    pub SetSystemProperty: *mut c_void,
+    pub GetPhase: *mut c_void,
+    pub GetCurrentThreadCpuTimerInfo: *mut c_void,
+    pub GetCurrentThreadCpuTime: *mut c_void,
+    pub GetThreadCpuTimerInfo: *mut c_void,
+    pub GetThreadCpuTime: *mut c_void,
+    pub GetTimerInfo: *mut c_void,
+    pub GetTime: *mut c_void,
+    pub GetPotentialCapabilities: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        capabilities_ptr: *mut jvmtiCapabilities,
+    ) -> jvmtiError,
+    pub reserved141: *mut c_void,
+    pub AddCapabilities: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        capabilities_ptr: *const jvmtiCapabilities,
+    ) -> jvmtiError,
+    pub RelinquishCapabilities: unsafe extern "system" fn(
+        env: *mut jvmtiEnv,
+        capabilities_ptr: *const jvmtiCapabilities,
+    ) -> jvmtiError,
+    pub GetAvailableProcessors: *mut c_void,
+    pub GetClassVersionNumbers: *mut c_void,
+    pub GetConstantPool: *mut c_void,
+    pub GetEnvironmentLocalStorage: *mut c_void,
+    pub SetEnvironmentLocalStorage: *mut c_void,
+    pub AddToBootstrapClassLoaderSearch: *mut c_void,
+    pub SetVerboseFlag: *mut c_void,
+    pub AddToSystemClassLoaderSearch: *mut c_void,
+    pub RetransformClasses: *mut c_void,
+    pub GetOwnedMonitorStackDepthInfo: *mut c_void,
+    pub GetObjectSize: *mut c_void,
+    pub GetLocalInstance: *mut c_void,
+    pub SetHeapSamplingInterval: *mut c_void,
+}
@@ -0,0 +1,96 @@
+use jni_sys::jint;
+
+use super::sys;
+
+/// JVMTI Version
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[repr(transparent)]
+pub struct JvmtiVersion {
+    ver: u32,
+}
+
+impl JvmtiVersion {
+    /// JVMTI Version 1.0
+    pub const V1_0: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_1_0 as u32,
+    };
+    /// JVMTI Version 1.1
+    pub const V1_1: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_1_1 as u32,
+    };
+    /// JVMTI Version 1.2
+    pub const V1_2: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_1_2 as u32,
+    };
+    /// JVMTI Version 9.0
+    pub const V9: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_9 as u32,
+    };
+    /// JVMTI Version 11.0
+    pub const V11: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_11 as u32,
+    };
+    /// JVMTI Version 19.0
+    pub const V19: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_19 as u32,
+    };
+    /// JVMTI Version 21.0
+    pub const V21: Self = JvmtiVersion {
+        ver: sys::JVMTI_VERSION_21 as u32,
+    };
+
+    /// Return a version from a raw version constant like [`sys::JVMTI_VERSION_1_2`]
+    pub fn new(ver: jint) -> Self {
+        Self::from(ver)
+    }
+
+    /// Get the major component of the version number
+    pub fn major(&self) -> u16 {
+        ((self.ver & 0x0fff0000) >> 16) as u16
+    }
+
+    /// Get the minor component of the version number
+    pub fn minor(&self) -> u8 {
+        ((self.ver & 0xff00) >> 8) as u8
+    }
+
+    /// Get the micro component of the version number
+    pub fn micro(&self) -> u8 {
+        (self.ver & 0xff) as u8
+    }
+}
+
+impl From<jint> for JvmtiVersion {
+    fn from(value: jint) -> Self {
+        Self { ver: value as u32 }
+    }
+}
+
+impl From<JvmtiVersion> for jint {
+    fn from(val: JvmtiVersion) -> Self {
+        val.ver as i32
+    }
+}
+
+#[test]
+fn jvmti_version_major_minor() {
+    macro_rules! check_major_minor {
+        ($major:expr, $minor:expr, $jvmti_ver:tt, $sys_ver:tt) => {
+            let v = JvmtiVersion::$jvmti_ver;
+            assert_eq!(v.major(), $major);
+            assert_eq!(v.minor(), $minor);
+            assert_eq!(v.micro(), 0);
+            let v = JvmtiVersion::new(sys::$sys_ver);
+            assert_eq!(v.major(), $major);
+            assert_eq!(v.minor(), $minor);
+        };
+    }
+
+    check_major_minor!(1, 0, V1_0, JVMTI_VERSION_1_0);
+    check_major_minor!(1, 1, V1_1, JVMTI_VERSION_1_1);
+    check_major_minor!(1, 2, V1_2, JVMTI_VERSION_1_2);
+    check_major_minor!(9, 0, V9, JVMTI_VERSION_9);
+    check_major_minor!(11, 0, V11, JVMTI_VERSION_11);
+    check_major_minor!(19, 0, V19, JVMTI_VERSION_19);
+    check_major_minor!(21, 0, V21, JVMTI_VERSION_21);
+}
@@ -240,6 +240,8 @@
 //! - [`bind_java_type`] for generating full Rust bindings for Java types
 //! - [`native_method`] for individual native method bindings
 //! - [`jni_mangle`] for mangling native method names
+//! - [`agent`] for exporting JVMTI agent entry points (`Agent_OnLoad` etc)
+//! - [`on_load`] and [`on_unload`] for exporting `JNI_OnLoad` and `JNI_OnUnload`
 //!
 //! ### JNI Documentation
 //! - [Java Native Interface Specification][jni-spec]
@@ -342,6 +344,9 @@ pub mod elements;
 /// [modified UTF-8]: https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8
 pub mod strings;
 
+/// Conversions between Java values and Rust types, such as `String`, `Vec` and `HashMap`.
+pub mod convert;
+
 mod env;
 pub use env::*;
 
@@ -385,6 +390,21 @@ pub type JNIEnv<'frame> = EnvUnowned<'frame>;
 /// Java VM interface.
 pub mod vm;
 
+/// JVM Tool Interface (JVMTI) bindings.
+pub mod jvmti;
+
+/// Running jobs on threads that are permanently attached to the Java VM.
+pub mod executor;
+
+/// Implementing Java interfaces in Rust via `java.lang.reflect.Proxy`.
+pub mod proxy;
+
+/// Rust values that are owned by Java objects and dropped by a `java.lang.ref.Cleaner`.
+pub mod handle;
+
+// Java classes that are embedded in this crate and defined at runtime
+mod native_classes;
+
 #[doc(hidden)]
 #[deprecated(
     since = "0.22.0",
@@ -404,6 +424,14 @@ pub use jni_macros::jni_str;
 #[doc = include_str!("../docs/macros/jni_mangle.md")]
 pub use jni_macros::jni_mangle;
 
+#[doc = include_str!("../docs/macros/agent.md")]
+pub use jni_macros::agent;
+
+#[doc = include_str!("../docs/macros/on_load.md")]
+pub use jni_macros::on_load;
+#[doc = include_str!("../docs/macros/on_unload.md")]
+pub use jni_macros::on_unload;
+
 #[doc = include_str!("../docs/macros/jni_sig.md")]
 pub use jni_macros::jni_sig;
 #[doc(inline)]
@@ -436,25 +464,55 @@ pub fn __must_use<T>(v: T) -> T {
 // in a panic here when the binding initialization fails.
 #[doc(hidden)]
 pub fn __test_bindings_init(env: &crate::Env, loader: &crate::refs::LoaderContext) {
+    objects::JBooleanAPI::get(env, loader).expect("Failed to initialize JBooleanAPI bindings");
+    objects::JByteAPI::get(env, loader).expect("Failed to initialize JByteAPI bindings");
     objects::JByteBufferAPI::get(env, loader)
         .expect("Failed to initialize JByteBufferAPI bindings");
+    objects::JCharacterAPI::get(env, loader).expect("Failed to initialize JCharacterAPI bindings");
     objects::JClassLoaderAPI::get(env, loader)
         .expect("Failed to initialize JClassLoaderAPI bindings");
     objects::JClassAPI::get(env, loader).expect("Failed to initialize JClassAPI bindings");
+    objects::JCompletableFutureAPI::get(env, loader)
+        .expect("Failed to initialize JCompletableFutureAPI bindings");
+    objects::JConstructorAPI::get(env, loader)
+        .expect("Failed to initialize JConstructorAPI bindings");
     objects::JCharSequenceAPI::get(env, loader)
         .expect("Failed to initialize JCharSequenceAPI bindings");
     objects::JCollectionAPI::get(env, loader)
         .expect("Failed to initialize JCollectionAPI bindings");
+    objects::JExecutableAPI::get(env, loader)
+        .expect("Failed to initialize JExecutableAPI bindings");
+    objects::JDoubleAPI::get(env, loader).expect("Failed to initialize JDoubleAPI bindings");
+    objects::JFieldAPI::get(env, loader).expect("Failed to initialize JFieldAPI bindings");
+    objects::JFloatAPI::get(env, loader).expect("Failed to initialize JFloatAPI bindings");
+    objects::JFunctionAPI::get(env, loader).expect("Failed to initialize JFunctionAPI bindings");
+    objects::JBiFunctionAPI::get(env, loader)
+        .expect("Failed to initialize JBiFunctionAPI bindings");
+    objects::JSupplierAPI::get(env, loader).expect("Failed to initialize JSupplierAPI bindings");
+    objects::JConsumerAPI::get(env, loader).expect("Failed to initialize JConsumerAPI bindings");
+    objects::JComparatorAPI::get(env, loader)
+        .expect("Failed to initialize JComparatorAPI bindings");
+    objects::JPredicateAPI::get(env, loader).expect("Failed to initialize JPredicateAPI bindings");
+    objects::JRunnableAPI::get(env, loader).expect("Failed to initialize JRunnableAPI bindings");
+    objects::JIntegerAPI::get(env, loader).expect("Failed to initialize JIntegerAPI bindings");
     objects::JIteratorAPI::get(env, loader).expect("Failed to initialize JIteratorAPI bindings");
-    objects::JListAPI::get(env, loader).expect("Failed to initialize JListAPI bindings");
-    objects::JMapAPI::get(env, loader).expect("Failed to initialize JMapAPI bindings");
+    objects::JLongAPI::get(env, loader).expect("Failed to initialize JLongAPI bindings");
+    objects::JNumberAPI::get(env, loader).expect("Failed to initialize JNumberAPI bindings");
+    objects::JShortAPI::get(env, loader).expect("Failed to initialize JShortAPI bindings");
+    objects::JListMethodsAPI::get(env, loader)
+        .expect("Failed to initialize JListMethodsAPI bindings");
+    objects::JMapMethodsAPI::get(env, loader)
+        .expect("Failed to initialize JMapMethodsAPI bindings");
     objects::JMapEntryAPI::get(env, loader).expect("Failed to initialize JMapEntryAPI bindings");
+    objects::JMethodAPI::get(env, loader).expect("Failed to initialize JMethodAPI bindings");
+    objects::JModuleAPI::get(env, loader).expect("Failed to initialize JModuleAPI bindings");
     objects::JObjectArrayAPI::<objects::JString>::get(env, loader)
         .expect("Failed to initialize JObjectArrayAPI<JString> bindings");
     objects::JObjectAPI::get(env).expect("Failed to initialize JObjectAPI bindings");
     objects::JPrimitiveArrayAPI_jboolean::get(env, loader)
         .expect("Failed to initialize JPrimitiveArrayAPI_jboolean bindings");
-    objects::JSetAPI::get(env, loader).expect("Failed to initialize JSetAPI bindings");
+    objects::JSetMethodsAPI::get(env, loader)
+        .expect("Failed to initialize JSetMethodsAPI bindings");
     objects::JStackTraceElementAPI::get(env, loader)
         .expect("Failed to initialize JStackTraceElementAPI bindings");
     objects::JStringAPI::get(env, loader).expect("Failed to initialize JStringAPI bindings");
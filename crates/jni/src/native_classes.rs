@@ -0,0 +1,137 @@
+//! Support for Java classes that are embedded in this crate and defined at runtime, for bindings
+//! that need a Java object whose methods are implemented in Rust (such as a completion callback).
+//!
+//! The Java sources live under `java/src` and are compiled into `java/classes`, targeting the
+//! oldest Java release that supports the APIs they use. The `embedded_classes` test checks that
+//! the committed classes match their sources, and its `SOURCES` list gives the release for each
+//! source (which can be recompiled with `javac --release <release> -cp java/classes -d
+//! java/classes java/src/<source>`).
+//!
+//! The classes are defined with [`Env::define_class`] from the `load_class` hook of their
+//! `bind_java_type!` bindings, which means that their native methods are registered as soon as
+//! the class is defined.
+//!
+//! The classes are defined in a class loader that's private to this crate (see
+//! [`define_embedded_class`]), instead of the application class loader, so they can't conflict
+//! with the same embedded classes of another library (e.g. another library that uses `jni`)
+//! that's loaded into the same JVM.
+//!
+//! Note: `DefineClass` isn't supported on Android.
+
+use std::sync::{Mutex, OnceLock};
+
+use crate::{
+    Env,
+    errors::{Error, Result},
+    jni_sig, jni_str,
+    objects::{Global, JClass, JClassLoader, JObject},
+    strings::JNIStr,
+    sys::jlong,
+};
+
+/// Defines an embedded class, or returns a new local reference to the class if it was already
+/// defined.
+///
+/// The `cache` should be a `static` that's specific to the class.
+///
+/// All embedded classes are defined in the same class loader, which is created the first time a
+/// class is defined. This is a `URLClassLoader` with no URLs, whose parent is the bootstrap class
+/// loader, so the embedded classes (which only depend on `java.*` classes and each other) are
+/// resolved without ever consulting the application class loader.
+pub(crate) fn define_embedded_class<'local>(
+    env: &mut Env<'local>,
+    cache: &OnceLock<Global<JClass<'static>>>,
+    name: &JNIStr,
+    bytes: &[u8],
+) -> Result<JClass<'local>> {
+    // Serialize definitions, since defining the same class twice would fail with a `LinkageError`
+    // if two threads race to initialize a binding
+    static DEFINE_LOCK: Mutex<()> = Mutex::new(());
+    let _guard = DEFINE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    static LOADER: OnceLock<Global<JClassLoader<'static>>> = OnceLock::new();
+
+    let class = match cache.get() {
+        Some(class) => class,
+        None => {
+            let loader = match LOADER.get() {
+                Some(loader) => loader,
+                None => {
+                    let loader = new_embedded_class_loader(env)?;
+                    let _ = LOADER.set(env.new_global_ref(loader)?);
+                    LOADER.get().unwrap()
+                }
+            };
+            let class = env.define_class(Some(name), loader, bytes)?;
+            let _ = cache.set(env.new_global_ref(class)?);
+            cache.get().unwrap()
+        }
+    };
+    env.new_local_ref(class)
+}
+
+/// Creates the class loader that embedded classes are defined in.
+fn new_embedded_class_loader<'local>(env: &mut Env<'local>) -> Result<JClassLoader<'local>> {
+    env.with_local_frame_returning_local::<_, JClassLoader, _>(4, |env| {
+        let urls = env.new_object_array(0, jni_str!("java/net/URL"), JObject::null())?;
+        let loader = env.new_object(
+            jni_str!("java/net/URLClassLoader"),
+            jni_sig!("([Ljava/net/URL;Ljava/lang/ClassLoader;)V"),
+            &[(&urls).into(), (&JObject::null()).into()],
+        )?;
+        env.cast_local::<JClassLoader>(loader)
+    })
+}
+
+/// Returns the `dropFn` for a `RustCleanup` handle that was created with `Box::into_raw` for a
+/// `Box<T>`.
+pub(crate) fn box_drop_fn<T>() -> jlong {
+    unsafe fn drop_box<T>(handle: jlong) {
+        // Safety: the caller guarantees that the handle came from `Box::<T>::into_raw`
+        drop(unsafe { Box::from_raw(handle as *mut T) });
+    }
+    drop_box::<T> as unsafe fn(jlong) as usize as jlong
+}
+
+crate::bind_java_type! {
+    pub(crate) RustCleanup => "io.github.jni_rs.RustCleanup",
+    native_methods_export = false,
+    hooks {
+        load_class = |env, _loader_context, _initialize| {
+            static CLASS: OnceLock<Global<JClass<'static>>> = OnceLock::new();
+            define_embedded_class(
+                env,
+                &CLASS,
+                jni_str!("io/github/jni_rs/RustCleanup"),
+                include_bytes!("../java/classes/io/github/jni_rs/RustCleanup.class"),
+            )
+        }
+    },
+    methods {
+        /// Registers a cleaning action that releases the `handle` (by calling `drop_fn`) once
+        /// `owner` becomes unreachable.
+        static fn register(owner: JObject, handle: jlong, drop_fn: jlong) -> void,
+    },
+    native_methods {
+        static fn release(handle: jlong, drop_fn: jlong) -> void,
+    }
+}
+
+impl RustCleanupNativeInterface for RustCleanupAPI {
+    type Error = Error;
+
+    fn release<'local>(
+        _env: &mut Env<'local>,
+        _class: JClass<'local>,
+        handle: jlong,
+        drop_fn: jlong,
+    ) -> Result<()> {
+        // Safety: the `drop_fn` was created by `box_drop_fn` for the type of the handle, and the
+        // Java cleaning action only releases the handle once
+        unsafe {
+            let drop_fn = std::mem::transmute::<usize, unsafe fn(jlong)>(drop_fn as usize);
+            drop_fn(handle);
+        }
+        Ok(())
+    }
+}
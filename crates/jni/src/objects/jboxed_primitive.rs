@@ -0,0 +1,200 @@
+use crate::{
+    Env,
+    errors::{Error, Result},
+    objects::Reference as _,
+    sys::{jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort},
+};
+
+crate::bind_java_type! {
+    pub JNumber => "java.lang.Number",
+    methods {
+        /// Returns the value of this number as a `byte`, which may involve rounding or truncation.
+        fn byte_value() -> jbyte,
+        /// Returns the value of this number as a `short`, which may involve rounding or truncation.
+        fn short_value() -> jshort,
+        /// Returns the value of this number as an `int`, which may involve rounding or truncation.
+        fn int_value() -> jint,
+        /// Returns the value of this number as a `long`, which may involve rounding or truncation.
+        fn long_value() -> jlong,
+        /// Returns the value of this number as a `float`, which may involve rounding.
+        fn float_value() -> jfloat,
+        /// Returns the value of this number as a `double`, which may involve rounding.
+        fn double_value() -> jdouble,
+    }
+}
+
+crate::bind_java_type! {
+    pub JByte => "java.lang.Byte",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns a `Byte` instance representing the given `value`.
+        ///
+        /// All `Byte` values are cached by Java, so this never allocates a new object.
+        static fn value_of(value: jbyte) -> JByte,
+        /// Returns the value of this `Byte`.
+        fn byte_value() -> jbyte,
+    }
+}
+
+crate::bind_java_type! {
+    pub JShort => "java.lang.Short",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns a `Short` instance representing the given `value`.
+        ///
+        /// Values in the range -128 to 127 are cached by Java.
+        static fn value_of(value: jshort) -> JShort,
+        /// Returns the value of this `Short`.
+        fn short_value() -> jshort,
+    }
+}
+
+crate::bind_java_type! {
+    pub JInteger => "java.lang.Integer",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns an `Integer` instance representing the given `value`.
+        ///
+        /// Values in the range -128 to 127 are cached by Java.
+        static fn value_of(value: jint) -> JInteger,
+        /// Returns the value of this `Integer`.
+        fn int_value() -> jint,
+    }
+}
+
+crate::bind_java_type! {
+    pub JLong => "java.lang.Long",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns a `Long` instance representing the given `value`.
+        ///
+        /// Values in the range -128 to 127 are cached by Java.
+        static fn value_of(value: jlong) -> JLong,
+        /// Returns the value of this `Long`.
+        fn long_value() -> jlong,
+    }
+}
+
+crate::bind_java_type! {
+    pub JFloat => "java.lang.Float",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns a `Float` instance representing the given `value`.
+        static fn value_of(value: jfloat) -> JFloat,
+        /// Returns the value of this `Float`.
+        fn float_value() -> jfloat,
+    }
+}
+
+crate::bind_java_type! {
+    pub JDouble => "java.lang.Double",
+    is_instance_of {
+        number = JNumber,
+    },
+    methods {
+        /// Returns a `Double` instance representing the given `value`.
+        static fn value_of(value: jdouble) -> JDouble,
+        /// Returns the value of this `Double`.
+        fn double_value() -> jdouble,
+    }
+}
+
+crate::bind_java_type! {
+    pub JBoolean => "java.lang.Boolean",
+    methods {
+        /// Returns `Boolean.TRUE` or `Boolean.FALSE`, for the given `value`.
+        static fn value_of(value: jboolean) -> JBoolean,
+        /// Returns the value of this `Boolean`.
+        fn boolean_value() -> jboolean,
+    }
+}
+
+crate::bind_java_type! {
+    pub JCharacter => "java.lang.Character",
+    methods {
+        /// Returns a `Character` instance representing the given UTF-16 code unit.
+        ///
+        /// Values in the range `'\u{0}'` to `'\u{7f}'` are cached by Java.
+        static fn value_of(value: jchar) -> JCharacter,
+        /// Returns the value of this `Character`, as a UTF-16 code unit.
+        fn char_value() -> jchar,
+    }
+}
+
+/// A binding for one of the `java.lang` wrapper classes for a primitive type, such as
+/// [`JInteger`] for `int`
+///
+/// This provides conversions between the Java wrapper objects and Rust primitives, via the
+/// (cached) method IDs of the binding's `valueOf` and `xxxValue` methods.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, objects::{BoxedPrimitive, JInteger}};
+/// # fn example<'local>(env: &mut Env<'local>) -> Result<()> {
+/// let boxed = JInteger::try_from_primitive(env, 42)?;
+/// assert_eq!(boxed.try_to_primitive(env)?, 42);
+/// # Ok(())
+/// # }
+/// ```
+pub trait BoxedPrimitive<'local>: Sized {
+    /// The Rust primitive type that is boxed by this class
+    type Primitive: Copy;
+
+    /// Boxes the given `value`, via the wrapper class's `valueOf` method.
+    fn try_from_primitive(env: &mut Env<'local>, value: Self::Primitive) -> Result<Self>;
+
+    /// Unboxes this wrapper object.
+    ///
+    /// Returns [`Error::NullPtr`] if this is a `null` reference.
+    fn try_to_primitive(&self, env: &Env<'_>) -> Result<Self::Primitive>;
+}
+
+macro_rules! impl_boxed_primitive {
+    ($type:ident, $primitive:ty, $getter:ident) => {
+        impl<'local> BoxedPrimitive<'local> for $type<'local> {
+            type Primitive = $primitive;
+
+            fn try_from_primitive(env: &mut Env<'local>, value: $primitive) -> Result<Self> {
+                $type::value_of(env, value)
+            }
+
+            fn try_to_primitive(&self, env: &Env<'_>) -> Result<$primitive> {
+                if self.is_null() {
+                    return Err(Error::NullPtr(concat!(stringify!($type), " value")));
+                }
+                self.$getter(env)
+            }
+        }
+    };
+}
+
+impl_boxed_primitive!(JByte, jbyte, byte_value);
+impl_boxed_primitive!(JShort, jshort, short_value);
+impl_boxed_primitive!(JInteger, jint, int_value);
+impl_boxed_primitive!(JLong, jlong, long_value);
+impl_boxed_primitive!(JFloat, jfloat, float_value);
+impl_boxed_primitive!(JDouble, jdouble, double_value);
+impl_boxed_primitive!(JBoolean, jboolean, boolean_value);
+impl_boxed_primitive!(JCharacter, jchar, char_value);
+
+impl JCharacter<'_> {
+    /// Returns the value of this `Character` as a Rust `char`.
+    ///
+    /// Returns `None` if the value is a UTF-16 surrogate code unit, which isn't a valid `char` on
+    /// its own.
+    pub fn try_to_char(&self, env: &Env<'_>) -> Result<Option<char>> {
+        let value = self.try_to_primitive(env)?;
+        Ok(char::from_u32(value as u32))
+    }
+}
@@ -1,3 +1,11 @@
+use crate::{
+    Env,
+    convert::FromJava,
+    errors::Result,
+    objects::{JIter, JObject, convert_element},
+    refs::Global,
+};
+
 crate::bind_java_type! {
     pub JCollection => "java.util.Collection",
     methods {
@@ -58,3 +66,35 @@ crate::bind_java_type! {
         fn to_array() -> JObject[],
     }
 }
+
+impl<'local> JCollection<'local> {
+    /// Returns a Rust [`Iterator`] over the elements in this collection, which converts each
+    /// element into `T` via its [`FromJava`] implementation.
+    ///
+    /// Each element is fetched and converted within its own local reference frame. See [`JIter`]
+    /// for more information.
+    pub fn iter_as<'env, 'env_local, T>(
+        &self,
+        env: &'env mut Env<'env_local>,
+    ) -> Result<JIter<'env, 'env_local, T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        let iterator = self.iterator(env)?;
+        Ok(JIter::new(env, iterator, convert_element::<T>))
+    }
+
+    /// Converts all of the elements in this collection into a `Vec<T>`, via the [`FromJava`]
+    /// implementation of `T`.
+    pub fn to_vec<T>(&self, env: &mut Env<'_>) -> Result<Vec<T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        self.iter_as(env)?.to_vec()
+    }
+
+    /// Returns global references to all of the elements in this collection.
+    pub fn collect_globals(&self, env: &mut Env<'_>) -> Result<Vec<Global<JObject<'static>>>> {
+        self.iter_as(env)?.collect_globals()
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    future::{Future, poll_fn},
+    marker::PhantomData,
+    panic::{AssertUnwindSafe, catch_unwind},
+    pin::{Pin, pin},
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    Env,
+    errors::{Error, ErrorPolicy, Result, ThrowRuntimeExAndDefault, ToException},
+    jni_str,
+    objects::{Global, JClass, JObject, JThrowable},
+    strings::JNIString,
+    sys::jlong,
+};
+
+crate::bind_java_type! {
+    pub JCompletableFuture => "java.util.concurrent.CompletableFuture",
+    constructors {
+        /// Creates a new, incomplete `CompletableFuture`.
+        fn new(),
+    },
+    methods {
+        /// Returns a new `CompletableFuture` that's already completed with the given `value`.
+        static fn completed_future(value: JObject) -> JCompletableFuture,
+        /// Completes this future with the given `value`, if it's not already completed.
+        ///
+        /// Returns `true` if this call caused the future to transition to a completed state.
+        fn complete(value: JObject) -> bool,
+        /// Completes this future with the given exception, if it's not already completed.
+        ///
+        /// Returns `true` if this call caused the future to transition to a completed state.
+        fn complete_exceptionally(exception: JThrowable) -> bool,
+        /// Cancels this future (completing it with a `CancellationException`) if it's not already
+        /// completed.
+        ///
+        /// The `may_interrupt_if_running` argument has no effect for a `CompletableFuture`.
+        fn cancel(may_interrupt_if_running: bool) -> bool,
+        /// Returns `true` if this future has completed in any fashion: normally, exceptionally or
+        /// via cancellation.
+        fn is_done() -> bool,
+        /// Returns `true` if this future was cancelled before it completed normally.
+        fn is_cancelled() -> bool,
+        /// Returns `true` if this future completed exceptionally, including via cancellation.
+        fn is_completed_exceptionally() -> bool,
+        /// Returns the result value if completed, or else the given `value_if_absent`.
+        ///
+        /// # Throws
+        ///
+        /// - `CancellationException` - if the future was cancelled
+        /// - `CompletionException` - if the future completed exceptionally
+        fn get_now(value_if_absent: JObject) -> JObject,
+        /// Waits for this future to complete and returns its result value.
+        ///
+        /// This blocks the current thread.
+        ///
+        /// # Throws
+        ///
+        /// - `CancellationException` - if the future was cancelled
+        /// - `CompletionException` - if the future completed exceptionally
+        fn join() -> JObject,
+        /// Returns a new `CompletableFuture` with the same result or exception as this future,
+        /// that runs the given `java.util.function.BiConsumer` action when this future completes.
+        fn when_complete(action: java.util.function.BiConsumer) -> JCompletableFuture,
+    }
+}
+
+impl<'local> JCompletableFuture<'local> {
+    /// Returns a Rust future that awaits the given `future` and then completes this Java
+    /// `CompletableFuture` with its output.
+    ///
+    /// This is runtime agnostic: the returned future needs to be spawned on (or otherwise driven
+    /// by) some async executor. When it's done, it attaches the current thread to the JVM (if
+    /// necessary) in order to complete the Java future.
+    ///
+    /// If `future` resolves to `Ok(value)` then this Java future is completed with `value`.
+    ///
+    /// If `future` resolves to an error, then the error is converted with [`ToException`] and the
+    /// Java future is completed exceptionally with a new instance of that exception class. If
+    /// `future` panics, then the Java future is completed exceptionally with a
+    /// `java.lang.RuntimeException`.
+    ///
+    /// See [`JCompletableFuture::complete_with_policy`] to map errors via an [`ErrorPolicy`]
+    /// instead.
+    ///
+    /// This can be used to implement a native method that's `async` from the point of view of
+    /// Java, by returning a new `CompletableFuture` that's completed by a spawned Rust future:
+    ///
+    /// ```rust,no_run
+    /// # use jni::{Env, errors::{Exception, ToException}, objects::{Global, JCompletableFuture, JString}};
+    /// # fn spawn(_: impl std::future::Future<Output = ()> + Send + 'static) {}
+    /// # async fn fetch(_: &str) -> Result<Global<JString<'static>>, FetchError> { todo!() }
+    /// # struct FetchError;
+    /// # impl ToException for FetchError {
+    /// #     fn to_exception(&self) -> Exception {
+    /// #         Exception { class: "java/io/IOException".into(), msg: "fetch failed".into() }
+    /// #     }
+    /// # }
+    /// fn fetch_async<'local>(env: &mut Env<'local>) -> jni::errors::Result<JCompletableFuture<'local>> {
+    ///     let java_future = JCompletableFuture::new(env)?;
+    ///     spawn(java_future.complete_with(env, async { fetch("https://example.com").await })?);
+    ///     Ok(java_future)
+    /// }
+    /// ```
+    pub fn complete_with<F, T, E>(
+        &self,
+        env: &Env<'_>,
+        future: F,
+    ) -> Result<impl Future<Output = ()> + Send + use<F, T, E>>
+    where
+        F: Future<Output = std::result::Result<T, E>> + Send + 'static,
+        T: AsRef<JObject<'static>> + Send + 'static,
+        E: ToException + Send + 'static,
+    {
+        self.complete_with_policy::<ThrowToException, F, T, E>(env, future)
+    }
+
+    /// Returns a Rust future that awaits the given `future` and then completes this Java
+    /// `CompletableFuture` with its output, mapping errors and panics via the `P` error policy.
+    ///
+    /// This is the same as [`JCompletableFuture::complete_with`] except that errors and panics
+    /// are passed to the [`ErrorPolicy`] `P`, and then this Java future is completed
+    /// exceptionally with whatever exception the policy throws (such as the
+    /// `java.lang.RuntimeException` thrown by [`ThrowRuntimeExAndDefault`]).
+    ///
+    /// If the policy doesn't throw an exception (for example because it only logs the error) then
+    /// the Java future is completed exceptionally with a `java.lang.RuntimeException`, so that
+    /// Java code waiting on the future doesn't wait forever.
+    pub fn complete_with_policy<P, F, T, E>(
+        &self,
+        env: &Env<'_>,
+        future: F,
+    ) -> Result<impl Future<Output = ()> + Send + use<P, F, T, E>>
+    where
+        P: for<'unowned_env_local> ErrorPolicy<
+                (),
+                E,
+                Captures<'unowned_env_local, 'unowned_env_local> = (),
+            > + 'static,
+        F: Future<Output = std::result::Result<T, E>> + Send + 'static,
+        T: AsRef<JObject<'static>> + Send + 'static,
+        E: Send + 'static,
+    {
+        let vm = env.get_java_vm()?;
+        let java_future = env.new_global_ref(self)?;
+        let policy = PhantomData::<fn() -> P>;
+
+        Ok(async move {
+            let _policy = policy;
+            let mut future = pin!(future);
+            let outcome =
+                poll_fn(
+                    |cx| match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+                        Ok(Poll::Pending) => Poll::Pending,
+                        Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+                        Err(payload) => Poll::Ready(Err(payload)),
+                    },
+                )
+                .await;
+
+            let res = vm.attach_current_thread(|env| -> Result<()> {
+                let java_future: &JCompletableFuture = &java_future;
+                let policy_result = match outcome {
+                    Ok(Ok(value)) => {
+                        java_future.complete(env, value.as_ref())?;
+                        return Ok(());
+                    }
+                    Ok(Err(err)) => P::on_error(env, &mut (), err),
+                    Err(payload) => P::on_panic(env, &mut (), payload),
+                };
+                if let Err(err) = policy_result {
+                    log::error!("Failed to map Rust future error to a Java exception: {err}");
+                }
+                complete_exceptionally_with_pending(env, java_future)
+            });
+            if let Err(err) = res {
+                log::error!("Failed to complete Java CompletableFuture: {err}");
+            }
+        })
+    }
+
+    /// Returns a Rust future that resolves when this Java `CompletableFuture` completes.
+    ///
+    /// This registers a completion callback via `whenComplete`, which wakes the Rust future, so
+    /// the Rust future can be polled without being attached to the JVM.
+    ///
+    /// The output is a global reference to the result value, or [`Error::CaughtJavaException`]
+    /// if the Java future completed exceptionally (or was cancelled).
+    ///
+    /// If the Java future never completes then a small amount of memory is leaked for the
+    /// callback state.
+    ///
+    /// # Portability Note
+    ///
+    /// The callback is implemented with a Java class that's defined at runtime via
+    /// [`Env::define_class`], which isn't supported on Android.
+    pub fn to_future(&self, env: &mut Env<'_>) -> Result<CompletionFuture> {
+        let state = Arc::new(Mutex::new(CompletionState::default()));
+
+        // The reference to the state is released by the callback's native `complete` method
+        let handle = Arc::into_raw(state.clone()) as jlong;
+        env.with_local_frame(4, |env| -> Result<()> {
+            let callback = CompletionCallback::new(env, handle)?;
+            // Note: if this fails then the callback state is leaked, since we can't be certain
+            // the callback won't be called
+            self.when_complete(env, &callback)?;
+            Ok(())
+        })?;
+
+        Ok(CompletionFuture { state })
+    }
+}
+
+/// Completes the Java future exceptionally with the pending exception (clearing it), or with a
+/// `RuntimeException` if there's no pending exception.
+fn complete_exceptionally_with_pending(
+    env: &mut Env<'_>,
+    java_future: &JCompletableFuture,
+) -> Result<()> {
+    if !env.exception_check() {
+        let _ = env.throw_new(
+            jni_str!("java/lang/RuntimeException"),
+            jni_str!("Rust future failed without throwing an exception"),
+        );
+    }
+    let exception = env.exception_occurred();
+    env.exception_clear();
+    match exception {
+        Some(exception) => {
+            java_future.complete_exceptionally(env, &exception)?;
+            Ok(())
+        }
+        None => Err(Error::JavaException),
+    }
+}
+
+/// An [`ErrorPolicy`] that throws the exception given by [`ToException`] for errors.
+struct ThrowToException;
+
+impl<E: ToException> ErrorPolicy<(), E> for ThrowToException {
+    type Captures<'unowned_env_local: 'native_method, 'native_method> = ();
+
+    fn on_error<'unowned_env_local: 'native_method, 'native_method>(
+        env: &mut Env<'unowned_env_local>,
+        _cap: &mut Self::Captures<'unowned_env_local, 'native_method>,
+        err: E,
+    ) -> Result<()> {
+        let exception = err.to_exception();
+        // Note: `throw_new` returns `Err(Error::JavaException)` after throwing
+        let class = JNIString::from(exception.class);
+        match env.throw_new(&class, JNIString::from(exception.msg)) {
+            Ok(()) | Err(Error::JavaException) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn on_panic<'unowned_env_local: 'native_method, 'native_method>(
+        env: &mut Env<'unowned_env_local>,
+        cap: &mut Self::Captures<'unowned_env_local, 'native_method>,
+        payload: Box<dyn std::any::Any + Send + 'static>,
+    ) -> Result<()> {
+        <ThrowRuntimeExAndDefault as ErrorPolicy<(), Error>>::on_panic(env, cap, payload)
+    }
+}
+
+/// The state that's shared between a [`CompletionFuture`] and its Java completion callback
+#[derive(Default)]
+struct CompletionState {
+    result: Option<Result<Global<JObject<'static>>>>,
+    waker: Option<Waker>,
+}
+
+/// A Rust [`Future`] that resolves when a Java `CompletableFuture` completes
+///
+/// See [`JCompletableFuture::to_future`].
+#[derive(Debug)]
+pub struct CompletionFuture {
+    state: Arc<Mutex<CompletionState>>,
+}
+
+impl std::fmt::Debug for CompletionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompletionState")
+            .field("completed", &self.result.is_some())
+            .finish()
+    }
+}
+
+impl Future for CompletionFuture {
+    type Output = Result<Global<JObject<'static>>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+crate::bind_java_type! {
+    CompletionCallback => "io.github.jni_rs.CompletionCallback",
+    native_methods_export = false,
+    hooks {
+        load_class = |env, _loader_context, _initialize| {
+            static CLASS: OnceLock<Global<JClass<'static>>> = OnceLock::new();
+            crate::native_classes::define_embedded_class(
+                env,
+                &CLASS,
+                jni_str!("io/github/jni_rs/CompletionCallback"),
+                include_bytes!("../../java/classes/io/github/jni_rs/CompletionCallback.class"),
+            )
+        }
+    },
+    constructors {
+        fn new(handle: jlong),
+    },
+    native_methods {
+        static fn complete(handle: jlong, value: JObject, error: JThrowable) -> void,
+    }
+}
+
+impl CompletionCallbackNativeInterface for CompletionCallbackAPI {
+    type Error = Error;
+
+    fn complete<'local>(
+        env: &mut Env<'local>,
+        _class: JClass<'local>,
+        handle: jlong,
+        value: JObject<'local>,
+        error: JThrowable<'local>,
+    ) -> Result<()> {
+        // Safety: the handle was created by `JCompletableFuture::to_future` via `Arc::into_raw`,
+        // and the Java callback only passes it to this method once
+        let state = unsafe { Arc::from_raw(handle as *const Mutex<CompletionState>) };
+
+        let result = if error.is_null() {
+            env.new_global_ref(value)
+        } else {
+            // Throwing and catching the exception is the simplest way to get an
+            // `Error::CaughtJavaException` with the name, message and stack trace
+            let _ = env.throw(error);
+            env.exception_catch().and(Err(Error::JavaException))
+        };
+
+        let waker = {
+            let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+}
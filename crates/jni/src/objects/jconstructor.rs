@@ -0,0 +1,31 @@
+crate::bind_java_type! {
+    pub JConstructor => "java.lang.reflect.Constructor",
+    is_instance_of {
+        executable = JExecutable,
+    },
+    methods {
+        /// Returns the binary name of the class that declares this constructor.
+        fn get_name() -> JString,
+        /// Returns the Java language modifiers for the constructor represented by this object.
+        ///
+        /// See `java.lang.reflect.Modifier` for how to decode the returned value.
+        fn get_modifiers() -> jint,
+        /// Returns the class that declares the constructor represented by this object.
+        fn get_declaring_class() -> JClass,
+        /// Returns the types of the formal parameters for the constructor represented by this
+        /// object, in declaration order.
+        fn get_parameter_types() -> JClass[],
+        /// Creates and initializes a new instance of the constructor's declaring class, with the
+        /// given arguments.
+        ///
+        /// Primitive arguments are boxed.
+        ///
+        /// # Throws
+        ///
+        /// - `IllegalAccessException` if the constructor is inaccessible
+        /// - `IllegalArgumentException` if the arguments don't match the constructor parameters
+        /// - `InstantiationException` if the declaring class is abstract
+        /// - `InvocationTargetException` if the constructor itself throws an exception
+        fn new_instance(args: JObject[]) -> JObject,
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Env, errors::Result, sys::jint};
+
+/// The `java.lang.reflect.Modifier.STATIC` modifier bit
+pub(crate) const MODIFIER_STATIC: jint = 0x0008;
+
+crate::bind_java_type! {
+    pub JExecutable => "java.lang.reflect.Executable",
+    methods {
+        /// Returns the name of the executable (method or constructor) represented by this object.
+        fn get_name() -> JString,
+        /// Returns the Java language modifiers for the executable represented by this object.
+        ///
+        /// See `java.lang.reflect.Modifier` for how to decode the returned value.
+        fn get_modifiers() -> jint,
+        /// Returns the class that declares the executable represented by this object.
+        fn get_declaring_class() -> JClass,
+        /// Returns the number of formal parameters for the executable represented by this object.
+        fn get_parameter_count() -> jint,
+        /// Returns the types of the formal parameters for the executable represented by this
+        /// object, in declaration order.
+        fn get_parameter_types() -> JClass[],
+        /// Returns `true` if this executable was declared to take a variable number of arguments.
+        fn is_var_args() -> bool,
+        /// Returns a string describing this executable, including its modifiers and parameter types.
+        fn try_to_string {
+            name = "toString",
+            sig = () -> JString,
+        },
+    }
+}
+
+impl JExecutable<'_> {
+    /// Returns `true` if this executable is a static method.
+    ///
+    /// This is a convenience for checking for the `java.lang.reflect.Modifier.STATIC` bit in
+    /// [`Self::get_modifiers`]. It's also available for a [`JMethod`](crate::objects::JMethod) via
+    /// `method.as_executable().is_static(env)`.
+    pub fn is_static(&self, env: &mut Env<'_>) -> Result<bool> {
+        Ok(self.get_modifiers(env)? & MODIFIER_STATIC != 0)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Env, errors::Result, objects::MODIFIER_STATIC};
+
+crate::bind_java_type! {
+    pub JField => "java.lang.reflect.Field",
+    methods {
+        /// Returns the name of the field represented by this object.
+        fn get_name() -> JString,
+        /// Returns the Java language modifiers for the field represented by this object.
+        ///
+        /// See `java.lang.reflect.Modifier` for how to decode the returned value.
+        fn get_modifiers() -> jint,
+        /// Returns the class that declares the field represented by this object.
+        fn get_declaring_class() -> JClass,
+        /// Returns the declared type of the field represented by this object.
+        fn get_type() -> JClass,
+        /// Returns the value of the field represented by this object, on the given object.
+        ///
+        /// `obj` is ignored (and may be `null`) for static fields. Primitive values are boxed.
+        ///
+        /// # Throws
+        ///
+        /// - `IllegalAccessException` if the field is inaccessible
+        /// - `IllegalArgumentException` if `obj` is not an instance of the declaring class
+        fn get(obj: JObject) -> JObject,
+        /// Sets the value of the field represented by this object, on the given object.
+        ///
+        /// `obj` is ignored (and may be `null`) for static fields. Primitive values must be boxed.
+        ///
+        /// # Throws
+        ///
+        /// - `IllegalAccessException` if the field is inaccessible or final
+        /// - `IllegalArgumentException` if `obj` is not an instance of the declaring class or the
+        ///   value can't be converted to the field type
+        fn set(obj: JObject, value: JObject) -> (),
+        /// Returns a string describing this field, including its modifiers and type.
+        fn try_to_string {
+            name = "toString",
+            sig = () -> JString,
+        },
+    }
+}
+
+impl JField<'_> {
+    /// Returns `true` if this is a static field, based on [`Self::get_modifiers`].
+    pub fn is_static(&self, env: &mut Env<'_>) -> Result<bool> {
+        Ok(self.get_modifiers(env)? & MODIFIER_STATIC != 0)
+    }
+}
@@ -0,0 +1,360 @@
+use std::sync::OnceLock;
+
+use crate::{
+    Env,
+    errors::{Error, Result},
+    jni_sig, jni_str,
+    native_classes::{RustCleanupAPI, box_drop_fn, define_embedded_class},
+    objects::{Global, JClass, JObject, JValue},
+    refs::LoaderContext,
+    strings::JNIStr,
+    sys::jlong,
+};
+
+crate::bind_java_type! {
+    pub JFunction => "java.util.function.Function",
+    methods {
+        /// Applies this function to the given argument.
+        fn apply(t: JObject) -> JObject,
+    }
+}
+
+crate::bind_java_type! {
+    pub JBiFunction => "java.util.function.BiFunction",
+    methods {
+        /// Applies this function to the given arguments.
+        fn apply(t: JObject, u: JObject) -> JObject,
+    }
+}
+
+crate::bind_java_type! {
+    pub JSupplier => "java.util.function.Supplier",
+    methods {
+        /// Gets a result.
+        fn get() -> JObject,
+    }
+}
+
+crate::bind_java_type! {
+    pub JConsumer => "java.util.function.Consumer",
+    methods {
+        /// Performs this operation on the given argument.
+        fn accept(t: JObject) -> void,
+    }
+}
+
+crate::bind_java_type! {
+    pub JPredicate => "java.util.function.Predicate",
+    methods {
+        /// Evaluates this predicate on the given argument.
+        fn test(t: JObject) -> bool,
+    }
+}
+
+crate::bind_java_type! {
+    pub JComparator => "java.util.Comparator",
+    methods {
+        /// Compares its two arguments for order.
+        ///
+        /// Returns a negative integer, zero, or a positive integer as the first argument is less
+        /// than, equal to, or greater than the second.
+        fn compare(o1: JObject, o2: JObject) -> jint,
+        /// Returns a comparator that compares `Comparable` objects in natural order.
+        static fn natural_order() -> JComparator,
+        /// Returns a comparator that imposes the reverse of the natural ordering.
+        static fn reverse_order() -> JComparator,
+    }
+}
+
+crate::bind_java_type! {
+    pub JRunnable => "java.lang.Runnable",
+    methods {
+        /// Runs this operation.
+        fn run() -> void,
+    }
+}
+
+/// The closure behind every `RustFunction`, except for predicates
+type ObjectFn = Box<
+    dyn for<'local> Fn(
+            &mut Env<'local>,
+            JObject<'local>,
+            JObject<'local>,
+        ) -> Result<JObject<'local>>
+        + Send
+        + Sync,
+>;
+
+/// The closure behind a `RustFunction.OfPredicate`
+type BooleanFn =
+    Box<dyn for<'local> Fn(&mut Env<'local>, JObject<'local>) -> Result<bool> + Send + Sync>;
+
+/// Declares the cache, name and bytecode for a nested class of `RustFunction`
+macro_rules! rust_function_class {
+    ($name:literal, $file:literal) => {{
+        static CLASS: OnceLock<Global<JClass<'static>>> = OnceLock::new();
+        (
+            &CLASS,
+            jni_str!($name),
+            include_bytes!(concat!("../../java/classes/io/github/jni_rs/", $file)),
+        )
+    }};
+}
+
+/// Creates an instance of a nested class of `RustFunction` that owns the given `callback`.
+fn new_rust_function<'local, T: Send + Sync + 'static>(
+    env: &mut Env<'local>,
+    (cache, name, bytes): (&OnceLock<Global<JClass<'static>>>, &JNIStr, &[u8]),
+    callback: T,
+) -> Result<JObject<'local>> {
+    // The superclass must be defined before the nested class, and `RustCleanup` must be defined
+    // before the constructor runs
+    RustFunctionAPI::get(env, &LoaderContext::None)?;
+    RustCleanupAPI::get(env, &LoaderContext::None)?;
+
+    env.with_local_frame_returning_local::<_, JObject, _>(4, |env| {
+        let class = define_embedded_class(env, cache, name, bytes)?;
+        let handle = Box::into_raw(Box::new(callback));
+        let object = env.new_object(
+            &class,
+            jni_sig!("(JJ)V"),
+            &[
+                JValue::Long(handle as jlong),
+                JValue::Long(box_drop_fn::<T>()),
+            ],
+        );
+        if object.is_err() {
+            // Safety: the object wasn't created, or failed to register its cleaning action, so
+            // the handle won't be used or released by Java
+            drop(unsafe { Box::from_raw(handle) });
+        }
+        object
+    })
+}
+
+impl JFunction<'_> {
+    /// Creates a `java.util.function.Function` that's implemented by the given closure.
+    ///
+    /// The closure may be called concurrently by multiple Java threads, and it will be dropped on
+    /// an arbitrary Java thread once the function has been garbage collected.
+    ///
+    /// If the closure returns an error then a Java exception is thrown from `apply`. An
+    /// [`Error::JavaException`] error leaves the pending exception to be thrown, and any other
+    /// error is thrown as a `java.lang.RuntimeException`.
+    ///
+    /// # Portability Note
+    ///
+    /// The function is an instance of a Java class that's defined at runtime via
+    /// [`Env::define_class`] (which isn't supported on Android), and the closure is dropped via a
+    /// `java.lang.ref.Cleaner` which requires Java 9 or later.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::{JFunction, JString}};
+    /// # fn example<'local>(env: &mut Env<'local>) -> Result<()> {
+    /// let to_upper = JFunction::from_fn(env, |env, arg| {
+    ///     let arg = env.cast_local::<JString>(arg)?;
+    ///     let upper = arg.try_to_string(env)?.to_uppercase();
+    ///     Ok(JString::from_str(env, upper)?.into())
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fn<'env_local, F>(env: &mut Env<'env_local>, f: F) -> Result<JFunction<'env_local>>
+    where
+        F: for<'local> Fn(&mut Env<'local>, JObject<'local>) -> Result<JObject<'local>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let callback: ObjectFn = Box::new(move |env, t, _| f(env, t));
+        let function = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfFunction",
+                "RustFunction$OfFunction.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JFunction>(function)
+    }
+}
+
+impl JBiFunction<'_> {
+    /// Creates a `java.util.function.BiFunction` that's implemented by the given closure.
+    ///
+    /// See [`JFunction::from_fn`] for details about how the closure is called and dropped, and
+    /// portability notes.
+    pub fn from_fn<'env_local, F>(
+        env: &mut Env<'env_local>,
+        f: F,
+    ) -> Result<JBiFunction<'env_local>>
+    where
+        F: for<'local> Fn(
+                &mut Env<'local>,
+                JObject<'local>,
+                JObject<'local>,
+            ) -> Result<JObject<'local>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let callback: ObjectFn = Box::new(f);
+        let function = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfBiFunction",
+                "RustFunction$OfBiFunction.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JBiFunction>(function)
+    }
+}
+
+impl JSupplier<'_> {
+    /// Creates a `java.util.function.Supplier` that's implemented by the given closure.
+    ///
+    /// See [`JFunction::from_fn`] for details about how the closure is called and dropped, and
+    /// portability notes.
+    pub fn from_fn<'env_local, F>(env: &mut Env<'env_local>, f: F) -> Result<JSupplier<'env_local>>
+    where
+        F: for<'local> Fn(&mut Env<'local>) -> Result<JObject<'local>> + Send + Sync + 'static,
+    {
+        let callback: ObjectFn = Box::new(move |env, _, _| f(env));
+        let supplier = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfSupplier",
+                "RustFunction$OfSupplier.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JSupplier>(supplier)
+    }
+}
+
+impl JConsumer<'_> {
+    /// Creates a `java.util.function.Consumer` that's implemented by the given closure.
+    ///
+    /// See [`JFunction::from_fn`] for details about how the closure is called and dropped, and
+    /// portability notes.
+    pub fn from_fn<'env_local, F>(env: &mut Env<'env_local>, f: F) -> Result<JConsumer<'env_local>>
+    where
+        F: for<'local> Fn(&mut Env<'local>, JObject<'local>) -> Result<()> + Send + Sync + 'static,
+    {
+        let callback: ObjectFn = Box::new(move |env, t, _| {
+            f(env, t)?;
+            Ok(JObject::null())
+        });
+        let consumer = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfConsumer",
+                "RustFunction$OfConsumer.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JConsumer>(consumer)
+    }
+}
+
+impl JPredicate<'_> {
+    /// Creates a `java.util.function.Predicate` that's implemented by the given closure.
+    ///
+    /// See [`JFunction::from_fn`] for details about how the closure is called and dropped, and
+    /// portability notes.
+    pub fn from_fn<'env_local, F>(env: &mut Env<'env_local>, f: F) -> Result<JPredicate<'env_local>>
+    where
+        F: for<'local> Fn(&mut Env<'local>, JObject<'local>) -> Result<bool>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let callback: BooleanFn = Box::new(f);
+        let predicate = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfPredicate",
+                "RustFunction$OfPredicate.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JPredicate>(predicate)
+    }
+}
+
+impl JRunnable<'_> {
+    /// Creates a `java.lang.Runnable` that's implemented by the given closure.
+    ///
+    /// See [`JFunction::from_fn`] for details about how the closure is called and dropped, and
+    /// portability notes.
+    pub fn from_fn<'env_local, F>(env: &mut Env<'env_local>, f: F) -> Result<JRunnable<'env_local>>
+    where
+        F: for<'local> Fn(&mut Env<'local>) -> Result<()> + Send + Sync + 'static,
+    {
+        let callback: ObjectFn = Box::new(move |env, _, _| {
+            f(env)?;
+            Ok(JObject::null())
+        });
+        let runnable = new_rust_function(
+            env,
+            rust_function_class!(
+                "io/github/jni_rs/RustFunction$OfRunnable",
+                "RustFunction$OfRunnable.class"
+            ),
+            callback,
+        )?;
+        env.cast_local::<JRunnable>(runnable)
+    }
+}
+
+crate::bind_java_type! {
+    RustFunction => "io.github.jni_rs.RustFunction",
+    native_methods_export = false,
+    hooks {
+        load_class = |env, _loader_context, _initialize| {
+            static CLASS: OnceLock<Global<JClass<'static>>> = OnceLock::new();
+            define_embedded_class(
+                env,
+                &CLASS,
+                jni_str!("io/github/jni_rs/RustFunction"),
+                include_bytes!("../../java/classes/io/github/jni_rs/RustFunction.class"),
+            )
+        }
+    },
+    native_methods {
+        fn call(handle: jlong, a: JObject, b: JObject) -> JObject,
+        fn call_boolean(handle: jlong, a: JObject) -> bool,
+    }
+}
+
+impl RustFunctionNativeInterface for RustFunctionAPI {
+    type Error = Error;
+
+    fn call<'local>(
+        env: &mut Env<'local>,
+        _this: RustFunction<'local>,
+        handle: jlong,
+        a: JObject<'local>,
+        b: JObject<'local>,
+    ) -> Result<JObject<'local>> {
+        // Safety: the handle was created by `new_rust_function` for an `ObjectFn`, and it's only
+        // released once `this` (which is kept alive by this call) is unreachable
+        let callback = unsafe { &*(handle as *const ObjectFn) };
+        callback(env, a, b)
+    }
+
+    fn call_boolean<'local>(
+        env: &mut Env<'local>,
+        _this: RustFunction<'local>,
+        handle: jlong,
+        a: JObject<'local>,
+    ) -> Result<bool> {
+        // Safety: the handle was created by `new_rust_function` for a `BooleanFn`, and it's only
+        // released once `this` (which is kept alive by this call) is unreachable
+        let callback = unsafe { &*(handle as *const BooleanFn) };
+        callback(env, a)
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
+    JValueOwned,
+    convert::FromJava,
     env::Env,
     errors::{Error, Result},
     objects::JObject,
+    refs::Global,
 };
 
+#[cfg(doc)]
+use crate::objects::{JList, JMap};
+
 crate::bind_java_type! {
     pub JIterator => "java.util.Iterator",
     methods {
@@ -60,3 +66,147 @@ impl<'local> JIterator<'local> {
         }
     }
 }
+
+/// The local reference capacity for converting each element of an iteration
+pub(crate) const ELEMENT_FRAME_CAPACITY: usize = 8;
+
+/// Converts an element of an iteration, within the element's local reference frame
+type ConvertFn<T> = for<'any> fn(&mut Env<'any>, JObject<'any>) -> Result<T>;
+
+/// A Rust [`Iterator`] over the elements of a Java `java.util.Iterator`, which converts each
+/// element into a Rust type via [`FromJava`]
+///
+/// This is returned by methods like [`JIterator::into_iter_as`], [`JList::iter_as`] and
+/// [`JMap::iter_as`].
+///
+/// The iterator borrows the `&mut Env` that's used to call into Java. Each element is fetched and
+/// converted within its own local reference frame, so iterating over large collections doesn't
+/// overflow the local reference table. Elements that need to outlive the iteration as Java
+/// objects can be converted into [`Global`] references (see [`JIter::collect_globals`]).
+///
+/// Iteration stops after the first error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, objects::JList};
+/// # fn example(env: &mut Env, list: JList) -> Result<()> {
+/// for name in list.iter_as::<String>(env)? {
+///     println!("{}", name?);
+/// }
+///
+/// let lengths: Vec<i32> = list.iter_as::<Option<String>>(env)?
+///     .map(|name| Ok(name?.map_or(0, |name| name.len() as i32)))
+///     .collect::<Result<_>>()?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct JIter<'env, 'local, T> {
+    env: &'env mut Env<'local>,
+    iterator: JIterator<'local>,
+    convert: ConvertFn<T>,
+    done: bool,
+}
+
+impl<'env, 'local, T> JIter<'env, 'local, T> {
+    /// Creates an adaptor that converts each element with the given `convert` function.
+    pub(crate) fn new(
+        env: &'env mut Env<'local>,
+        iterator: JIterator<'local>,
+        convert: ConvertFn<T>,
+    ) -> Self {
+        Self {
+            env,
+            iterator,
+            convert,
+            done: false,
+        }
+    }
+
+    /// Collects the remaining elements into a `Vec`, or returns the first error.
+    pub fn to_vec(self) -> Result<Vec<T>> {
+        self.collect()
+    }
+}
+
+impl<'env, 'local> JIter<'env, 'local, Global<JObject<'static>>> {
+    /// Collects the remaining elements as global references, or returns the first error.
+    ///
+    /// Unlike local references, the global references aren't limited to a local reference frame
+    /// and can be sent to other threads.
+    pub fn collect_globals(self) -> Result<Vec<Global<JObject<'static>>>> {
+        self.to_vec()
+    }
+}
+
+impl<T> Iterator for JIter<'_, '_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let iterator = &self.iterator;
+        let convert = self.convert;
+        let next = self
+            .env
+            .with_local_frame(ELEMENT_FRAME_CAPACITY, |env| -> Result<_> {
+                match iterator.next(env)? {
+                    Some(element) => convert(env, element).map(Some),
+                    None => Ok(None),
+                }
+            });
+        match next {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<T> std::iter::FusedIterator for JIter<'_, '_, T> {}
+
+impl<T> Drop for JIter<'_, '_, T> {
+    fn drop(&mut self) {
+        let iterator = std::mem::take(&mut self.iterator);
+        self.env.delete_local_ref(iterator);
+    }
+}
+
+impl<T> std::fmt::Debug for JIter<'_, '_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JIter")
+            .field("iterator", &self.iterator)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Converts an element via its [`FromJava`] implementation
+pub(crate) fn convert_element<'any, T>(env: &mut Env<'any>, element: JObject<'any>) -> Result<T>
+where
+    T: for<'a> FromJava<'a>,
+{
+    T::from_java(env, JValueOwned::Object(element))
+}
+
+impl<'local> JIterator<'local> {
+    /// Adapts this iterator as a Rust [`Iterator`] that converts each element into `T`, via its
+    /// [`FromJava`] implementation.
+    ///
+    /// Each element is fetched and converted within its own local reference frame. See [`JIter`]
+    /// for more information.
+    pub fn into_iter_as<'env, T>(self, env: &'env mut Env<'local>) -> JIter<'env, 'local, T>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        JIter::new(env, self, convert_element::<T>)
+    }
+}
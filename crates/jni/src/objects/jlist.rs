@@ -1,40 +1,159 @@
+use std::{borrow::Cow, marker::PhantomData, ops::Deref};
+
 use crate::{
     Env,
+    convert::FromJava,
     errors::*,
-    objects::{JIterator, JObject, Reference},
-    sys::jint,
+    objects::{
+        JClass, JCollection, JComparator, JIter, JIterator, JObject, LoaderContext, Reference,
+    },
+    refs::{Cast, Global},
+    strings::JNIStr,
+    sys::{jint, jobject},
 };
 
 crate::bind_java_type! {
-    pub JList => "java.util.List",
-    is_instance_of {
-        collection = JCollection,
-    },
+    pub(crate) JListMethods => "java.util.List",
     methods = {
-        /// Returns the list element at the given `idx`
-        ///
-        /// # Throws
-        ///
-        /// - `IndexOutOfBoundsException` - if the index is out of range (index < 0 || index >= size())
         fn get(index: jint) -> JObject,
-        /// Insert an element at a specific index
+        fn set(index: jint, element: JObject) -> JObject,
         fn insert {
             name = "add",
             sig = (index: jint, element: JObject) -> void,
         },
-        /// Remove an element from the list by index
-        ///
-        /// Returns the removed element
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the remove operation is not supported
-        /// - `IndexOutOfBoundsException` - if the index is out of bounds
         fn remove(index: jint) -> JObject,
+        fn index_of(element: JObject) -> jint,
+        fn last_index_of(element: JObject) -> jint,
+        fn sub_list(from_index: jint, to_index: jint) -> JList,
+        fn sort(comparator: JComparator),
+    }
+}
+
+/// A `java.util.List` reference, tied to a JNI local reference frame.
+///
+/// The `E` type parameter is the type of the list elements, which defaults to [`JObject`]. Since
+/// Java generics are erased at runtime, casting to a `JList<E>` only checks that the object is a
+/// `java.util.List`, and methods that return elements (such as [`JList::get`]) instead check
+/// that each element is an instance of `E`, returning [`Error::WrongObjectType`] if not.
+///
+/// See the [`JObject`] documentation for more information about reference wrappers, how to cast
+/// them, and local reference frame lifetimes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, objects::{JList, JObject, JString}};
+/// # fn example<'local>(env: &mut Env<'local>, obj: JObject<'local>) -> Result<()> {
+/// let list = env.cast_local::<JList<JString>>(obj)?;
+///
+/// let hello = JString::from_str(env, "hello")?;
+/// list.add(env, &hello)?;
+///
+/// let first: JString = list.get(env, 0)?;
+/// assert_eq!(list.index_of(env, &first)?, 0);
+/// # Ok(())
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default)]
+pub struct JList<'local, E: Reference + 'local = JObject<'local>> {
+    list: JObject<'local>,
+    _marker: PhantomData<E>,
+}
+
+impl<'local, E: Reference> AsRef<JList<'local, E>> for JList<'local, E> {
+    fn as_ref(&self) -> &JList<'local, E> {
+        self
+    }
+}
+
+impl<'local, E: Reference> AsRef<JObject<'local>> for JList<'local, E> {
+    fn as_ref(&self) -> &JObject<'local> {
+        self
+    }
+}
+
+impl<'local, E: Reference> Deref for JList<'local, E> {
+    type Target = JObject<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<'local, E: Reference> From<JList<'local, E>> for JObject<'local> {
+    fn from(other: JList<'local, E>) -> JObject<'local> {
+        other.list
+    }
+}
+
+impl<'local, E: Reference> From<JList<'local, E>> for JCollection<'local> {
+    fn from(other: JList<'local, E>) -> JCollection<'local> {
+        // Safety: any `java.util.List` is also a `java.util.Collection`
+        unsafe { JCollection::kind_from_raw(other.list.into_raw()) }
+    }
+}
+
+impl<'local, E: Reference> AsRef<JCollection<'local>> for JList<'local, E> {
+    fn as_ref(&self) -> &JCollection<'local> {
+        // Safety: both types are transparent `JObject` wrappers, and any `java.util.List` is also
+        // a `java.util.Collection`
+        unsafe { &*(self as *const Self as *const JCollection<'local>) }
     }
 }
 
 impl<'local> JList<'local> {
+    /// Creates a [`JList`] that wraps the given `raw` [`jobject`]
+    ///
+    /// # Safety
+    ///
+    /// - `raw` must be a valid raw JNI local reference (or `null`).
+    /// - `raw` must be an instance of `java.util.List`.
+    /// - There must not be any other owning [`Reference`] wrapper for the same reference.
+    /// - The local reference must belong to the current thread and not outlive the
+    ///   JNI stack frame associated with the [Env] `'env_local` lifetime.
+    pub unsafe fn from_raw<'env_local>(env: &Env<'env_local>, raw: jobject) -> JList<'env_local> {
+        JList {
+            list: unsafe { JObject::from_raw(env, raw) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new null reference.
+    ///
+    /// Null references are always valid and do not belong to a local reference frame. Therefore,
+    /// the returned [`JList`] always has the `'static` lifetime.
+    pub const fn null() -> JList<'static> {
+        JList {
+            list: JObject::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Cast a local reference to a [`JList`]
+    ///
+    /// This will do a runtime (`IsInstanceOf`) check that the object is a `java.util.List`.
+    ///
+    /// To cast to a list with a specific element type, use [`Env::cast_local`] (e.g.
+    /// `env.cast_local::<JList<JString>>(obj)`) or [`JList::cast_elements`].
+    ///
+    /// Also see these other options for casting local or global references to a [`JList`]:
+    /// - [Env::as_cast]
+    /// - [Env::new_cast_local_ref]
+    /// - [Env::cast_local]
+    /// - [Env::new_cast_global_ref]
+    /// - [Env::cast_global]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::WrongObjectType] if the `IsInstanceOf` check fails.
+    pub fn cast_local<'any_local>(
+        env: &mut Env<'_>,
+        obj: impl Reference + Into<JObject<'any_local>> + AsRef<JObject<'any_local>>,
+    ) -> Result<JList<'any_local>> {
+        env.cast_local::<JList>(obj)
+    }
+
     /// Cast a local reference to a `JList`
     ///
     /// See [`JList::cast_local`] for more information.
@@ -48,12 +167,119 @@ impl<'local> JList<'local> {
     ) -> Result<JList<'any_local>> {
         env.cast_local::<JList>(obj)
     }
+}
+
+impl<'local, E: Reference + 'local> JList<'local, E> {
+    /// Unwrap to the raw jni type.
+    pub fn into_raw(self) -> jobject {
+        self.list.into_raw()
+    }
+
+    /// Casts this `JList` to a `JCollection`
+    ///
+    /// This does not require a runtime type check since any `JList` is also a `JCollection`
+    pub fn as_collection(&self) -> Cast<'local, '_, JCollection<'local>> {
+        // Safety: any `java.util.List` is also a `java.util.Collection`
+        unsafe { Cast::<JCollection>::new_unchecked(self) }
+    }
+
+    /// Changes the element type of this list, without any runtime check.
+    ///
+    /// This is safe because the element type is only checked when elements are returned by
+    /// methods like [`JList::get`].
+    pub fn cast_elements<T: Reference + 'local>(self) -> JList<'local, T::Kind<'local>> {
+        JList {
+            list: self.list,
+            _marker: PhantomData,
+        }
+    }
+
+    fn methods(&self) -> Cast<'local, '_, JListMethods<'local>> {
+        // Safety: we know that `self` is a `java.util.List`
+        unsafe { Cast::<JListMethods>::new_unchecked(self) }
+    }
+
+    /// Returns the list element at the given `index`
+    ///
+    /// Returns [`Error::WrongObjectType`] if the element isn't an instance of `E`.
+    ///
+    /// # Throws
+    ///
+    /// - `IndexOutOfBoundsException` - if the index is out of range (index < 0 || index >= size())
+    pub fn get<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        index: jint,
+    ) -> Result<E::Kind<'env_local>> {
+        let element = self.methods().get(env, index)?;
+        env.cast_local::<E>(element)
+    }
+
+    /// Replaces the list element at the given `index`
+    ///
+    /// Returns the element previously at the given `index`.
+    ///
+    /// Returns [`Error::WrongObjectType`] if the previous element isn't an instance of `E`.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the set operation is not supported
+    /// - `ClassCastException` - if the element type isn't compatible with the list
+    /// - `NullPointerException` - if the given element is null and the list does not allow null values
+    /// - `IllegalArgumentException` - if the element has a property that prevents it from being added to this list
+    /// - `IndexOutOfBoundsException` - if the index is out of range (index < 0 || index >= size())
+    pub fn set<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        index: jint,
+        element: &E::Kind<'_>,
+    ) -> Result<E::Kind<'env_local>> {
+        let old = self.methods().set(env, index, element)?;
+        env.cast_local::<E>(old)
+    }
 
     /// Append an element to the list
-    pub fn add(&self, env: &Env, value: &JObject) -> Result<bool> {
+    pub fn add(&self, env: &Env, value: &E::Kind<'_>) -> Result<bool> {
         self.as_collection().add(env, value)
     }
 
+    /// Insert an element at a specific index
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the add operation is not supported
+    /// - `ClassCastException` - if the element type isn't compatible with the list
+    /// - `NullPointerException` - if the given element is null and the list does not allow null values
+    /// - `IllegalArgumentException` - if the element has a property that prevents it from being added to this list
+    /// - `IndexOutOfBoundsException` - if the index is out of range (index < 0 || index > size())
+    pub fn insert<'any_local>(
+        &self,
+        env: &Env<'_>,
+        index: jint,
+        element: impl AsRef<E::Kind<'any_local>>,
+    ) -> Result<()> {
+        self.methods().insert(env, index, element.as_ref())
+    }
+
+    /// Remove an element from the list by index
+    ///
+    /// Returns the removed element
+    ///
+    /// Returns [`Error::WrongObjectType`] if the removed element isn't an instance of `E`.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the remove operation is not supported
+    /// - `IndexOutOfBoundsException` - if the index is out of bounds
+    pub fn remove<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        index: jint,
+    ) -> Result<E::Kind<'env_local>> {
+        let element = self.methods().remove(env, index)?;
+        env.cast_local::<E>(element)
+    }
+
     /// Removes the first occurrence of `value` from this [JList], if it's present.
     ///
     /// Returns `true` if an element was removed.
@@ -63,7 +289,7 @@ impl<'local> JList<'local> {
     /// - `UnsupportedOperationException` - if the remove operation is not supported
     /// - `ClassCastException` - if the element type isn't compatible with the set
     /// - `NullPointerException` - if the given element is null and the set does not allow null values
-    pub fn remove_item(&self, env: &Env<'_>, value: &JObject) -> Result<bool> {
+    pub fn remove_item(&self, env: &Env<'_>, value: &E::Kind<'_>) -> Result<bool> {
         self.as_collection().remove(env, value)
     }
 
@@ -76,6 +302,77 @@ impl<'local> JList<'local> {
         self.as_collection().clear(env)
     }
 
+    /// Returns `true` if this list contains the given element (according to `equals`).
+    ///
+    /// # Throws
+    ///
+    /// - `ClassCastException` - if the element type isn't compatible with the list
+    /// - `NullPointerException` - if the given element is null and the list does not allow null values
+    pub fn contains(&self, env: &Env<'_>, element: &E::Kind<'_>) -> Result<bool> {
+        self.as_collection().contains(env, element)
+    }
+
+    /// Returns the index of the first occurrence of the given element (according to `equals`), or
+    /// `-1` if the list doesn't contain the element.
+    ///
+    /// # Throws
+    ///
+    /// - `ClassCastException` - if the element type isn't compatible with the list
+    /// - `NullPointerException` - if the given element is null and the list does not allow null values
+    pub fn index_of(&self, env: &Env<'_>, element: &E::Kind<'_>) -> Result<jint> {
+        self.methods().index_of(env, element)
+    }
+
+    /// Returns the index of the last occurrence of the given element (according to `equals`), or
+    /// `-1` if the list doesn't contain the element.
+    ///
+    /// # Throws
+    ///
+    /// - `ClassCastException` - if the element type isn't compatible with the list
+    /// - `NullPointerException` - if the given element is null and the list does not allow null values
+    pub fn last_index_of(&self, env: &Env<'_>, element: &E::Kind<'_>) -> Result<jint> {
+        self.methods().last_index_of(env, element)
+    }
+
+    /// Returns a view of the portion of this list between `from_index` (inclusive) and
+    /// `to_index` (exclusive).
+    ///
+    /// Changes to the returned list are reflected in this list, and vice versa.
+    ///
+    /// # Throws
+    ///
+    /// - `IndexOutOfBoundsException` - if `from_index < 0`, `to_index > size()` or `from_index > to_index`
+    pub fn sub_list<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        from_index: jint,
+        to_index: jint,
+    ) -> Result<JList<'env_local, E::Kind<'env_local>>> {
+        let list = self.methods().sub_list(env, from_index, to_index)?;
+        // Safety: `List.subList` returns a `java.util.List`
+        Ok(unsafe { <JList<'local, E> as Reference>::kind_from_raw(list.into_raw()) })
+    }
+
+    /// Sorts this list according to the order induced by the given `java.util.Comparator`.
+    ///
+    /// If the comparator is `null` (e.g. [`JComparator::null`]) then the elements are sorted
+    /// according to their natural ordering (i.e. they must implement `java.lang.Comparable`).
+    ///
+    /// A comparator can be implemented in Rust with a [`crate::proxy::RustProxy`].
+    ///
+    /// # Throws
+    ///
+    /// - `ClassCastException` - if the list contains elements that are not mutually comparable
+    /// - `UnsupportedOperationException` - if the list's iterator does not support the set operation
+    /// - `IllegalArgumentException` - if the comparator is found to violate the `Comparator` contract
+    pub fn sort<'any_local>(
+        &self,
+        env: &Env<'_>,
+        comparator: impl AsRef<JComparator<'any_local>>,
+    ) -> Result<()> {
+        self.methods().sort(env, comparator)
+    }
+
     /// Get the size of the list
     pub fn size(&self, env: &Env) -> Result<jint> {
         self.as_collection().size(env)
@@ -100,7 +397,7 @@ impl<'local> JList<'local> {
     pub fn pop<'other_local_2>(
         &self,
         env: &mut Env<'other_local_2>,
-    ) -> Result<Option<JObject<'other_local_2>>> {
+    ) -> Result<Option<E::Kind<'other_local_2>>> {
         let size = self.size(env)?;
         if size == 0 {
             return Ok(None);
@@ -113,7 +410,8 @@ impl<'local> JList<'local> {
     ///
     /// The returned iterator does not implement [`std::iter::Iterator`] and
     /// cannot be used with a `for` loop. This is because its `next` method uses
-    /// a `&mut Env` to call the Java iterator. Use a `while let` loop instead:
+    /// a `&mut Env` to call the Java iterator. Use a `while let` loop instead
+    /// (or see [`JList::iter_as`] for a Rust iterator):
     ///
     /// ```rust,no_run
     /// # use jni::{errors::Result, Env, objects::{JList, JObject}};
@@ -141,4 +439,70 @@ impl<'local> JList<'local> {
     pub fn iter<'env_local>(&self, env: &mut Env<'env_local>) -> Result<JIterator<'env_local>> {
         self.as_collection().iterator(env)
     }
+
+    /// Returns a Rust [`Iterator`] over the elements in this list, which converts each element
+    /// into `T` via its [`FromJava`] implementation.
+    ///
+    /// See [`JCollection::iter_as`] for more information.
+    pub fn iter_as<'env, 'env_local, T>(
+        &self,
+        env: &'env mut Env<'env_local>,
+    ) -> Result<JIter<'env, 'env_local, T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        self.as_collection().iter_as(env)
+    }
+
+    /// Converts all of the elements in this list into a `Vec<T>`, via the [`FromJava`]
+    /// implementation of `T`.
+    pub fn to_vec<T>(&self, env: &mut Env<'_>) -> Result<Vec<T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        self.as_collection().to_vec(env)
+    }
+
+    /// Returns global references to all of the elements in this list.
+    pub fn collect_globals(&self, env: &mut Env<'_>) -> Result<Vec<Global<JObject<'static>>>> {
+        self.as_collection().collect_globals(env)
+    }
+}
+
+// SAFETY: JList is a transparent JObject wrapper with no Drop side effects
+unsafe impl<'local, E: Reference + 'local> Reference for JList<'local, E> {
+    type Kind<'env>
+        = JList<'env, E::Kind<'env>>
+    where
+        <E as Reference>::Kind<'env>: 'env;
+    type GlobalKind = JList<'static, E::GlobalKind>;
+
+    fn as_raw(&self) -> jobject {
+        self.list.as_raw()
+    }
+
+    fn class_name() -> Cow<'static, JNIStr> {
+        JListMethods::class_name()
+    }
+
+    fn lookup_class<'caller>(
+        env: &Env<'_>,
+        loader_context: &LoaderContext,
+    ) -> Result<impl Deref<Target = Global<JClass<'static>>> + 'caller> {
+        JListMethods::lookup_class(env, loader_context)
+    }
+
+    unsafe fn kind_from_raw<'env>(local_ref: jobject) -> Self::Kind<'env> {
+        JList {
+            list: unsafe { JObject::kind_from_raw(local_ref) },
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn global_kind_from_raw(global_ref: jobject) -> Self::GlobalKind {
+        JList {
+            list: unsafe { JObject::global_kind_from_raw(global_ref) },
+            _marker: PhantomData,
+        }
+    }
 }
@@ -1,114 +1,162 @@
 use crate::{
-    Env,
+    Env, JValueOwned,
+    convert::FromJava,
     errors::*,
-    objects::{JIterator, JObject, Reference},
+    objects::{
+        JBiFunction, JClass, JCollection, JIter, JIterator, JObject, JSet, LoaderContext, Reference,
+    },
+    refs::{Cast, Global},
+    strings::JNIStr,
+    sys::{jint, jobject},
 };
 
-use std::ops::Deref;
-
-#[cfg(doc)]
-use crate::objects::JSet;
+use std::{borrow::Cow, marker::PhantomData, ops::Deref};
 
 crate::bind_java_type! {
-    pub JMap => "java.util.Map",
+    pub(crate) JMapMethods => "java.util.Map",
     methods = {
-        /// Returns the number of key-value mappings in this map
-        ///
-        /// If the map contains more than `jint::MAX` mappings, returns `jint::MAX`.
         fn size() -> jint,
-        /// Returns `true` if this map contains no key-value mappings
         fn is_empty() -> bool,
-        /// Look up the value for a key
-        priv fn _get(key: JObject) -> JObject,
-        /// Get the value for a key, or return the default value if the key is not present
-        ///
-        /// # Throws
-        ///
-        /// - `ClassCastException` - if the key is of an inappropriate type for this map
-        /// - `NullPointerException` - if the key is null and this map does not allow null keys
+        fn get(key: JObject) -> JObject,
         fn get_or_default(key: JObject, default_value: JObject) -> JObject,
-        // Associates the specified value with the specified key in this map
-        priv fn _put(key: JObject, value: JObject) -> JObject,
-        /// Copies all of the mappings from the specified map to this map
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the putAll operation is not supported by this map
-        /// - `ClassCastException` - if a key or value in the specified map is of an inappropriate type for this map
-        /// - `NullPointerException` - if the given map is null, or if a key or value in the specified map is null and this map does not allow null keys or values
-        /// - `IllegalArgumentException` - if some property of a key or value in the specified map prevents it from being stored by this map
+        fn put(key: JObject, value: JObject) -> JObject,
         fn put_all(other_map: JMap),
-        /// If the specified key is not already associated with a value, associate it with the given value.
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the `putIfAbsent` operation is not supported by this map
-        /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
-        /// - `NullPointerException` - if the key or value is null and this map does not allow null keys or values
-        /// - `IllegalArgumentException` - if some property of the key or value prevents it from being stored by this map
         fn put_if_absent(key: JObject, value: JObject) -> JObject,
-        /// Remove a mapping for a key from the map
-        priv fn _remove(key: JObject) -> JObject,
-        /// Removes the entry for the specified key only if it is currently mapped to the specified value
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the remove operation is not supported by this map
-        /// - `ClassCastException` - if the key is of an inappropriate type for this map
-        /// - `NullPointerException` - if the key is null and this map does not allow null keys
-        priv fn remove_value {
+        fn remove(key: JObject) -> JObject,
+        fn remove_value {
             name = "remove",
             sig = (key: JObject, value: JObject) -> bool,
         },
-        /// Replaces the entry for the specified key only if it is currently mapped to some value
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the replace operation is not supported by this map
-        /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
-        /// - `NullPointerException` - if the key or value is null and this map does not allow null keys or values
-        /// - `IllegalArgumentException` - if some property of the value prevents it from being stored by this map
         fn replace(key: JObject, value: JObject) -> JObject,
-        /// Replaces the entry for the specified key only if currently mapped to a given value
-        ///
-        /// # Throws
-        ///
-        /// - `UnsupportedOperationException` - if the replace operation is not supported by this map
-        /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
-        /// - `NullPointerException` - if the key, new_value or old_value is null and this map does not allow null keys or values
-        /// - `IllegalArgumentException` - if some property of the value prevents it from being stored by this map
         fn replace_value {
             name = "replace",
             sig = (key: JObject, old_value: JObject, new_value: JObject) -> bool,
         },
-        /// Removes all of the mappings from this map
+        fn compute(key: JObject, remapping_function: JBiFunction) -> JObject,
         fn clear(),
-        /// Determines if the map contains a mapping for the specified key
-        ///
-        /// # Throws
-        /// - `ClassCastException` - if the key is of an inappropriate type for this map
-        /// - `NullPointerException` - if the key is null and this map does not allow null keys
         fn contains_key(key: JObject) -> bool,
-        /// Determines if the map maps one or more keys to the specified value
-        ///
-        /// # Throws
-        /// - `ClassCastException` - if the key is of an inappropriate type for this map
-        /// - `NullPointerException` - if the key is null and this map does not allow null keys
         fn contains_value(value: JObject) -> bool,
-        /// Get a `JSet` view of the mappings contained in this map
-        ///
-        /// Returns a [JSet] view of the mappings contained in the map, which can be used to iterate over the key/value pairs.
-        ///
-        /// Also see [JSet::iterator] and [Self::iter]
         fn entry_set() -> JSet,
-        /// Get a `JSet` view of the keys contained in this map
         fn key_set() -> JSet,
-        /// Get a `JCollection` view of the values contained in this map
         fn values() -> JCollection,
     }
 }
 
+/// A `java.util.Map` reference, tied to a JNI local reference frame.
+///
+/// The `K` and `V` type parameters are the types of the map keys and values, which both default
+/// to [`JObject`]. Since Java generics are erased at runtime, casting to a `JMap<K, V>` only
+/// checks that the object is a `java.util.Map`, and methods that return values (such as
+/// [`JMap::get`]) instead check that each value is an instance of `V`, returning
+/// [`Error::WrongObjectType`] if not.
+///
+/// See the [`JObject`] documentation for more information about reference wrappers, how to cast
+/// them, and local reference frame lifetimes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, objects::{JInteger, JMap, JObject, JString}};
+/// # fn example<'local>(env: &mut Env<'local>, obj: JObject<'local>) -> Result<()> {
+/// let map = env.cast_local::<JMap<JString, JInteger>>(obj)?;
+///
+/// let key = JString::from_str(env, "answer")?;
+/// let value = JInteger::value_of(env, 42)?;
+/// map.put(env, &key, &value)?;
+///
+/// let value: Option<JInteger> = map.get(env, &key)?;
+/// # Ok(())
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default)]
+pub struct JMap<
+    'local,
+    K: Reference + 'local = JObject<'local>,
+    V: Reference + 'local = JObject<'local>,
+> {
+    map: JObject<'local>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<'local, K: Reference, V: Reference> AsRef<JMap<'local, K, V>> for JMap<'local, K, V> {
+    fn as_ref(&self) -> &JMap<'local, K, V> {
+        self
+    }
+}
+
+impl<'local, K: Reference, V: Reference> AsRef<JObject<'local>> for JMap<'local, K, V> {
+    fn as_ref(&self) -> &JObject<'local> {
+        self
+    }
+}
+
+impl<'local, K: Reference, V: Reference> Deref for JMap<'local, K, V> {
+    type Target = JObject<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<'local, K: Reference, V: Reference> From<JMap<'local, K, V>> for JObject<'local> {
+    fn from(other: JMap<'local, K, V>) -> JObject<'local> {
+        other.map
+    }
+}
+
 impl<'local> JMap<'local> {
+    /// Creates a [`JMap`] that wraps the given `raw` [`jobject`]
+    ///
+    /// # Safety
+    ///
+    /// - `raw` must be a valid raw JNI local reference (or `null`).
+    /// - `raw` must be an instance of `java.util.Map`.
+    /// - There must not be any other owning [`Reference`] wrapper for the same reference.
+    /// - The local reference must belong to the current thread and not outlive the
+    ///   JNI stack frame associated with the [Env] `'env_local` lifetime.
+    pub unsafe fn from_raw<'env_local>(env: &Env<'env_local>, raw: jobject) -> JMap<'env_local> {
+        JMap {
+            map: unsafe { JObject::from_raw(env, raw) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new null reference.
+    ///
+    /// Null references are always valid and do not belong to a local reference frame. Therefore,
+    /// the returned [`JMap`] always has the `'static` lifetime.
+    pub const fn null() -> JMap<'static> {
+        JMap {
+            map: JObject::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Cast a local reference to a [`JMap`]
+    ///
+    /// This will do a runtime (`IsInstanceOf`) check that the object is a `java.util.Map`.
+    ///
+    /// To cast to a map with specific key and value types, use [`Env::cast_local`] (e.g.
+    /// `env.cast_local::<JMap<JString, JInteger>>(obj)`) or [`JMap::cast_entries`].
+    ///
+    /// Also see these other options for casting local or global references to a [`JMap`]:
+    /// - [Env::as_cast]
+    /// - [Env::new_cast_local_ref]
+    /// - [Env::cast_local]
+    /// - [Env::new_cast_global_ref]
+    /// - [Env::cast_global]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::WrongObjectType] if the `IsInstanceOf` check fails.
+    pub fn cast_local<'any_local>(
+        env: &mut Env<'_>,
+        obj: impl Reference + Into<JObject<'any_local>> + AsRef<JObject<'any_local>>,
+    ) -> Result<JMap<'any_local>> {
+        env.cast_local::<JMap>(obj)
+    }
+
     /// Cast a local reference to a `JMap`
     ///
     /// See [`JMap::cast_local`] for more information.
@@ -122,6 +170,43 @@ impl<'local> JMap<'local> {
     ) -> Result<JMap<'any_local>> {
         env.cast_local::<JMap>(obj)
     }
+}
+
+impl<'local, K: Reference + 'local, V: Reference + 'local> JMap<'local, K, V> {
+    /// Unwrap to the raw jni type.
+    pub fn into_raw(self) -> jobject {
+        self.map.into_raw()
+    }
+
+    /// Changes the key and value types of this map, without any runtime check.
+    ///
+    /// This is safe because the value type is only checked when values are returned by
+    /// methods like [`JMap::get`].
+    pub fn cast_entries<K2: Reference + 'local, V2: Reference + 'local>(
+        self,
+    ) -> JMap<'local, K2::Kind<'local>, V2::Kind<'local>> {
+        JMap {
+            map: self.map,
+            _marker: PhantomData,
+        }
+    }
+
+    fn methods(&self) -> Cast<'local, '_, JMapMethods<'local>> {
+        // Safety: we know that `self` is a `java.util.Map`
+        unsafe { Cast::<JMapMethods>::new_unchecked(self) }
+    }
+
+    /// Returns the number of key-value mappings in this map
+    ///
+    /// If the map contains more than `jint::MAX` mappings, returns `jint::MAX`.
+    pub fn size(&self, env: &Env<'_>) -> Result<jint> {
+        self.methods().size(env)
+    }
+
+    /// Returns `true` if this map contains no key-value mappings
+    pub fn is_empty(&self, env: &Env<'_>) -> Result<bool> {
+        self.methods().is_empty(env)
+    }
 
     /// Look up the value for a key.
     ///
@@ -133,6 +218,8 @@ impl<'local> JMap<'local> {
     /// In that case, use [JMap::contains_key] to determine if the key is
     /// present.
     ///
+    /// Returns [`Error::WrongObjectType`] if the value isn't an instance of `V`.
+    ///
     /// # Throws
     ///
     /// - `ClassCastException` - if the key is of an inappropriate type for this map
@@ -140,14 +227,28 @@ impl<'local> JMap<'local> {
     pub fn get<'env_local>(
         &self,
         env: &mut Env<'env_local>,
-        key: &JObject,
-    ) -> Result<Option<JObject<'env_local>>> {
-        let value = self._get(env, key)?;
-        if value.is_null() {
-            Ok(None)
-        } else {
-            Ok(Some(value))
-        }
+        key: &K::Kind<'_>,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let value = self.methods().get(env, key)?;
+        cast_non_null::<V>(env, value)
+    }
+
+    /// Get the value for a key, or return the default value if the key is not present
+    ///
+    /// Returns [`Error::WrongObjectType`] if the value isn't an instance of `V`.
+    ///
+    /// # Throws
+    ///
+    /// - `ClassCastException` - if the key is of an inappropriate type for this map
+    /// - `NullPointerException` - if the key is null and this map does not allow null keys
+    pub fn get_or_default<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        key: &K::Kind<'_>,
+        default_value: &V::Kind<'_>,
+    ) -> Result<V::Kind<'env_local>> {
+        let value = self.methods().get_or_default(env, key, default_value)?;
+        env.cast_local::<V>(value)
     }
 
     /// Associates the specified value with the specified key in this map
@@ -158,6 +259,8 @@ impl<'local> JMap<'local> {
     /// If the map permits null values, a `None` return value could also indicate
     /// that the previous value associated with the key was explicitly `null`.
     ///
+    /// Returns [`Error::WrongObjectType`] if the old value isn't an instance of `V`.
+    ///
     /// # Throws
     ///
     /// - `UnsupportedOperationException` - if the put operation is not supported by this map
@@ -167,15 +270,53 @@ impl<'local> JMap<'local> {
     pub fn put<'env_local>(
         &self,
         env: &mut Env<'env_local>,
-        key: &JObject,
-        value: &JObject,
-    ) -> Result<Option<JObject<'env_local>>> {
-        let old = self._put(env, key, value)?;
-        if old.is_null() {
-            Ok(None)
-        } else {
-            Ok(Some(old))
-        }
+        key: &K::Kind<'_>,
+        value: &V::Kind<'_>,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let old = self.methods().put(env, key, value)?;
+        cast_non_null::<V>(env, old)
+    }
+
+    /// Copies all of the mappings from the specified map to this map
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the putAll operation is not supported by this map
+    /// - `ClassCastException` - if a key or value in the specified map is of an inappropriate type for this map
+    /// - `NullPointerException` - if the given map is null, or if a key or value in the specified map is null and this map does not allow null keys or values
+    /// - `IllegalArgumentException` - if some property of a key or value in the specified map prevents it from being stored by this map
+    pub fn put_all<'other_local>(
+        &self,
+        env: &Env<'_>,
+        other_map: &JMap<'other_local, K::Kind<'other_local>, V::Kind<'other_local>>,
+    ) -> Result<()> {
+        // Safety: `other_map` is a `java.util.Map`
+        let other_map = unsafe { Cast::<JMap>::new_unchecked(other_map) };
+        self.methods().put_all(env, other_map)
+    }
+
+    /// If the specified key is not already associated with a value (or is mapped to `null`),
+    /// associate it with the given value.
+    ///
+    /// Returns `Some` with the current value if the key was already associated with a non-null
+    /// value, or `None` if the given value was stored.
+    ///
+    /// Returns [`Error::WrongObjectType`] if the current value isn't an instance of `V`.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the `putIfAbsent` operation is not supported by this map
+    /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
+    /// - `NullPointerException` - if the key or value is null and this map does not allow null keys or values
+    /// - `IllegalArgumentException` - if some property of the key or value prevents it from being stored by this map
+    pub fn put_if_absent<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        key: &K::Kind<'_>,
+        value: &V::Kind<'_>,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let current = self.methods().put_if_absent(env, key, value)?;
+        cast_non_null::<V>(env, current)
     }
 
     /// Remove a mapping for a key from the map
@@ -186,6 +327,8 @@ impl<'local> JMap<'local> {
     /// If the map permits null values, this method cannot distinguish between a
     /// key that was not present and a key that was explicitly mapped to `null`.
     ///
+    /// Returns [`Error::WrongObjectType`] if the removed value isn't an instance of `V`.
+    ///
     /// # Throws
     ///
     /// - `UnsupportedOperationException` - if the remove operation is not supported by this map
@@ -194,14 +337,154 @@ impl<'local> JMap<'local> {
     pub fn remove<'env_local>(
         &self,
         env: &mut Env<'env_local>,
-        key: &JObject,
-    ) -> Result<Option<JObject<'env_local>>> {
-        let old = self._remove(env, key)?;
-        if old.is_null() {
-            Ok(None)
-        } else {
-            Ok(Some(old))
-        }
+        key: &K::Kind<'_>,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let old = self.methods().remove(env, key)?;
+        cast_non_null::<V>(env, old)
+    }
+
+    /// Removes the entry for the specified key only if it is currently mapped to the specified value
+    ///
+    /// Returns `true` if the entry was removed.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the remove operation is not supported by this map
+    /// - `ClassCastException` - if the key is of an inappropriate type for this map
+    /// - `NullPointerException` - if the key is null and this map does not allow null keys
+    pub fn remove_value(
+        &self,
+        env: &Env<'_>,
+        key: &K::Kind<'_>,
+        value: &V::Kind<'_>,
+    ) -> Result<bool> {
+        self.methods().remove_value(env, key, value)
+    }
+
+    /// Replaces the entry for the specified key only if it is currently mapped to some value
+    ///
+    /// Returns `Some` with the previous value, or `None` if there was no mapping for the key (or
+    /// the previous value was `null`).
+    ///
+    /// Returns [`Error::WrongObjectType`] if the previous value isn't an instance of `V`.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the replace operation is not supported by this map
+    /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
+    /// - `NullPointerException` - if the key or value is null and this map does not allow null keys or values
+    /// - `IllegalArgumentException` - if some property of the value prevents it from being stored by this map
+    pub fn replace<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        key: &K::Kind<'_>,
+        value: &V::Kind<'_>,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let old = self.methods().replace(env, key, value)?;
+        cast_non_null::<V>(env, old)
+    }
+
+    /// Replaces the entry for the specified key only if currently mapped to a given value
+    ///
+    /// Returns `true` if the value was replaced.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the replace operation is not supported by this map
+    /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
+    /// - `NullPointerException` - if the key, new_value or old_value is null and this map does not allow null keys or values
+    /// - `IllegalArgumentException` - if some property of the value prevents it from being stored by this map
+    pub fn replace_value(
+        &self,
+        env: &Env<'_>,
+        key: &K::Kind<'_>,
+        old_value: &V::Kind<'_>,
+        new_value: &V::Kind<'_>,
+    ) -> Result<bool> {
+        self.methods().replace_value(env, key, old_value, new_value)
+    }
+
+    /// Computes a new value for the specified key, from the key and its current value (or `null`
+    /// if there is no current mapping).
+    ///
+    /// If the remapping function returns `null` then the mapping is removed (or remains absent).
+    ///
+    /// Returns `Some` with the new value, or `None` if the function returned `null`. The
+    /// remapping function can be implemented in Rust with [`JBiFunction::from_fn`].
+    ///
+    /// Returns [`Error::WrongObjectType`] if the new value isn't an instance of `V`.
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the put operation is not supported by this map
+    /// - `ClassCastException` - if the key or value are of an inappropriate type for this map
+    /// - `NullPointerException` - if the key is null and this map does not allow null keys
+    /// - `IllegalArgumentException` - if some property of the key or value prevents it from being stored by this map
+    /// - Any exception thrown by the remapping function is rethrown
+    pub fn compute<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        key: &K::Kind<'_>,
+        remapping_function: &JBiFunction,
+    ) -> Result<Option<V::Kind<'env_local>>> {
+        let value = self.methods().compute(env, key, remapping_function)?;
+        cast_non_null::<V>(env, value)
+    }
+
+    /// Removes all of the mappings from this map
+    ///
+    /// # Throws
+    ///
+    /// - `UnsupportedOperationException` - if the clear operation is not supported by this map
+    pub fn clear(&self, env: &Env<'_>) -> Result<()> {
+        self.methods().clear(env)
+    }
+
+    /// Determines if the map contains a mapping for the specified key
+    ///
+    /// # Throws
+    /// - `ClassCastException` - if the key is of an inappropriate type for this map
+    /// - `NullPointerException` - if the key is null and this map does not allow null keys
+    pub fn contains_key(&self, env: &Env<'_>, key: &K::Kind<'_>) -> Result<bool> {
+        self.methods().contains_key(env, key)
+    }
+
+    /// Determines if the map maps one or more keys to the specified value
+    ///
+    /// # Throws
+    /// - `ClassCastException` - if the key is of an inappropriate type for this map
+    /// - `NullPointerException` - if the key is null and this map does not allow null keys
+    pub fn contains_value(&self, env: &Env<'_>, value: &V::Kind<'_>) -> Result<bool> {
+        self.methods().contains_value(env, value)
+    }
+
+    /// Get a `JSet` view of the mappings contained in this map
+    ///
+    /// Returns a [JSet] view of the mappings contained in the map, which can be used to iterate over the key/value pairs.
+    ///
+    /// Also see [JSet::iterator] and [Self::iter]
+    pub fn entry_set<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+    ) -> Result<JSet<'env_local, JMapEntry<'env_local>>> {
+        let set = self.methods().entry_set(env)?;
+        // Safety: `Map.entrySet` returns a `java.util.Set` of `Map.Entry` values
+        Ok(unsafe { <JSet<JMapEntry> as Reference>::kind_from_raw(set.into_raw()) })
+    }
+
+    /// Get a `JSet` view of the keys contained in this map
+    pub fn key_set<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+    ) -> Result<JSet<'env_local, K::Kind<'env_local>>> {
+        let set = self.methods().key_set(env)?;
+        // Safety: `Map.keySet` returns a `java.util.Set`
+        Ok(unsafe { <JSet<'local, K> as Reference>::kind_from_raw(set.into_raw()) })
+    }
+
+    /// Get a `JCollection` view of the values contained in this map
+    pub fn values<'env_local>(&self, env: &mut Env<'env_local>) -> Result<JCollection<'env_local>> {
+        self.methods().values(env)
     }
 
     /// Get key/value iterator for the map. This is done by getting the
@@ -209,7 +492,8 @@ impl<'local> JMap<'local> {
     ///
     /// The returned iterator does not implement [`std::iter::Iterator`] and
     /// cannot be used with a `for` loop. This is because its `next` method uses
-    /// a `&mut Env` to call the Java iterator. Use a `while let` loop instead:
+    /// a `&mut Env` to call the Java iterator. Use a `while let` loop instead
+    /// (or see [`JMap::iter_as`] for a Rust iterator):
     ///
     /// ```rust,no_run
     /// # use jni::{errors::Result, Env, objects::{JMap, JObject}};
@@ -242,6 +526,124 @@ impl<'local> JMap<'local> {
 
         Ok(JMapIter { iterator })
     }
+
+    /// Returns a Rust [`Iterator`] over the key-value pairs in this map, which converts each key
+    /// and value via their [`FromJava`] implementations.
+    ///
+    /// Each entry is fetched and converted within its own local reference frame. See [`JIter`]
+    /// for more information.
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::JMap};
+    /// # fn example(env: &mut Env, map: JMap) -> Result<()> {
+    /// for entry in map.iter_as::<String, Option<i32>>(env)? {
+    ///     let (key, value) = entry?;
+    ///     // Do something with `key` and `value` here.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_as<'env, 'env_local, KT, VT>(
+        &self,
+        env: &'env mut Env<'env_local>,
+    ) -> Result<JIter<'env, 'env_local, (KT, VT)>>
+    where
+        KT: for<'any> FromJava<'any>,
+        VT: for<'any> FromJava<'any>,
+    {
+        let set = self.entry_set(env)?;
+        let iterator = set.iterator(env)?;
+        env.delete_local_ref(set);
+        Ok(JIter::new(env, iterator, convert_entry::<KT, VT>))
+    }
+
+    /// Converts all of the key-value pairs in this map into a `Vec<(KT, VT)>`, via the
+    /// [`FromJava`] implementations of `KT` and `VT`.
+    ///
+    /// See [`crate::convert`] for converting into a `HashMap`.
+    pub fn to_vec<KT, VT>(&self, env: &mut Env<'_>) -> Result<Vec<(KT, VT)>>
+    where
+        KT: for<'any> FromJava<'any>,
+        VT: for<'any> FromJava<'any>,
+    {
+        self.iter_as(env)?.to_vec()
+    }
+
+    /// Returns global references to all of the keys and values in this map.
+    #[allow(clippy::type_complexity)]
+    pub fn collect_globals(
+        &self,
+        env: &mut Env<'_>,
+    ) -> Result<Vec<(Global<JObject<'static>>, Global<JObject<'static>>)>> {
+        self.to_vec(env)
+    }
+}
+
+/// Converts a `java.util.Map$Entry` into a key-value pair
+fn convert_entry<'any, K, V>(env: &mut Env<'any>, entry: JObject<'any>) -> Result<(K, V)>
+where
+    K: for<'a> FromJava<'a>,
+    V: for<'a> FromJava<'a>,
+{
+    // SAFETY: we know that the entrySet iterator will yield Map.Entry values
+    // so we can safely downcast without needing a runtime type check
+    let entry = unsafe { JMapEntry::from_raw(env, entry.into_raw()) };
+    let key = entry.key(env)?;
+    let key = K::from_java(env, JValueOwned::Object(key))?;
+    let value = entry.value(env)?;
+    let value = V::from_java(env, JValueOwned::Object(value))?;
+    Ok((key, value))
+}
+
+/// Casts a value returned by a `java.util.Map` method to `V`, mapping `null` to `None`
+fn cast_non_null<'env_local, V: Reference>(
+    env: &mut Env<'env_local>,
+    value: JObject<'env_local>,
+) -> Result<Option<V::Kind<'env_local>>> {
+    if value.is_null() {
+        Ok(None)
+    } else {
+        env.cast_local::<V>(value).map(Some)
+    }
+}
+
+// SAFETY: JMap is a transparent JObject wrapper with no Drop side effects
+unsafe impl<'local, K: Reference + 'local, V: Reference + 'local> Reference for JMap<'local, K, V> {
+    type Kind<'env>
+        = JMap<'env, K::Kind<'env>, V::Kind<'env>>
+    where
+        <K as Reference>::Kind<'env>: 'env,
+        <V as Reference>::Kind<'env>: 'env;
+    type GlobalKind = JMap<'static, K::GlobalKind, V::GlobalKind>;
+
+    fn as_raw(&self) -> jobject {
+        self.map.as_raw()
+    }
+
+    fn class_name() -> Cow<'static, JNIStr> {
+        JMapMethods::class_name()
+    }
+
+    fn lookup_class<'caller>(
+        env: &Env<'_>,
+        loader_context: &LoaderContext,
+    ) -> Result<impl Deref<Target = Global<JClass<'static>>> + 'caller> {
+        JMapMethods::lookup_class(env, loader_context)
+    }
+
+    unsafe fn kind_from_raw<'env>(local_ref: jobject) -> Self::Kind<'env> {
+        JMap {
+            map: unsafe { JObject::kind_from_raw(local_ref) },
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn global_kind_from_raw(global_ref: jobject) -> Self::GlobalKind {
+        JMap {
+            map: unsafe { JObject::global_kind_from_raw(global_ref) },
+            _marker: PhantomData,
+        }
+    }
 }
 
 crate::bind_java_type! {
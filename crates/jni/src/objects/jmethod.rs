@@ -0,0 +1,35 @@
+crate::bind_java_type! {
+    pub JMethod => "java.lang.reflect.Method",
+    is_instance_of {
+        executable = JExecutable,
+    },
+    methods {
+        /// Returns the name of the method represented by this object.
+        fn get_name() -> JString,
+        /// Returns the Java language modifiers for the method represented by this object.
+        ///
+        /// See `java.lang.reflect.Modifier` for how to decode the returned value.
+        fn get_modifiers() -> jint,
+        /// Returns the class that declares the method represented by this object.
+        fn get_declaring_class() -> JClass,
+        /// Returns the formal return type of the method represented by this object.
+        fn get_return_type() -> JClass,
+        /// Returns the types of the formal parameters for the method represented by this object,
+        /// in declaration order.
+        fn get_parameter_types() -> JClass[],
+        /// Returns `true` if this method is a default method of an interface.
+        fn is_default() -> bool,
+        /// Invokes the method represented by this object, on the given object with the given
+        /// arguments.
+        ///
+        /// `obj` is ignored (and may be `null`) for static methods. Primitive arguments and
+        /// return values are boxed.
+        ///
+        /// # Throws
+        ///
+        /// - `IllegalAccessException` if the method is inaccessible
+        /// - `IllegalArgumentException` if the arguments don't match the method parameters
+        /// - `InvocationTargetException` if the method itself throws an exception
+        fn invoke(obj: JObject, args: JObject[]) -> JObject,
+    }
+}
@@ -0,0 +1,61 @@
+crate::bind_java_type! {
+    pub JModule => "java.lang.Module",
+    methods {
+        /// Returns the name of this module, or `null` if this is an unnamed module.
+        fn get_name() -> JString,
+        /// Returns `true` if this module is a named module.
+        fn is_named() -> bool,
+        /// Returns the class loader for this module.
+        ///
+        /// This may be `null` for modules defined to the bootstrap class loader.
+        ///
+        /// # Throws
+        ///
+        /// - `SecurityException` if a security manager denies access to the class loader.
+        fn get_class_loader() -> JClassLoader,
+        /// Returns `true` if this module exports the given package unconditionally (to all modules).
+        ///
+        /// `pn` is a package name, such as `"java.lang"`.
+        ///
+        /// This is a Java method binding for `java.lang.Module#isExported(String)`.
+        fn is_exported(pn: JString) -> bool,
+        /// Returns `true` if this module exports the given package to at least the given module.
+        ///
+        /// This is a Java method binding for `java.lang.Module#isExported(String, Module)`.
+        fn is_exported_to {
+            name = "isExported",
+            sig = (pn: JString, other: JModule) -> bool,
+        },
+        /// Returns `true` if this module has opened the given package unconditionally (to all
+        /// modules), allowing deep reflection.
+        ///
+        /// This is a Java method binding for `java.lang.Module#isOpen(String)`.
+        fn is_open(pn: JString) -> bool,
+        /// Returns `true` if this module has opened the given package to at least the given
+        /// module, allowing deep reflection.
+        ///
+        /// This is a Java method binding for `java.lang.Module#isOpen(String, Module)`.
+        fn is_open_to {
+            name = "isOpen",
+            sig = (pn: JString, other: JModule) -> bool,
+        },
+        /// Opens the given package of this module to the given module (if it isn't already open),
+        /// and returns this module.
+        ///
+        /// Note: `addOpens` is caller sensitive, and for a named module it can only be used by code
+        /// within that same module.
+        ///
+        /// # Throws
+        ///
+        /// - `IllegalArgumentException` if `pn` is `null` or not a package in this module.
+        /// - `IllegalCallerException` if this is a named module and the caller's module is not this
+        ///   module.
+        fn add_opens(pn: JString, other: JModule) -> JModule,
+        /// Returns a string describing this module, such as `"module java.base"` or
+        /// `"unnamed module @1b6d3586"`.
+        fn try_to_string {
+            name = "toString",
+            sig = () -> JString,
+        },
+    }
+}
@@ -1,17 +1,143 @@
+use std::{borrow::Cow, marker::PhantomData, ops::Deref};
+
 use crate::{
+    convert::FromJava,
     env::Env,
     errors::Result,
-    objects::{JIterator, JObject},
+    objects::{JClass, JCollection, JIter, JIterator, JObject, LoaderContext, Reference},
+    refs::{Cast, Global},
+    strings::JNIStr,
+    sys::jobject,
 };
 
 crate::bind_java_type! {
-    pub JSet => "java.util.Set",
-    is_instance_of {
-        collection = JCollection,
+    pub(crate) JSetMethods => "java.util.Set",
+}
+
+/// A `java.util.Set` reference, tied to a JNI local reference frame.
+///
+/// The `E` type parameter is the type of the set elements, which defaults to [`JObject`]. Since
+/// Java generics are erased at runtime, casting to a `JSet<E>` only checks that the object is a
+/// `java.util.Set`.
+///
+/// See the [`JObject`] documentation for more information about reference wrappers, how to cast
+/// them, and local reference frame lifetimes.
+#[repr(transparent)]
+#[derive(Debug, Default)]
+pub struct JSet<'local, E: Reference + 'local = JObject<'local>> {
+    set: JObject<'local>,
+    _marker: PhantomData<E>,
+}
+
+impl<'local, E: Reference> AsRef<JSet<'local, E>> for JSet<'local, E> {
+    fn as_ref(&self) -> &JSet<'local, E> {
+        self
+    }
+}
+
+impl<'local, E: Reference> AsRef<JObject<'local>> for JSet<'local, E> {
+    fn as_ref(&self) -> &JObject<'local> {
+        self
+    }
+}
+
+impl<'local, E: Reference> Deref for JSet<'local, E> {
+    type Target = JObject<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.set
+    }
+}
+
+impl<'local, E: Reference> From<JSet<'local, E>> for JObject<'local> {
+    fn from(other: JSet<'local, E>) -> JObject<'local> {
+        other.set
+    }
+}
+
+impl<'local, E: Reference> From<JSet<'local, E>> for JCollection<'local> {
+    fn from(other: JSet<'local, E>) -> JCollection<'local> {
+        // Safety: any `java.util.Set` is also a `java.util.Collection`
+        unsafe { JCollection::kind_from_raw(other.set.into_raw()) }
+    }
+}
+
+impl<'local, E: Reference> AsRef<JCollection<'local>> for JSet<'local, E> {
+    fn as_ref(&self) -> &JCollection<'local> {
+        // Safety: both types are transparent `JObject` wrappers, and any `java.util.Set` is also
+        // a `java.util.Collection`
+        unsafe { &*(self as *const Self as *const JCollection<'local>) }
     }
 }
 
 impl<'local> JSet<'local> {
+    /// Creates a [`JSet`] that wraps the given `raw` [`jobject`]
+    ///
+    /// # Safety
+    ///
+    /// - `raw` must be a valid raw JNI local reference (or `null`).
+    /// - `raw` must be an instance of `java.util.Set`.
+    /// - There must not be any other owning [`Reference`] wrapper for the same reference.
+    /// - The local reference must belong to the current thread and not outlive the
+    ///   JNI stack frame associated with the [Env] `'env_local` lifetime.
+    pub unsafe fn from_raw<'env_local>(env: &Env<'env_local>, raw: jobject) -> JSet<'env_local> {
+        JSet {
+            set: unsafe { JObject::from_raw(env, raw) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new null reference.
+    ///
+    /// Null references are always valid and do not belong to a local reference frame. Therefore,
+    /// the returned [`JSet`] always has the `'static` lifetime.
+    pub const fn null() -> JSet<'static> {
+        JSet {
+            set: JObject::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Cast a local reference to a [`JSet`]
+    ///
+    /// This will do a runtime (`IsInstanceOf`) check that the object is a `java.util.Set`.
+    ///
+    /// To cast to a set with a specific element type, use [`Env::cast_local`] (e.g.
+    /// `env.cast_local::<JSet<JString>>(obj)`) or [`JSet::cast_elements`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [crate::errors::Error::WrongObjectType] if the `IsInstanceOf` check fails.
+    pub fn cast_local<'any_local>(
+        env: &mut Env<'_>,
+        obj: impl Reference + Into<JObject<'any_local>> + AsRef<JObject<'any_local>>,
+    ) -> Result<JSet<'any_local>> {
+        env.cast_local::<JSet>(obj)
+    }
+}
+
+impl<'local, E: Reference + 'local> JSet<'local, E> {
+    /// Unwrap to the raw jni type.
+    pub fn into_raw(self) -> jobject {
+        self.set.into_raw()
+    }
+
+    /// Casts this `JSet` to a `JCollection`
+    ///
+    /// This does not require a runtime type check since any `JSet` is also a `JCollection`
+    pub fn as_collection(&self) -> Cast<'local, '_, JCollection<'local>> {
+        // Safety: any `java.util.Set` is also a `java.util.Collection`
+        unsafe { Cast::<JCollection>::new_unchecked(self) }
+    }
+
+    /// Changes the element type of this set, without any runtime check.
+    pub fn cast_elements<T: Reference + 'local>(self) -> JSet<'local, T::Kind<'local>> {
+        JSet {
+            set: self.set,
+            _marker: PhantomData,
+        }
+    }
+
     /// Adds the given element to this set if it is not already present
     ///
     /// Returns `true` if the element was added, `false` if it was already present.
@@ -25,9 +151,9 @@ impl<'local> JSet<'local> {
     pub fn add<'any_local>(
         &self,
         env: &mut Env<'_>,
-        element: impl AsRef<JObject<'any_local>>,
+        element: impl AsRef<E::Kind<'any_local>>,
     ) -> Result<bool> {
-        self.as_collection().add(env, element)
+        self.as_collection().add(env, element.as_ref())
     }
 
     /// Removes the given element from this set if it is present
@@ -42,9 +168,9 @@ impl<'local> JSet<'local> {
     pub fn remove<'any_local>(
         &self,
         env: &mut Env<'_>,
-        element: impl AsRef<JObject<'any_local>>,
+        element: impl AsRef<E::Kind<'any_local>>,
     ) -> Result<bool> {
-        self.as_collection().remove(env, element)
+        self.as_collection().remove(env, element.as_ref())
     }
 
     /// Removes all of the elements from this set.
@@ -64,7 +190,7 @@ impl<'local> JSet<'local> {
     ///
     /// - `ClassCastException` - if the element type isn't compatible with the set
     /// - `NullPointerException` - if the given element is null and the set does not allow null values
-    pub fn contains(&self, env: &mut Env<'_>, element: &JObject) -> Result<bool> {
+    pub fn contains(&self, env: &mut Env<'_>, element: &E::Kind<'_>) -> Result<bool> {
         self.as_collection().contains(env, element)
     }
 
@@ -82,4 +208,70 @@ impl<'local> JSet<'local> {
     pub fn iterator<'env_local>(&self, env: &mut Env<'env_local>) -> Result<JIterator<'env_local>> {
         self.as_collection().iterator(env)
     }
+
+    /// Returns a Rust [`Iterator`] over the elements in this set, which converts each element
+    /// into `T` via its [`FromJava`] implementation.
+    ///
+    /// See [`JCollection::iter_as`] for more information.
+    pub fn iter_as<'env, 'env_local, T>(
+        &self,
+        env: &'env mut Env<'env_local>,
+    ) -> Result<JIter<'env, 'env_local, T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        self.as_collection().iter_as(env)
+    }
+
+    /// Converts all of the elements in this set into a `Vec<T>`, via the [`FromJava`]
+    /// implementation of `T`.
+    pub fn to_vec<T>(&self, env: &mut Env<'_>) -> Result<Vec<T>>
+    where
+        T: for<'any> FromJava<'any>,
+    {
+        self.as_collection().to_vec(env)
+    }
+
+    /// Returns global references to all of the elements in this set.
+    pub fn collect_globals(&self, env: &mut Env<'_>) -> Result<Vec<Global<JObject<'static>>>> {
+        self.as_collection().collect_globals(env)
+    }
+}
+
+// SAFETY: JSet is a transparent JObject wrapper with no Drop side effects
+unsafe impl<'local, E: Reference + 'local> Reference for JSet<'local, E> {
+    type Kind<'env>
+        = JSet<'env, E::Kind<'env>>
+    where
+        <E as Reference>::Kind<'env>: 'env;
+    type GlobalKind = JSet<'static, E::GlobalKind>;
+
+    fn as_raw(&self) -> jobject {
+        self.set.as_raw()
+    }
+
+    fn class_name() -> Cow<'static, JNIStr> {
+        JSetMethods::class_name()
+    }
+
+    fn lookup_class<'caller>(
+        env: &Env<'_>,
+        loader_context: &LoaderContext,
+    ) -> Result<impl Deref<Target = Global<JClass<'static>>> + 'caller> {
+        JSetMethods::lookup_class(env, loader_context)
+    }
+
+    unsafe fn kind_from_raw<'env>(local_ref: jobject) -> Self::Kind<'env> {
+        JSet {
+            set: unsafe { JObject::kind_from_raw(local_ref) },
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn global_kind_from_raw(global_ref: jobject) -> Self::GlobalKind {
+        JSet {
+            set: unsafe { JObject::global_kind_from_raw(global_ref) },
+            _marker: PhantomData,
+        }
+    }
 }
@@ -1,9 +1,9 @@
 use thiserror::Error;
 
 use crate::{
-    Env, JavaVM,
+    Env, JNIVersion, JavaVM,
     errors::{Error, JniError, Result},
-    strings::{JNIStr, JNIString, MUTF8Chars},
+    strings::{JNIStr, JNIString, MUTF8Chars, StringCritical, UTF16Chars},
 };
 
 use super::Reference as _;
@@ -166,6 +166,433 @@ impl JString<'_> {
         }
     }
 
+    /// Creates a `JString` (`java.lang.String` object) from a slice of UTF-16 code units (via
+    /// `NewString`).
+    ///
+    /// Unlike [`Self::from_str`] this doesn't need to re-encode the input, and since Java strings
+    /// are UTF-16 internally, it can represent any Java string losslessly (including strings with
+    /// unpaired surrogates).
+    ///
+    /// This API catches exceptions internally and is not expected to return
+    /// [`Error::JavaException`] (unless called while there is a pending exception).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::JniCall`] with [`JniError::InvalidArguments`] if the length of `from`
+    /// exceeds `jsize::MAX`.
+    pub fn from_utf16<'env_local>(
+        env: &mut Env<'env_local>,
+        from: &[u16],
+    ) -> Result<JString<'env_local>> {
+        if from.len() > crate::sys::jsize::MAX as usize {
+            return Err(Error::JniCall(JniError::InvalidArguments));
+        }
+        // Runtime check that the 'local reference lifetime will be tied to
+        // Env lifetime for the top JNI stack frame
+        env.assert_top();
+        unsafe {
+            jni_call_with_catch_and_null_check!(
+                catch |env| {
+                    crate::exceptions::JOutOfMemoryError =>
+                        Err(Error::JniCall(JniError::NoMemory)),
+                    else => Err(Error::NullPtr("Unexpected Exception")),
+                },
+                env, v1_1, NewString, from.as_ptr(), from.len() as crate::sys::jsize)
+            .map(|s| JString::from_raw(env, s))
+        }
+    }
+
+    /// Returns the length of this string, in UTF-16 code units (via `GetStringLength`).
+    ///
+    /// This is the same as `String.length()` in Java.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn utf16_len(&self, env: &Env<'_>) -> Result<usize> {
+        let s = null_check!(self.as_raw(), "JString::utf16_len self argument")?;
+        let len = unsafe { jni_call_no_post_check_ex!(env, v1_1, GetStringLength, s)? } as usize;
+        Ok(len)
+    }
+
+    /// Returns the length of this string, in bytes, when encoded as [modified UTF-8] (via
+    /// `GetStringUTFLength`).
+    ///
+    /// The length doesn't include a nul terminator.
+    ///
+    /// Note: since `GetStringUTFLength` returns a `jsize`, the length may overflow for very large
+    /// strings (more than `i32::MAX` bytes when encoded). See [Self::mutf8_len_long] for a JNI >=
+    /// 24 API that can't overflow.
+    ///
+    /// [modified UTF-8]: https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8
+    ///
+    /// # Errors
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn mutf8_len(&self, env: &Env<'_>) -> Result<usize> {
+        let s = null_check!(self.as_raw(), "JString::mutf8_len self argument")?;
+        let len = unsafe { jni_call_no_post_check_ex!(env, v1_1, GetStringUTFLength, s)? } as usize;
+        Ok(len)
+    }
+
+    /// Returns the length of this string, in bytes, when encoded as [modified UTF-8] (via
+    /// `GetStringUTFLengthAsLong`).
+    ///
+    /// The length doesn't include a nul terminator.
+    ///
+    /// Unlike [Self::mutf8_len], this returns the length as a `jlong`, so it can't overflow for
+    /// very large strings.
+    ///
+    /// [modified UTF-8]: https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8
+    ///
+    /// # Errors
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    ///
+    /// Returns [`Error::UnsupportedJniVersion`] if the JNI version is lower than 24.
+    pub fn mutf8_len_long(&self, env: &Env<'_>) -> Result<u64> {
+        let s = null_check!(self.as_raw(), "JString::mutf8_len_long self argument")?;
+        env.ensure_version(JNIVersion::V24)?;
+        // Safety: GetStringUTFLengthAsLong is a 24 API that we have checked is available
+        let len =
+            unsafe { jni_call_no_post_check_ex!(env, v24, GetStringUTFLengthAsLong, s)? } as u64;
+        Ok(len)
+    }
+
+    /// Gets the contents of this string, as UTF-16 code units (via `GetStringChars`).
+    ///
+    /// The returned [UTF16Chars] guard dereferences to a `[u16]` slice.
+    ///
+    /// For example:
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::*};
+    /// #
+    /// # fn f(env: &mut Env) -> Result<()> {
+    /// let my_jstring = JString::from_str(env, "Hello, world!")?;
+    /// let utf16_chars = my_jstring.utf16_chars(env)?;
+    /// let units: &[u16] = &utf16_chars;
+    /// assert_eq!(units.len(), 13);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// When the [UTF16Chars] guard is dropped, the reference to the contents gets released.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn utf16_chars(&self, env: &Env<'_>) -> Result<UTF16Chars<'_, &JString<'_>>> {
+        UTF16Chars::from_get_string_chars(env, self)
+    }
+
+    /// Gets the contents of this string, as UTF-16 code units, within a critical section (via
+    /// `GetStringCritical`).
+    ///
+    /// The returned [StringCritical] guard dereferences to a `[u16]` slice, and the contents are
+    /// accessible during the critical section that exists until the guard is dropped.
+    ///
+    /// This API imposes some strict restrictions that help the JNI implementation avoid any need
+    /// to copy the string contents before making them accessible to native code:
+    ///
+    /// 1. No other use of JNI calls are allowed (on the same thread) within the critical section
+    ///    that exists while holding the [StringCritical] guard.
+    /// 2. No system calls can be made (Such as `read`) that may depend on a result from another
+    ///    Java thread.
+    ///
+    /// The JNI spec does not specify what will happen if these rules aren't adhered to but it
+    /// should be assumed it will lead to undefined behaviour, likely deadlock and possible program
+    /// termination.
+    ///
+    /// A nested scope or explicit use of `std::mem::drop` can be used to control when the returned
+    /// [StringCritical] is dropped to minimize the length of the critical section.
+    ///
+    /// For example:
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::*};
+    /// #
+    /// # fn f(env: &mut Env, my_jstring: &JString) -> Result<()> {
+    /// let hash = {
+    ///     // Safety: no JNI calls or blocking system calls are made within the critical section
+    ///     let chars = unsafe { my_jstring.utf16_chars_critical(env)? };
+    ///     chars.iter().fold(0u32, |h, c| h.wrapping_mul(31).wrapping_add(*c as u32))
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    ///
+    /// # Safety
+    ///
+    /// ## Critical Section Restrictions
+    ///
+    /// This API is marked as `unsafe` due to the complex, far-reaching nature of the
+    /// critical-section restrictions imposed here that can't be enforced through Rust's borrow
+    /// checker rules.
+    ///
+    /// The rules above about JNI usage and system calls _must_ be adhered to.
+    ///
+    /// Using this API implies:
+    ///
+    /// 1. All garbage collection will likely be paused during the critical section
+    /// 2. Any use of JNI in other threads may block if they need to allocate memory (due to the
+    ///    garbage collector being paused)
+    /// 3. Any use of system calls that will wait for a result from another Java thread could
+    ///    deadlock if that other thread is blocked by a paused garbage collector.
+    ///
+    /// A failure to adhere to the critical section rules could lead to any undefined behaviour,
+    /// including aborting the program.
+    ///
+    /// Also see [`Self::utf16_chars`] which has fewer restrictions, but is more likely to incur a
+    /// cost from copying the string contents.
+    pub unsafe fn utf16_chars_critical(
+        &self,
+        env: &Env<'_>,
+    ) -> Result<StringCritical<'_, &JString<'_>>> {
+        unsafe { StringCritical::from_get_string_critical(env, self) }
+    }
+
+    /// Copies `len` UTF-16 code units, starting at `start`, from this string into `buf` (via
+    /// `GetStringRegion`).
+    ///
+    /// Returns the number of code units written to `buf` (which is always `len`).
+    ///
+    /// This doesn't allocate and doesn't pin the string contents, so it can be used to
+    /// efficiently read substrings into a reusable buffer.
+    ///
+    /// Also see [`Self::get_utf_region`] to read a region in modified UTF-8 encoding.
+    ///
+    /// This API catches exceptions internally and is not expected to return
+    /// [`Error::JavaException`] (unless called while there is a pending exception).
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::IndexOutOfBounds] if `start + len` is greater than [`Self::utf16_len`].
+    ///
+    /// Returns [`Error::JniCall`] with [`JniError::InvalidArguments`] if `buf` is shorter than
+    /// `len`.
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn get_region(
+        &self,
+        env: &Env<'_>,
+        start: usize,
+        len: usize,
+        buf: &mut [u16],
+    ) -> Result<usize> {
+        let s = null_check!(self.as_raw(), "JString::get_region self argument")?;
+        if buf.len() < len {
+            return Err(Error::JniCall(JniError::InvalidArguments));
+        }
+        let (Ok(start), Ok(len)) = (
+            crate::sys::jsize::try_from(start),
+            crate::sys::jsize::try_from(len),
+        ) else {
+            return Err(Error::IndexOutOfBounds);
+        };
+        unsafe {
+            jni_call_with_catch!(
+                catch |env| {
+                    crate::exceptions::JStringIndexOutOfBoundsException =>
+                        Err(Error::IndexOutOfBounds),
+                    else => Err(Error::JniCall(JniError::Unknown)),
+                },
+                env,
+                v1_2,
+                GetStringRegion,
+                s,
+                start,
+                len,
+                buf.as_mut_ptr()
+            )?
+        };
+        Ok(len as usize)
+    }
+
+    /// Copies `len` UTF-16 code units, starting at `start`, from this string into `buf`, encoded
+    /// as [modified UTF-8] (via `GetStringUTFRegion`).
+    ///
+    /// Returns the number of bytes written to `buf` (not including any nul terminator that the
+    /// JNI implementation may have written after the encoded contents).
+    ///
+    /// Since the JNI implementation may write a nul terminator, `buf` must be at least one byte
+    /// longer than the encoded length of the region. Each UTF-16 code unit is encoded as up to
+    /// three bytes, so a buffer of `3 * len + 1` bytes is always large enough.
+    ///
+    /// This doesn't allocate and doesn't pin the string contents, so it can be used to
+    /// efficiently read substrings into a reusable buffer. See [`Self::get_region_into_string`]
+    /// to decode the substring into a Rust `String`.
+    ///
+    /// This API catches exceptions internally and is not expected to return
+    /// [`Error::JavaException`] (unless called while there is a pending exception).
+    ///
+    /// [modified UTF-8]: https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::IndexOutOfBounds] if `start + len` is greater than [`Self::utf16_len`].
+    ///
+    /// Returns [`Error::JniCall`] with [`JniError::InvalidArguments`] if `buf` isn't longer than
+    /// the encoded length of the region.
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn get_utf_region(
+        &self,
+        env: &Env<'_>,
+        start: usize,
+        len: usize,
+        buf: &mut [u8],
+    ) -> Result<usize> {
+        let s = null_check!(self.as_raw(), "JString::get_utf_region self argument")?;
+        // `GetStringUTFRegion` doesn't report how many bytes it writes, or check the size of the
+        // buffer, so we need to know the encoded length up front.
+        let mutf8_len = self.region_mutf8_len(env, start, len)?;
+        if buf.len() <= mutf8_len {
+            return Err(Error::JniCall(JniError::InvalidArguments));
+        }
+        let (Ok(start), Ok(len)) = (
+            crate::sys::jsize::try_from(start),
+            crate::sys::jsize::try_from(len),
+        ) else {
+            return Err(Error::IndexOutOfBounds);
+        };
+
+        unsafe {
+            jni_call_with_catch!(
+                catch |env| {
+                    crate::exceptions::JStringIndexOutOfBoundsException =>
+                        Err(Error::IndexOutOfBounds),
+                    else => Err(Error::JniCall(JniError::Unknown)),
+                },
+                env,
+                v1_2,
+                GetStringUTFRegion,
+                s,
+                start,
+                len,
+                buf.as_mut_ptr() as *mut std::os::raw::c_char
+            )?
+        };
+        Ok(mutf8_len)
+    }
+
+    /// Returns the [modified UTF-8] encoded length of `len` UTF-16 code units, starting at
+    /// `start`, from this string.
+    ///
+    /// This is the same as [`Self::mutf8_len`] if the region covers the whole string. Otherwise
+    /// the region is read in fixed-size chunks (via `GetStringRegion`) to count the encoded
+    /// length, since there's no JNI function to get the encoded length of a substring.
+    ///
+    /// [modified UTF-8]: https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8
+    fn region_mutf8_len(&self, env: &Env<'_>, start: usize, len: usize) -> Result<usize> {
+        if start == 0 && len == self.utf16_len(env)? {
+            return self.mutf8_len(env);
+        }
+
+        let mut chunk = [0u16; 256];
+        let mut mutf8_len = 0usize;
+        let mut offset = 0;
+        while offset < len {
+            let n = (len - offset).min(chunk.len());
+            self.get_region(env, start + offset, n, &mut chunk)?;
+            for &unit in &chunk[..n] {
+                mutf8_len += match unit {
+                    0x0001..=0x007f => 1,
+                    // nul is encoded as two bytes in modified UTF-8
+                    0x0000 | 0x0080..=0x07ff => 2,
+                    // surrogates are each encoded separately, as three bytes
+                    _ => 3,
+                };
+            }
+            offset += n;
+        }
+        Ok(mutf8_len)
+    }
+
+    /// Decodes `len` UTF-16 code units, starting at `start`, from this string into the given
+    /// `String` buffer, replacing its previous contents.
+    ///
+    /// Returns the length of the decoded string in bytes.
+    ///
+    /// The existing allocation of `buf` is reused (and grown as necessary), so repeatedly reading
+    /// substrings into the same buffer avoids per-call heap allocations. An intermediate
+    /// allocation is only needed if the substring contains a nul or a supplementary character
+    /// (which are encoded differently in modified UTF-8).
+    ///
+    /// As with [`JNIStr::to_str`], if the substring can't be decoded (such as if it contains an
+    /// unpaired surrogate) then invalid sequences are replaced with `U+FFFD REPLACEMENT
+    /// CHARACTER`. Use [`Self::get_region`] to read the UTF-16 code units losslessly.
+    ///
+    /// This API catches exceptions internally and is not expected to return
+    /// [`Error::JavaException`] (unless called while there is a pending exception).
+    ///
+    /// For example:
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::*};
+    /// #
+    /// # fn f(env: &mut Env) -> Result<()> {
+    /// let jstring = JString::from_str(env, "Hello, world!")?;
+    /// let mut buf = String::new();
+    /// jstring.get_region_into_string(env, 7, 5, &mut buf)?;
+    /// assert_eq!(buf, "world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::IndexOutOfBounds] if `start + len` is greater than [`Self::utf16_len`].
+    ///
+    /// Returns an [Error::NullPtr] if this [`JString`] is null.
+    pub fn get_region_into_string(
+        &self,
+        env: &Env<'_>,
+        start: usize,
+        len: usize,
+        buf: &mut String,
+    ) -> Result<usize> {
+        let Some(max_len) = len.checked_mul(3).and_then(|n| n.checked_add(1)) else {
+            return Err(Error::JniCall(JniError::InvalidArguments));
+        };
+
+        let mut bytes = std::mem::take(buf).into_bytes();
+        bytes.clear();
+        bytes.resize(max_len, 0);
+        let n = match self.get_utf_region(env, start, len, &mut bytes) {
+            Ok(n) => n,
+            Err(err) => {
+                bytes.clear();
+                // Safety: the buffer is empty
+                *buf = unsafe { String::from_utf8_unchecked(bytes) };
+                return Err(err);
+            }
+        };
+        bytes.truncate(n);
+
+        let decoded = match simd_cesu8::mutf8::decode(&bytes) {
+            Ok(std::borrow::Cow::Borrowed(_)) => None,
+            Ok(std::borrow::Cow::Owned(decoded)) => Some(decoded),
+            Err(e) => {
+                log::debug!("error decoding java cesu8: {:#?}", e);
+                Some(String::from_utf8_lossy(&bytes).into_owned())
+            }
+        };
+        if let Some(decoded) = decoded {
+            bytes.clear();
+            bytes.extend_from_slice(decoded.as_bytes());
+        }
+
+        // Safety: `mutf8::decode` only borrows its input if it's valid UTF-8, otherwise the
+        // buffer has been replaced with the decoded string.
+        *buf = unsafe { String::from_utf8_unchecked(bytes) };
+        Ok(buf.len())
+    }
+
     /// Gets the contents of this string, in [modified UTF-8] encoding (via `GetStringUTFChars`).
     ///
     /// The returned [MUTF8Chars] guard can be used to access the modified UTF-8 bytes, or to
@@ -1,4 +1,4 @@
-use crate::jni_str;
+use crate::{Env, errors::Result, jni_str};
 
 crate::bind_java_type! {
     pub JThread => "java.lang.Thread",
@@ -43,3 +43,30 @@ crate::bind_java_type! {
         fn set_context_class_loader(loader: JClassLoader) -> (),
     }
 }
+
+impl JThread<'_> {
+    /// Returns `true` if this is a virtual thread.
+    ///
+    /// This is equivalent to `java.lang.Thread#isVirtual()` but is implemented via
+    /// [`Env::is_virtual_thread`] (`IsVirtualThread`) so that the `JThread` bindings don't
+    /// require a Java 21 runtime.
+    ///
+    /// For example, to check if native code is running on a virtual thread:
+    ///
+    /// ```rust,no_run
+    /// # use jni::{errors::Result, Env, objects::*};
+    /// #
+    /// # fn f(env: &mut Env) -> Result<()> {
+    /// let current = JThread::current_thread(env)?;
+    /// if current.is_virtual(env)? {
+    ///     // Avoid blocking while holding a monitor...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns [`crate::errors::Error::UnsupportedJniVersion`] if the JNI version is lower than 21.
+    pub fn is_virtual(&self, env: &Env<'_>) -> Result<bool> {
+        env.is_virtual_thread(self)
+    }
+}
@@ -19,6 +19,9 @@ pub use self::jchar_sequence::*;
 mod jstring;
 pub use self::jstring::*;
 
+mod jboxed_primitive;
+pub use self::jboxed_primitive::*;
+
 mod jcollection;
 pub use self::jcollection::*;
 
@@ -37,9 +40,30 @@ pub use self::jlist::*;
 mod jbytebuffer;
 pub use self::jbytebuffer::*;
 
+mod jcompletable_future;
+pub use self::jcompletable_future::*;
+
+mod jfunction;
+pub use self::jfunction::*;
+
 mod jthread;
 pub use self::jthread::*;
 
+mod jexecutable;
+pub use self::jexecutable::*;
+
+mod jmethod;
+pub use self::jmethod::*;
+
+mod jconstructor;
+pub use self::jconstructor::*;
+
+mod jfield;
+pub use self::jfield::*;
+
+mod jmodule;
+pub use self::jmodule::*;
+
 /// Primitive Array types
 mod jobject_array;
 pub use self::jobject_array::*;
@@ -0,0 +1,344 @@
+//! Support for implementing Java interfaces in Rust, via `java.lang.reflect.Proxy`.
+//!
+//! A [`RustProxy`](crate::proxy::RustProxy) creates a Java proxy object that implements one or
+//! more interfaces, where every method call is dispatched to a Rust
+//! [`ProxyHandler`](crate::proxy::ProxyHandler). This makes it possible to pass a Rust
+//! implementation of something like a `Runnable`, `Comparator` or event listener to Java, without
+//! needing to ship any Java code.
+//!
+//! # Portability Note
+//!
+//! The native `InvocationHandler` is implemented with a Java class that's defined at runtime via
+//! [`Env::define_class`] (which isn't supported on Android), and it uses `java.lang.ref.Cleaner`
+//! which requires Java 9 or later.
+
+use std::sync::OnceLock;
+
+use crate::{
+    Env,
+    errors::{Error, Result},
+    jni_str,
+    native_classes::RustCleanupAPI,
+    objects::{Global, JClass, JClassLoader, JMethod, JObject, JObjectArray},
+    refs::LoaderContext,
+    strings::JNIString,
+    sys::jlong,
+};
+
+/// A Rust implementation of the methods of a Java proxy object
+///
+/// This is implemented for closures with a matching signature, but [`RustProxy::from_fn`] should
+/// be used for closures so that their argument types can be inferred.
+///
+/// The handler may be called concurrently by multiple Java threads, and it will be dropped on an
+/// arbitrary Java thread once the proxy has been garbage collected.
+pub trait ProxyHandler: Send + Sync + 'static {
+    /// Handles a method call on the proxy object.
+    ///
+    /// The return value must be compatible with the return type of the method. Primitive return
+    /// values need to be boxed (e.g. as a `java.lang.Integer`), and `null` should be returned for
+    /// `void` methods.
+    ///
+    /// If this returns an error then a Java exception is thrown from the proxy method. An
+    /// [`Error::JavaException`] error leaves the pending exception to be thrown, and any other
+    /// error is thrown as a `java.lang.RuntimeException`.
+    ///
+    /// Note: the `equals`, `hashCode` and `toString` methods of `java.lang.Object` aren't
+    /// dispatched to the handler, and are implemented based on the identity of the proxy.
+    fn invoke<'local>(
+        &self,
+        env: &mut Env<'local>,
+        invocation: &ProxyInvocation<'local>,
+    ) -> Result<JObject<'local>>;
+}
+
+impl<F> ProxyHandler for F
+where
+    F: for<'local> Fn(&mut Env<'local>, &ProxyInvocation<'local>) -> Result<JObject<'local>>
+        + Send
+        + Sync
+        + 'static,
+{
+    fn invoke<'local>(
+        &self,
+        env: &mut Env<'local>,
+        invocation: &ProxyInvocation<'local>,
+    ) -> Result<JObject<'local>> {
+        self(env, invocation)
+    }
+}
+
+/// A method call on a proxy object that's passed to a [`ProxyHandler`]
+#[derive(Debug)]
+pub struct ProxyInvocation<'local> {
+    proxy: JObject<'local>,
+    method: JMethod<'local>,
+    args: JObjectArray<'local>,
+}
+
+impl<'local> ProxyInvocation<'local> {
+    /// Returns the proxy object that the method was called on.
+    pub fn proxy(&self) -> &JObject<'local> {
+        &self.proxy
+    }
+
+    /// Returns the interface method that was called.
+    pub fn method(&self) -> &JMethod<'local> {
+        &self.method
+    }
+
+    /// Returns the name of the interface method that was called.
+    pub fn method_name(&self, env: &mut Env<'_>) -> Result<String> {
+        self.method.get_name(env)?.try_to_string(env)
+    }
+
+    /// Returns the number of arguments passed to the method.
+    pub fn arg_count(&self, env: &Env<'_>) -> Result<usize> {
+        if self.args.is_null() {
+            // Proxy passes `null` instead of an empty array for methods without arguments
+            Ok(0)
+        } else {
+            self.args.len(env)
+        }
+    }
+
+    /// Returns a new local reference to the argument at the given `index`.
+    ///
+    /// Primitive arguments are boxed (e.g. as a `java.lang.Integer`).
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if the `index` is out of bounds.
+    pub fn arg<'env_local>(
+        &self,
+        env: &mut Env<'env_local>,
+        index: usize,
+    ) -> Result<JObject<'env_local>> {
+        if self.args.is_null() {
+            return Err(Error::IndexOutOfBounds);
+        }
+        self.args.get_element(env, index)
+    }
+
+    /// Returns the array of arguments, which is `null` if the method doesn't take any arguments.
+    pub fn args(&self) -> &JObjectArray<'local> {
+        &self.args
+    }
+}
+
+/// The source of an interface that's implemented by a proxy
+#[derive(Debug)]
+enum ProxyInterface<'a> {
+    Name(JNIString),
+    Class(&'a JClass<'a>),
+}
+
+/// A builder for a `java.lang.reflect.Proxy` whose method calls are handled in Rust
+///
+/// The Rust [`ProxyHandler`] is owned by the proxy's `InvocationHandler`, and is dropped when the
+/// proxy becomes unreachable and is cleaned up by a `java.lang.ref.Cleaner`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, jni_str, objects::JObject, proxy::RustProxy};
+/// # fn example<'local>(env: &mut Env<'local>) -> Result<()> {
+/// let runnable = RustProxy::from_fn(|env, invocation| {
+///     println!("{} called", invocation.method_name(env)?);
+///     Ok(JObject::null())
+/// })
+/// .interface(jni_str!("java.lang.Runnable"))
+/// .build(env)?;
+///
+/// let thread = env.new_object(
+///     jni_str!("java/lang/Thread"),
+///     jni::jni_sig!("(Ljava/lang/Runnable;)V"),
+///     &[(&runnable).into()],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RustProxy<'a> {
+    handler: Box<dyn ProxyHandler>,
+    interfaces: Vec<ProxyInterface<'a>>,
+    class_loader: Option<&'a JClassLoader<'a>>,
+}
+
+impl std::fmt::Debug for RustProxy<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RustProxy")
+            .field("interfaces", &self.interfaces)
+            .field("class_loader", &self.class_loader)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> RustProxy<'a> {
+    /// Creates a builder for a proxy whose methods are handled by the given `handler`.
+    pub fn new(handler: impl ProxyHandler) -> Self {
+        Self {
+            handler: Box::new(handler),
+            interfaces: Vec::new(),
+            class_loader: None,
+        }
+    }
+
+    /// Creates a builder for a proxy whose methods are handled by the given closure.
+    pub fn from_fn<F>(handler: F) -> Self
+    where
+        F: for<'local> Fn(&mut Env<'local>, &ProxyInvocation<'local>) -> Result<JObject<'local>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::new(handler)
+    }
+
+    /// Adds an interface for the proxy to implement, by its binary name (like
+    /// `"java.lang.Runnable"`).
+    ///
+    /// The interface is loaded by the [`RustProxy::class_loader`], if one is set, or else with
+    /// the default [`LoaderContext`] strategy.
+    pub fn interface(mut self, name: impl Into<JNIString>) -> Self {
+        self.interfaces.push(ProxyInterface::Name(name.into()));
+        self
+    }
+
+    /// Adds an interface for the proxy to implement.
+    pub fn interface_class(mut self, class: &'a JClass<'a>) -> Self {
+        self.interfaces.push(ProxyInterface::Class(class));
+        self
+    }
+
+    /// Sets the class loader that defines the proxy class, which must be able to see all of the
+    /// proxy's interfaces.
+    ///
+    /// By default, the class loader of the first interface is used.
+    pub fn class_loader(mut self, loader: &'a JClassLoader<'a>) -> Self {
+        self.class_loader = Some(loader);
+        self
+    }
+
+    /// Creates the proxy object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an interface couldn't be loaded, or [`Error::JavaException`] (leaving
+    /// the exception pending) if `Proxy.newProxyInstance` throws an exception, e.g. because a
+    /// class isn't an interface or isn't visible from the class loader.
+    pub fn build<'local>(self, env: &mut Env<'local>) -> Result<JObject<'local>> {
+        let RustProxy {
+            handler,
+            interfaces,
+            class_loader,
+        } = self;
+
+        // The invocation handler registers itself with the cleaner that's shared with `RustCleanup`
+        RustCleanupAPI::get(env, &LoaderContext::None)?;
+
+        env.with_local_frame_returning_local::<_, JObject, _>(8 + interfaces.len(), |env| {
+            let loader_context = match class_loader {
+                Some(loader) => LoaderContext::Loader(loader),
+                None => LoaderContext::None,
+            };
+            let classes = JObjectArray::<JClass>::new(env, interfaces.len(), JClass::null())?;
+            for (index, interface) in interfaces.iter().enumerate() {
+                let class = match interface {
+                    ProxyInterface::Name(name) => loader_context.load_class(env, name, false)?,
+                    ProxyInterface::Class(class) => env.new_local_ref(*class)?,
+                };
+                classes.set_element(env, index, &class)?;
+            }
+            let loader = match class_loader {
+                Some(loader) => env.new_local_ref(loader)?,
+                None if !interfaces.is_empty() => {
+                    let first = classes.get_element(env, 0)?;
+                    first.get_class_loader(env)?
+                }
+                None => JClassLoader::null(),
+            };
+
+            let handle = Box::into_raw(Box::new(handler));
+            let proxy = RustInvocationHandler::new(env, handle as jlong).and_then(|handler| {
+                let proxy = JProxy::new_proxy_instance(env, &loader, &classes, &handler)?;
+                handler.register_cleanup(env, &proxy)?;
+                Ok(proxy)
+            });
+            if proxy.is_err() {
+                // Safety: the proxy (and so the invocation handler) hasn't escaped, so the
+                // handler's state won't be used or released by Java
+                drop(unsafe { Box::from_raw(handle) });
+            }
+            proxy
+        })
+    }
+}
+
+crate::bind_java_type! {
+    JProxy => "java.lang.reflect.Proxy",
+    methods {
+        static fn new_proxy_instance(
+            loader: JClassLoader,
+            interfaces: JClass[],
+            handler: java.lang.reflect.InvocationHandler,
+        ) -> JObject,
+    }
+}
+
+crate::bind_java_type! {
+    RustInvocationHandler => "io.github.jni_rs.RustInvocationHandler",
+    native_methods_export = false,
+    hooks {
+        load_class = |env, _loader_context, _initialize| {
+            static CLASS: OnceLock<Global<JClass<'static>>> = OnceLock::new();
+            crate::native_classes::define_embedded_class(
+                env,
+                &CLASS,
+                jni_str!("io/github/jni_rs/RustInvocationHandler"),
+                include_bytes!("../java/classes/io/github/jni_rs/RustInvocationHandler.class"),
+            )
+        }
+    },
+    constructors {
+        fn new(handle: jlong),
+    },
+    methods {
+        fn register_cleanup(proxy: JObject) -> void,
+    },
+    native_methods {
+        static fn dispatch(handle: jlong, proxy: JObject, method: JMethod, args: JObject[]) -> JObject,
+        static fn release(handle: jlong) -> void,
+    }
+}
+
+impl RustInvocationHandlerNativeInterface for RustInvocationHandlerAPI {
+    type Error = Error;
+
+    fn dispatch<'local>(
+        env: &mut Env<'local>,
+        _class: JClass<'local>,
+        handle: jlong,
+        proxy: JObject<'local>,
+        method: JMethod<'local>,
+        args: JObjectArray<'local>,
+    ) -> Result<JObject<'local>> {
+        // Safety: the handle was created by `RustProxy::build` and the Java invocation handler
+        // doesn't release it while there are any calls in progress
+        let handler = unsafe { &*(handle as *const Box<dyn ProxyHandler>) };
+        let invocation = ProxyInvocation {
+            proxy,
+            method,
+            args,
+        };
+        handler.invoke(env, &invocation)
+    }
+
+    fn release<'local>(
+        _env: &mut Env<'local>,
+        _class: JClass<'local>,
+        handle: jlong,
+    ) -> Result<()> {
+        // Safety: the handle was created by `RustProxy::build` and the Java invocation handler
+        // only releases it once
+        drop(unsafe { Box::from_raw(handle as *mut Box<dyn ProxyHandler>) });
+        Ok(())
+    }
+}
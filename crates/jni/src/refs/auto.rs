@@ -8,7 +8,7 @@ use crate::{
     strings::JNIStr,
 };
 
-use super::Reference;
+use super::{RefType, Reference};
 
 /// A wrapper to `Auto` delete local references early (before the JNI stack
 /// frame unwinds).
@@ -174,6 +174,28 @@ where
     }
 }
 
+impl<'local, T> Auto<'local, T>
+where
+    T: Into<JObject<'local>> + AsRef<JObject<'local>>,
+{
+    /// Asserts that this wraps a local JNI reference (or `null`), in debug builds.
+    ///
+    /// This uses [`Env::get_object_ref_type`] to check the kind of reference that's wrapped. Since
+    /// an [`Auto`] deletes its reference via `DeleteLocalRef`, it would be a serious bug for it to
+    /// wrap any other kind of reference.
+    ///
+    /// This does nothing in release builds, or if the reference type can't be queried (such as if
+    /// there is a pending exception).
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this will panic if the wrapped reference is not a local reference.
+    #[track_caller]
+    pub fn debug_assert_ref_type(&self, env: &Env) {
+        super::debug_assert_ref_type(env, (*self.obj).as_ref(), RefType::Local);
+    }
+}
+
 impl<'local, T> Drop for Auto<'local, T>
 where
     T: Into<JObject<'local>>,
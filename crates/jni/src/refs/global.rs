@@ -14,7 +14,7 @@ use crate::{
 #[cfg(doc)]
 use crate::objects::Weak;
 
-use super::Reference;
+use super::{RefType, Reference};
 
 // Note: `Global` must not implement `Into<JObject>`! If it did, then it would be possible to
 // wrap it in `Auto`, which would cause undefined behavior upon drop as a result of calling
@@ -255,6 +255,22 @@ where
     pub fn as_obj(&self) -> &JObject<'static> {
         self.as_ref()
     }
+
+    /// Asserts that this wraps a global JNI reference (or `null`), in debug builds.
+    ///
+    /// This uses [`Env::get_object_ref_type`] to check the kind of reference that's wrapped, which
+    /// can help catch mistakes when wrapping raw references via [`Env::global_from_raw`].
+    ///
+    /// This does nothing in release builds, or if the reference type can't be queried (such as if
+    /// there is a pending exception).
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this will panic if the wrapped reference is not a global reference.
+    #[track_caller]
+    pub fn debug_assert_ref_type(&self, env: &Env) {
+        super::debug_assert_ref_type(env, self.as_obj(), RefType::Global);
+    }
 }
 
 impl<T> Drop for Global<T>
@@ -2,7 +2,7 @@ use std::{borrow::Cow, ffi::CString};
 
 use crate::{
     errors::{Error, JniError},
-    objects::{JClass, JClassLoader, JObject, JString, JThread, JThrowable},
+    objects::{JClass, JClassLoader, JModule, JObject, JString, JThread, JThrowable},
     refs::{IntoAuto as _, Reference},
     strings::{JNIStr, JNIString},
 };
@@ -263,6 +263,67 @@ impl<'a, 'any_local> LoaderContext<'a, 'any_local> {
         self.load_class(env, &internal_name, initialize)
     }
 
+    /// Loads the class with the given name using the loader context, and checks that the class's
+    /// package is exported by its module to the `caller` module.
+    ///
+    /// This behaves the same as [Self::load_class] except that it can diagnose classes that
+    /// can be found, but which aren't accessible to code in the `caller` module, since they are
+    /// in a package that isn't exported. Without this check, such errors are usually only
+    /// reported later, as an `IllegalAccessError` thrown by Java code.
+    ///
+    /// `caller` would typically be looked up via [`crate::Env::get_module`] for a class that
+    /// belongs to the code that will be using the loaded class.
+    ///
+    /// This requires JNI >= 9.
+    ///
+    /// - Returns [`Error::ClassNotExported`] if the class's package is not exported to `caller`
+    /// - Returns [`Error::UnsupportedJniVersion`] if the JNI version is lower than 9
+    /// - Otherwise returns the same errors as [Self::load_class]
+    pub fn load_exported_class<'env_local>(
+        &self,
+        env: &mut crate::env::Env<'env_local>,
+        name: impl AsRef<JNIStr>,
+        initialize: bool,
+        caller: &JModule,
+    ) -> crate::errors::Result<JClass<'env_local>> {
+        /// Get the package name for a binary name or array descriptor (like `"java.lang.String"`
+        /// or `"[Ljava.lang.String;"`), or `None` for primitive arrays or the unnamed package.
+        fn package_name(binary_name: &str) -> Option<&str> {
+            let name = binary_name.trim_start_matches('[');
+            let name = if name.len() != binary_name.len() {
+                name.strip_prefix('L')?.strip_suffix(';')?
+            } else {
+                name
+            };
+            name.rsplit_once('.').map(|(package, _)| package)
+        }
+
+        let name = name.as_ref();
+        let class = self.load_class(env, name, initialize)?;
+
+        let requested = name.to_string();
+        if let Some(package) = package_name(&requested) {
+            env.with_local_frame(5, |env| -> crate::errors::Result<()> {
+                let module = env.get_module(&class)?;
+                let package_ref = JString::from_str(env, package)?;
+                if module.is_exported_to(env, &package_ref, caller)? {
+                    Ok(())
+                } else {
+                    let module = module.try_to_string(env)?.try_to_string(env)?;
+                    let caller = caller.try_to_string(env)?.try_to_string(env)?;
+                    Err(Error::ClassNotExported {
+                        requested: requested.clone(),
+                        package: package.to_string(),
+                        module,
+                        caller,
+                    })
+                }
+            })?;
+        }
+
+        Ok(class)
+    }
+
     /// Loads the class associated with the `JObjectRef` type `T`, using the given loader context.
     ///
     /// `initialize` indicates whether a newly loaded class should be initialized (has no effect
@@ -7,6 +7,9 @@ pub use reference::*;
 mod loader_context;
 pub use loader_context::*;
 
+mod ref_type;
+pub use ref_type::*;
+
 // For storing a reference to a java object
 mod global;
 pub use global::*;
@@ -0,0 +1,55 @@
+use crate::sys::jobjectRefType;
+
+#[cfg(doc)]
+use crate::{Env, objects::JObject};
+
+/// The kind of a JNI reference, as returned by [`Env::get_object_ref_type`]
+///
+/// This is mainly useful for debugging, such as checking that a raw reference that was given to
+/// [`Env::global_from_raw`] really is a global reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefType {
+    /// The reference is not a valid reference (including [`JObject::null`])
+    Invalid,
+    /// The reference is a local reference
+    Local,
+    /// The reference is a global reference
+    Global,
+    /// The reference is a weak global reference
+    WeakGlobal,
+}
+
+impl From<jobjectRefType> for RefType {
+    fn from(value: jobjectRefType) -> Self {
+        match value {
+            jobjectRefType::JNIInvalidRefType => RefType::Invalid,
+            jobjectRefType::JNILocalRefType => RefType::Local,
+            jobjectRefType::JNIGlobalRefType => RefType::Global,
+            jobjectRefType::JNIWeakGlobalRefType => RefType::WeakGlobal,
+        }
+    }
+}
+
+/// Asserts that `obj` is `null` or a reference of the `expected` kind (only in debug builds)
+///
+/// The check is skipped if the reference type can't be queried (such as if there is a pending
+/// exception).
+#[track_caller]
+pub(crate) fn debug_assert_ref_type(
+    env: &crate::Env,
+    obj: &crate::objects::JObject,
+    expected: RefType,
+) {
+    if !cfg!(debug_assertions) || obj.is_null() {
+        return;
+    }
+    match env.get_object_ref_type(obj) {
+        Ok(actual) => assert_eq!(
+            actual,
+            expected,
+            "Expected a {expected:?} reference but {:?} is a {actual:?} reference",
+            obj.as_raw()
+        ),
+        Err(err) => log::debug!("Skipped reference type check: {err}"),
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
     sys,
 };
 
-use super::Reference;
+use super::{RefType, Reference};
 
 // Note: `Weak` must not implement `Into<JObject>`! If it did, then it would be possible to
 // wrap it in `Auto`, which would cause undefined behavior upon drop as a result of calling
@@ -165,6 +165,22 @@ where
         self.obj.as_raw()
     }
 
+    /// Asserts that this wraps a weak global JNI reference (or `null`), in debug builds.
+    ///
+    /// This uses [`Env::get_object_ref_type`] to check the kind of reference that's wrapped, which
+    /// can help catch mistakes when wrapping raw references via [`Env::weak_from_raw`].
+    ///
+    /// This does nothing in release builds, or if the reference type can't be queried (such as if
+    /// there is a pending exception).
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this will panic if the wrapped reference is not a weak global reference.
+    #[track_caller]
+    pub fn debug_assert_ref_type(&self, env: &Env) {
+        super::debug_assert_ref_type(env, self.obj.as_ref(), RefType::WeakGlobal);
+    }
+
     /// Creates a new local reference to this object.
     ///
     /// This returns `None` if the object has already been garbage collected, otherwise it returns
@@ -5,4 +5,10 @@ pub use self::ffi_str::*;
 mod mutf8_chars;
 pub use self::mutf8_chars::*;
 
+mod utf16_chars;
+pub use self::utf16_chars::*;
+
+mod string_critical;
+pub use self::string_critical::*;
+
 pub use crate::jvalue::{char_from_java, char_from_java_int, char_to_java, char_to_java_int};
@@ -0,0 +1,165 @@
+use jni_sys::{JNI_TRUE, jboolean, jchar};
+use log::error;
+use std::{marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    Env, JavaVM,
+    errors::*,
+    objects::{JString, Reference},
+};
+
+/// Auto-release wrapper for a pointer to the UTF-16 contents of a
+/// `java.lang.String`, obtained within a critical section.
+///
+/// This guard type is returned by [JString::utf16_chars_critical] and wraps the
+/// pointer returned by `GetStringCritical`, ensuring that it is released via
+/// `ReleaseStringCritical` when dropped.
+///
+/// This can be dereferenced to obtain a `[u16]` slice of UTF-16 code units.
+///
+/// See [JString::utf16_chars_critical] for the restrictions that apply while
+/// this guard is alive.
+pub struct StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    obj: StringRef,
+    chars: NonNull<jchar>,
+    len: usize,
+    is_copy: bool,
+    _lifetime: PhantomData<&'local ()>,
+}
+
+impl<'local, StringRef> std::fmt::Debug for StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Note: we can't use JNI to format the string object within a critical section
+        f.debug_struct("StringCritical")
+            .field("obj", &self.obj.as_raw())
+            .field("chars", &self.chars)
+            .field("len", &self.len)
+            .field("is_copy", &self.is_copy)
+            .finish()
+    }
+}
+
+impl<'local, StringRef> StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    /// # Safety
+    ///
+    /// The caller must adhere to the critical section restrictions documented
+    /// for [JString::utf16_chars_critical] until the returned guard is dropped.
+    pub(crate) unsafe fn from_get_string_critical(env: &Env<'_>, obj: StringRef) -> Result<Self> {
+        let obj = null_check!(obj, "get_string_critical obj argument")?;
+
+        // Note: the length has to be queried before entering the critical section
+        let len = unsafe { jni_call_no_post_check_ex!(env, v1_1, GetStringLength, obj.as_raw())? }
+            as usize;
+
+        let mut is_copy: jboolean = true;
+        let ptr = unsafe {
+            jni_call_with_catch_and_null_check!(
+                catch |env| {
+                    crate::exceptions::JOutOfMemoryError =>
+                        Err(Error::JniCall(JniError::NoMemory)),
+                    else => Err(Error::NullPtr("Unexpected Exception")),
+                },
+                env,
+                v1_2,
+                GetStringCritical,
+                obj.as_raw(),
+                &mut is_copy
+            )? as *mut jchar
+        };
+
+        Ok(Self {
+            obj,
+            chars: NonNull::new(ptr).ok_or(Error::NullPtr("Non-null ptr expected"))?,
+            len,
+            is_copy: is_copy == JNI_TRUE,
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Get a raw pointer to the borrowed UTF-16 code units.
+    ///
+    /// The pointer is only valid while this guard is alive.
+    pub fn as_ptr(&self) -> *const u16 {
+        self.chars.as_ptr()
+    }
+
+    /// Indicates if the string contents are a copy or not
+    pub fn is_copy(&self) -> bool {
+        self.is_copy
+    }
+
+    /// Returns the length of the string, in UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'local, StringRef> std::ops::Deref for StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    type Target = [u16];
+
+    fn deref(&self) -> &Self::Target {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: `GetStringCritical` returned a pointer to `len` code units, that remain valid
+        // until `ReleaseStringCritical` is called when the guard is dropped.
+        unsafe { std::slice::from_raw_parts(self.chars.as_ptr(), self.len) }
+    }
+}
+
+impl<'local, StringRef> AsRef<[u16]> for StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn as_ref(&self) -> &[u16] {
+        self
+    }
+}
+
+impl<'local, StringRef> Drop for StringCritical<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn drop(&mut self) {
+        // Safety/Errors:
+        // - Since we can't construct `StringCritical` without a valid `Env` reference we know
+        //   we can call `JavaVM::singleton()` without an error.
+        // - Since `self` is associated with a local reference frame lifetime we know that the
+        //   thread is attached and so `with_top_local_frame()` can't return an error.
+        // - ReleaseStringCritical can be called while there is a pending exception
+        let res = JavaVM::singleton().and_then(|vm| {
+            vm.with_top_local_frame(|env| unsafe {
+                ex_safe_jni_call_no_post_check_ex!(
+                    env,
+                    v1_2,
+                    ReleaseStringCritical,
+                    self.obj.as_raw(),
+                    self.chars.as_ptr()
+                );
+                Ok(())
+            })
+        });
+
+        match res {
+            Ok(()) => {}
+            Err(e) => error!("error releasing critical string: {:#?}", e),
+        }
+    }
+}
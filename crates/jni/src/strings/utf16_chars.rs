@@ -0,0 +1,179 @@
+use jni_sys::{JNI_TRUE, jboolean, jchar};
+use std::marker::PhantomData;
+
+use log::warn;
+
+use crate::{
+    Env, JavaVM,
+    errors::*,
+    objects::{JString, Reference},
+};
+
+/// Borrows the contents of a `java.lang.String` object, as UTF-16 code units.
+///
+/// This guard type is returned by [JString::utf16_chars] and represents the
+/// borrowed contents of a `java.lang.String` object that will be automatically
+/// released when dropped.
+///
+/// This can be dereferenced to obtain a `[u16]` slice of UTF-16 code units.
+///
+/// For example:
+///
+/// ```rust,no_run
+/// # use jni::{errors::Result, Env, objects::*};
+/// #
+/// # fn f(env: &mut Env) -> Result<()> {
+/// let string = JString::from_str(env, "Hello, world!")?;
+/// let utf16_chars = string.utf16_chars(env)?;
+/// let rust_string = String::from_utf16_lossy(&utf16_chars);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Unlike [`MUTF8Chars`](crate::strings::MUTF8Chars), the contents are not
+/// re-encoded, and are not nul-terminated. Since Java strings are sequences of
+/// UTF-16 code units, this representation is lossless, even for strings that
+/// contain unpaired surrogates.
+pub struct UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    obj: StringRef,
+    chars: *const jchar,
+    len: usize,
+    is_copy: bool,
+    _lifetime: PhantomData<&'local ()>,
+}
+
+impl<'local, StringRef> std::fmt::Debug for UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UTF16Chars")
+            .field("obj", self.obj.as_ref())
+            .field("chars", &self.chars)
+            .field("len", &self.len)
+            .field("is_copy", &self.is_copy)
+            .finish()
+    }
+}
+
+impl<'local, StringRef> UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    /// Constructs a [`UTF16Chars`] from a `Env` and a `JString`.
+    ///
+    /// The implementation may either create a copy of the character array for
+    /// the given `String` or it may pin it to avoid it being collected by the
+    /// garbage collector.
+    ///
+    /// Returns a [`UTF16Chars`] that will automatically release the underlying
+    /// character array (via `ReleaseStringChars`) when dropped.
+    pub(crate) fn from_get_string_chars(env: &Env<'_>, obj: StringRef) -> Result<Self> {
+        let obj = null_check!(obj, "get_string_chars obj argument")?;
+
+        // SAFETY:
+        // - We have checked that the object is not null.
+        // - Having a `JString` guarantees that the reference is for a `java.lang.String`
+        //   (it would require unsafe code for that to be violated)
+        // - The pointer is immediately wrapped to ensure that the pointer will
+        //   be released when dropped.
+        unsafe {
+            let len =
+                jni_call_no_post_check_ex!(env, v1_1, GetStringLength, obj.as_raw())? as usize;
+
+            let mut is_copy: jboolean = false;
+            let ptr: *const jchar = jni_call_only_check_null_ret!(
+                env,
+                v1_1,
+                GetStringChars,
+                obj.as_raw(),
+                &mut is_copy as *mut _
+            )?;
+
+            let is_copy = is_copy == JNI_TRUE;
+            Ok(Self {
+                obj,
+                chars: ptr,
+                len,
+                is_copy,
+                _lifetime: PhantomData,
+            })
+        }
+    }
+
+    /// Get a raw pointer to the borrowed UTF-16 code units.
+    ///
+    /// The pointer is only valid while this guard is alive.
+    pub fn as_ptr(&self) -> *const u16 {
+        self.chars
+    }
+
+    /// Returns whether the string was copied or not.
+    pub fn is_copy(&self) -> bool {
+        self.is_copy
+    }
+
+    /// Returns the length of the string, in UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'local, StringRef> ::std::ops::Deref for UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    type Target = [u16];
+    fn deref(&self) -> &Self::Target {
+        if self.len == 0 {
+            // The JNI implementation may return a dangling (or unaligned) pointer for an empty
+            // string, which can't be used to construct a slice.
+            return &[];
+        }
+        // SAFETY: `GetStringChars` returned a pointer to `len` code units, that remain valid until
+        // `ReleaseStringChars` is called when the guard is dropped.
+        unsafe { std::slice::from_raw_parts(self.chars, self.len) }
+    }
+}
+
+impl<'local, StringRef> AsRef<[u16]> for UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn as_ref(&self) -> &[u16] {
+        self
+    }
+}
+
+impl<'local, StringRef> Drop for UTF16Chars<'local, StringRef>
+where
+    StringRef: AsRef<JString<'local>> + Reference,
+{
+    fn drop(&mut self) {
+        unsafe fn release_string_chars(obj: jni_sys::jobject, chars: *const jchar) -> Result<()> {
+            // Error: Since we can't construct a `UTF16Chars` without a valid `Env` reference we know
+            // `JavaVM::singleton()` must be initialized and won't panic.
+            JavaVM::singleton()?.with_top_local_frame(|env| {
+                // This method is safe to call in case of pending exceptions (see the chapter 2 of the spec)
+                unsafe {
+                    ex_safe_jni_call_no_post_check_ex!(env, v1_1, ReleaseStringChars, obj, chars)
+                };
+
+                Ok(())
+            })
+        }
+
+        match unsafe { release_string_chars(self.obj.as_raw(), self.chars) } {
+            Ok(()) => {}
+            Err(e) => warn!("error dropping java string chars: {}", e),
+        }
+    }
+}
@@ -46,6 +46,10 @@ impl JNIVersion {
     pub const V21: Self = JNIVersion {
         ver: jni_sys::JNI_VERSION_21 as u32,
     };
+    /// JNI Version 24.0
+    pub const V24: Self = JNIVersion {
+        ver: jni_sys::JNI_VERSION_24 as u32,
+    };
 
     /// Return a version from a raw version constant like [`jni_sys::JNI_VERSION_1_2`]
     pub fn new(ver: jni_sys::jint) -> Self {
@@ -98,4 +102,5 @@ fn jni_version_major_minor() {
     check_major_minor!(19, 0, V19, JNI_VERSION_19);
     check_major_minor!(20, 0, V20, JNI_VERSION_20);
     check_major_minor!(21, 0, V21, JNI_VERSION_21);
+    check_major_minor!(24, 0, V24, JNI_VERSION_24);
 }
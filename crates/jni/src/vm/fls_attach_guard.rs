@@ -77,6 +77,8 @@ static FLS_INDEX: OnceLock<u32> = OnceLock::new();
 #[derive(Debug)]
 struct FlsAttachData {
     thread: Thread,
+    /// Whether the fiber's thread was attached as a daemon thread
+    daemon: bool,
 }
 
 /// FLS callback that runs when a fiber terminates.
@@ -122,7 +124,9 @@ unsafe extern "system" fn fls_callback(data: *const core::ffi::c_void) {
     // Always safe to detach when guard_level == 0
     // Note: We pass None for cross_check_env because with multiple fibers, subsequent
     // detach attempts will have stale env pointers. DetachCurrentThread is idempotent.
-    if let Err(e) = unsafe { sys_detach_current_thread(None, &attach_data.thread) } {
+    if let Err(e) =
+        unsafe { sys_detach_current_thread(None, &attach_data.thread, attach_data.daemon) }
+    {
         error!(
             "Error detaching thread in FLS callback: {:#?}\nThread {} id={:?}",
             e,
@@ -208,14 +212,21 @@ pub(super) unsafe fn fls_attach_current_thread<'local>(
     // active AttachGuards). In this case we don't want to double-increment the attached
     // threads counter.
     let inc_attached_count = existing.is_null();
+    // Safety: we only ever store Box<FlsAttachData> in FLS
+    let was_daemon = !existing.is_null() && unsafe { (*(existing as *const FlsAttachData)).daemon };
 
     let thread = current();
-    let env = unsafe { sys_attach_current_thread(java_vm, config, &thread, inc_attached_count)? };
+    let env = unsafe {
+        sys_attach_current_thread(java_vm, config, &thread, inc_attached_count, was_daemon)?
+    };
 
     // Create attachment data for this fiber
     // Note: We don't store the env pointer to avoid holding stale pointers when
     // multiple fibers share the same thread attachment
-    let attach_data = Box::new(FlsAttachData { thread: current() });
+    let attach_data = Box::new(FlsAttachData {
+        thread: current(),
+        daemon: config.daemon,
+    });
 
     let data_ptr = Box::into_raw(attach_data) as *mut core::ffi::c_void;
 
@@ -282,6 +293,6 @@ pub(super) fn fls_detach_current_thread() -> Result<()> {
 
     // Note: We pass None for cross_check_env because we don't store env pointers in FLS
     // (they can become stale with multiple fibers). DetachCurrentThread is idempotent.
-    unsafe { sys_detach_current_thread(None, &attach_data.thread)? };
+    unsafe { sys_detach_current_thread(None, &attach_data.thread, attach_data.daemon)? };
     Ok(())
 }
@@ -1,15 +1,25 @@
-use std::{borrow::Cow, ffi::CStr, io, os::raw::c_void, ptr};
+use std::{
+    borrow::Cow,
+    ffi::{CStr, OsString},
+    io,
+    os::raw::c_void,
+    path::Path,
+    ptr,
+};
 
 use thiserror::Error;
 
 use crate::{
     JNIVersion,
-    sys::{JavaVMInitArgs, JavaVMOption},
+    sys::{JavaVMInitArgs, JavaVMOption, jint},
 };
 
+use self::hooks::JvmHooks;
+
 use cfg_if::cfg_if;
 
 mod char_encoding_generic;
+mod hooks;
 
 #[cfg(windows)]
 mod char_encoding_windows;
@@ -78,6 +88,22 @@ pub enum JvmError {
         #[source]
         error: io::Error,
     },
+
+    /// One of the typed option helpers, such as [`InitArgsBuilder::system_property`] or
+    /// [`InitArgsBuilder::class_path`], was given an argument that can't be represented as a
+    /// valid JVM option.
+    ///
+    /// For example, a system property name that contains an `=`, or a class path entry that
+    /// contains the platform's path separator.
+    #[error("invalid option {opt_string:?}: {reason}")]
+    #[non_exhaustive]
+    InvalidOption {
+        /// The (possibly partial) option string.
+        opt_string: String,
+
+        /// Why the option is invalid.
+        reason: &'static str,
+    },
 }
 
 impl JvmError {
@@ -88,6 +114,7 @@ impl JvmError {
             Self::OptStringTooLong { opt_string, .. } => Some(opt_string),
             Self::OptStringNotRepresentable { opt_string, .. } => Some(opt_string),
             Self::OptStringTranscodeFailure { opt_string, .. } => Some(opt_string),
+            Self::InvalidOption { opt_string, .. } => Some(opt_string),
         }
         .map(String::as_str)
     }
@@ -99,12 +126,19 @@ impl JvmError {
             Self::OptStringTooLong { opt_string, .. } => Some(opt_string),
             Self::OptStringNotRepresentable { opt_string, .. } => Some(opt_string),
             Self::OptStringTranscodeFailure { opt_string, .. } => Some(opt_string),
+            Self::InvalidOption { opt_string, .. } => Some(opt_string),
         }
     }
 }
 
 const SPECIAL_OPTIONS: &[&str] = &["vfprintf", "abort", "exit"];
 
+/// The separator that's used to join class path and module path entries
+#[cfg(windows)]
+const PATH_SEPARATOR: char = ';';
+#[cfg(not(windows))]
+const PATH_SEPARATOR: char = ':';
+
 const SPECIAL_OPTIONS_C: &[&CStr] = &[c"vfprintf", c"abort", c"exit"];
 
 /// Builder for JavaVM InitArgs.
@@ -116,6 +150,8 @@ pub struct InitArgsBuilder<'a> {
     opts: Result<Vec<Cow<'a, CStr>>, JvmError>,
     ignore_unrecognized: bool,
     version: JNIVersion,
+    hooks: JvmHooks,
+    default_hooks: bool,
 }
 
 impl Default for InitArgsBuilder<'_> {
@@ -124,12 +160,17 @@ impl Default for InitArgsBuilder<'_> {
             opts: Ok(vec![]),
             ignore_unrecognized: false,
             version: JNIVersion::V1_8,
+            hooks: JvmHooks::default(),
+            default_hooks: true,
         }
     }
 }
 
 impl<'a> InitArgsBuilder<'a> {
     /// Create a new default InitArgsBuilder
+    ///
+    /// By default, the JVM's diagnostic output and exit/abort events are reported to the [`log`]
+    /// crate. See [`InitArgsBuilder::default_hooks`].
     pub fn new() -> Self {
         Default::default()
     }
@@ -138,8 +179,10 @@ impl<'a> InitArgsBuilder<'a> {
     ///
     /// See [the JNI specification][jni-options] for details on which options are accepted.
     ///
-    /// The `vfprintf`, `abort`, and `exit` options are unsupported at this time. Setting one of
-    /// these options has no effect.
+    /// The `vfprintf`, `abort`, and `exit` options can't be set via this method, since they require
+    /// a function pointer. Setting one of these options has no effect. See
+    /// [`InitArgsBuilder::vfprintf_hook`], [`InitArgsBuilder::exit_hook`] and
+    /// [`InitArgsBuilder::abort_hook`] instead.
     ///
     /// The option must not contain any U+0000 code points except one at the end. A U+0000 code
     /// point at the end is not required, but on platforms where UTF-8 is the default character
@@ -176,8 +219,10 @@ impl<'a> InitArgsBuilder<'a> {
     ///
     /// See [the JNI specification][jni-options] for details on which options are accepted.
     ///
-    /// The `vfprintf`, `abort`, and `exit` options are unsupported at this time. Setting one of
-    /// these options has no effect.
+    /// The `vfprintf`, `abort`, and `exit` options can't be set via this method, since they require
+    /// a function pointer. Setting one of these options has no effect. See
+    /// [`InitArgsBuilder::vfprintf_hook`], [`InitArgsBuilder::exit_hook`] and
+    /// [`InitArgsBuilder::abort_hook`] instead.
     ///
     /// The option must not contain any U+0000 code points except one at the end. A U+0000 code
     /// point at the end is not required, but on platforms where UTF-8 is the default character
@@ -250,8 +295,10 @@ impl<'a> InitArgsBuilder<'a> {
     ///
     /// See [the JNI specification][jni-options] for details on which options are accepted.
     ///
-    /// The `vfprintf`, `abort`, and `exit` options are unsupported at this time. Setting one of
-    /// these options has no effect.
+    /// The `vfprintf`, `abort`, and `exit` options can't be set via this method, since they require
+    /// a function pointer. Setting one of these options has no effect. See
+    /// [`InitArgsBuilder::vfprintf_hook`], [`InitArgsBuilder::exit_hook`] and
+    /// [`InitArgsBuilder::abort_hook`] instead.
     ///
     /// This method does not fail, and will neither return nor defer an error.
     ///
@@ -276,6 +323,332 @@ impl<'a> InitArgsBuilder<'a> {
         self
     }
 
+    /// Sets the class path, via the `-Djava.class.path` option.
+    ///
+    /// The paths are joined with the platform's path separator (`:` on Unix, `;` on Windows) and
+    /// encoded in the platform default character encoding.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors described for [`InitArgsBuilder::option`], this fails if any of
+    /// the paths contain the platform's path separator (or a `"` on Windows), since the entry
+    /// couldn't be represented in the joined class path.
+    ///
+    /// Errors raised by this method are deferred. If an error occurs, it is returned from
+    /// [`InitArgsBuilder::build`] instead.
+    pub fn class_path<P: AsRef<Path>>(self, paths: impl IntoIterator<Item = P>) -> Self {
+        self.path_list_option("-Djava.class.path=", paths)
+    }
+
+    /// Sets the module path, via the `--module-path` option.
+    ///
+    /// The paths are joined and encoded in the same way as for [`InitArgsBuilder::class_path`].
+    ///
+    /// This option is only supported by Java 9 and later.
+    ///
+    /// # Errors
+    ///
+    /// See [`InitArgsBuilder::class_path`].
+    pub fn module_path<P: AsRef<Path>>(self, paths: impl IntoIterator<Item = P>) -> Self {
+        self.path_list_option("--module-path=", paths)
+    }
+
+    /// Sets a system property, via the `-D<key>=<value>` option.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors described for [`InitArgsBuilder::option`], this fails if `key`
+    /// is empty or contains an `=`.
+    ///
+    /// Errors raised by this method are deferred. If an error occurs, it is returned from
+    /// [`InitArgsBuilder::build`] instead.
+    pub fn system_property(self, key: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let (key, value) = (key.as_ref(), value.as_ref());
+        let opt_string = format!("-D{key}={value}");
+        if key.is_empty() {
+            return self.defer_error(JvmError::InvalidOption {
+                opt_string,
+                reason: "system property name is empty",
+            });
+        }
+        if key.contains('=') {
+            return self.defer_error(JvmError::InvalidOption {
+                opt_string,
+                reason: "system property name contains '='",
+            });
+        }
+        self.option(opt_string)
+    }
+
+    /// Sets the maximum heap size in bytes, via the `-Xmx` option.
+    ///
+    /// The JVM requires the size to be a multiple of 1024 bytes, and larger than 2MB.
+    ///
+    /// # Errors
+    ///
+    /// This fails if `bytes` is not a non-zero multiple of 1024.
+    ///
+    /// Errors raised by this method are deferred. If an error occurs, it is returned from
+    /// [`InitArgsBuilder::build`] instead.
+    pub fn max_heap(self, bytes: u64) -> Self {
+        self.heap_size_option("-Xmx", bytes)
+    }
+
+    /// Sets the initial heap size in bytes, via the `-Xms` option.
+    ///
+    /// The JVM requires the size to be a multiple of 1024 bytes, and larger than 1MB.
+    ///
+    /// # Errors
+    ///
+    /// See [`InitArgsBuilder::max_heap`].
+    pub fn initial_heap(self, bytes: u64) -> Self {
+        self.heap_size_option("-Xms", bytes)
+    }
+
+    /// Loads a native agent library by its full path, via the `-agentpath:<path>=<options>`
+    /// option.
+    ///
+    /// If `options` is empty, no `=<options>` suffix is added.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors described for [`InitArgsBuilder::option`], this fails if `path`
+    /// contains an `=`, since the JVM would treat the remainder of the path as agent options.
+    ///
+    /// Errors raised by this method are deferred. If an error occurs, it is returned from
+    /// [`InitArgsBuilder::build`] instead.
+    pub fn agent_path(self, path: impl AsRef<Path>, options: impl AsRef<str>) -> Self {
+        let path = path.as_ref();
+        let options = options.as_ref();
+        if path.as_os_str().to_string_lossy().contains('=') {
+            return self.defer_error(JvmError::InvalidOption {
+                opt_string: format!("-agentpath:{}", path.display()),
+                reason: "agent path contains '='",
+            });
+        }
+
+        let mut opt_string = OsString::from("-agentpath:");
+        opt_string.push(path);
+        if !options.is_empty() {
+            opt_string.push("=");
+            opt_string.push(options);
+        }
+        self.os_option(opt_string)
+    }
+
+    /// Enables additional checks for JNI functions, via the `-Xcheck:jni` option.
+    ///
+    /// This has a performance cost but is useful for catching misuse of JNI while testing, for
+    /// example via `.check_jni(cfg!(debug_assertions))`.
+    ///
+    /// Checks are disabled by default, so passing `false` doesn't add any option.
+    pub fn check_jni(self, enabled: bool) -> Self {
+        if enabled {
+            self.option("-Xcheck:jni")
+        } else {
+            self
+        }
+    }
+
+    /// Opens a package to a target module for deep reflection, via the
+    /// `--add-opens=<module>/<package>=<target>` option.
+    ///
+    /// `module_package` should be formatted like `java.base/java.lang` and `target` is either a
+    /// module name or `ALL-UNNAMED`.
+    ///
+    /// This option is only supported by Java 9 and later.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors described for [`InitArgsBuilder::option`], this fails if
+    /// `module_package` isn't of the form `<module>/<package>`, or if either argument is empty or
+    /// contains an `=` or whitespace.
+    ///
+    /// Errors raised by this method are deferred. If an error occurs, it is returned from
+    /// [`InitArgsBuilder::build`] instead.
+    pub fn add_opens(self, module_package: impl AsRef<str>, target: impl AsRef<str>) -> Self {
+        let (module_package, target) = (module_package.as_ref(), target.as_ref());
+        let opt_string = format!("--add-opens={module_package}={target}");
+
+        let is_invalid =
+            |s: &str| s.is_empty() || s.contains(|c: char| c == '=' || c.is_whitespace());
+        let reason = match module_package.split_once('/') {
+            None => Some("expected <module>/<package>"),
+            Some((module, package)) if is_invalid(module) || is_invalid(package) => {
+                Some("invalid module or package name")
+            }
+            Some(_) if is_invalid(target) => Some("invalid target module name"),
+            Some(_) => None,
+        };
+        if let Some(reason) = reason {
+            return self.defer_error(JvmError::InvalidOption { opt_string, reason });
+        }
+        self.option(opt_string)
+    }
+
+    /// Enables preview language and library features, via the `--enable-preview` option.
+    ///
+    /// This option is only supported by Java 11 and later.
+    pub fn enable_preview(self) -> Self {
+        self.option("--enable-preview")
+    }
+
+    /// Adds an option made up of a `prefix` and a list of paths, joined with the platform's path
+    /// separator.
+    fn path_list_option<P: AsRef<Path>>(
+        self,
+        prefix: &'static str,
+        paths: impl IntoIterator<Item = P>,
+    ) -> Self {
+        // `std::env::join_paths` isn't used because on Windows it quotes any path that contains
+        // a `;`, and the JVM doesn't understand quoted entries.
+        let mut opt_string = OsString::from(prefix);
+        let mut is_invalid = false;
+        for (i, path) in paths.into_iter().enumerate() {
+            let path = path.as_ref().as_os_str();
+            is_invalid |= path
+                .as_encoded_bytes()
+                .iter()
+                .any(|&b| b == PATH_SEPARATOR as u8 || (cfg!(windows) && b == b'"'));
+            if i > 0 {
+                opt_string.push(PATH_SEPARATOR.to_string());
+            }
+            opt_string.push(path);
+        }
+
+        if is_invalid {
+            return self.defer_error(JvmError::InvalidOption {
+                opt_string: opt_string.to_string_lossy().into_owned(),
+                reason: "path contains the platform's path separator (or a `\"` on Windows)",
+            });
+        }
+        self.os_option(opt_string)
+    }
+
+    /// Adds an option that may contain file system paths.
+    ///
+    /// On Unix, paths are arbitrary bytes, which are passed through as-is if they're not valid
+    /// UTF-8. Elsewhere, the option must be valid Unicode so that it can be converted to the
+    /// platform default character encoding.
+    fn os_option(self, opt_string: OsString) -> Self {
+        let opt_string = match opt_string.into_string() {
+            Ok(opt_string) => return self.option(opt_string),
+            Err(opt_string) => opt_string,
+        };
+
+        cfg_if! {
+            if #[cfg(unix)] {
+                use std::os::unix::ffi::OsStringExt;
+
+                match std::ffi::CString::new(opt_string.into_vec()) {
+                    Ok(encoded) => self.option_encoded(encoded),
+                    Err(error) => {
+                        let opt_string = String::from_utf8_lossy(&error.into_vec()).into_owned();
+                        self.defer_error(JvmError::NullOptString(opt_string))
+                    }
+                }
+            } else {
+                self.defer_error(JvmError::OptStringNotRepresentable {
+                    opt_string: opt_string.to_string_lossy().into_owned(),
+                })
+            }
+        }
+    }
+
+    fn heap_size_option(self, prefix: &'static str, bytes: u64) -> Self {
+        const KB: u64 = 1024;
+        const MB: u64 = 1024 * KB;
+        const GB: u64 = 1024 * MB;
+
+        let opt_string = match bytes {
+            0 => None,
+            b if b % GB == 0 => Some(format!("{prefix}{}g", b / GB)),
+            b if b % MB == 0 => Some(format!("{prefix}{}m", b / MB)),
+            b if b % KB == 0 => Some(format!("{prefix}{}k", b / KB)),
+            _ => None,
+        };
+        match opt_string {
+            Some(opt_string) => self.option(opt_string),
+            None => self.defer_error(JvmError::InvalidOption {
+                opt_string: format!("{prefix}{bytes}"),
+                reason: "heap size must be a non-zero multiple of 1024 bytes",
+            }),
+        }
+    }
+
+    /// Defers an error to [`InitArgsBuilder::build`], unless there's already a deferred error.
+    fn defer_error(mut self, error: JvmError) -> Self {
+        if self.opts.is_ok() {
+            self.opts = Err(error);
+        }
+        self
+    }
+
+    /// Sets a callback for the JVM's diagnostic output, via the special `vfprintf` option.
+    ///
+    /// Instead of writing to `stdout` or `stderr`, the JVM will pass its formatted output to
+    /// `hook`. Output isn't necessarily split into lines, and a single line may be written across
+    /// multiple calls.
+    ///
+    /// Since the JVM doesn't pass any context to the hook, the callback is stored globally when
+    /// the JVM is created. Any panic from the callback is caught and logged.
+    ///
+    /// This replaces the default hook, which forwards output to the [`log`] crate (see
+    /// [`InitArgsBuilder::default_hooks`]).
+    pub fn vfprintf_hook(mut self, hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.hooks.vfprintf = Some(std::sync::Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback that's called when the JVM exits the process, via the special `exit`
+    /// option.
+    ///
+    /// This is called with the exit code, for example when Java code calls `System.exit`. The
+    /// callback may exit the process itself (such as via [`std::process::exit`]), otherwise the
+    /// JVM will exit the process with the given code after the callback returns.
+    ///
+    /// Since the JVM doesn't pass any context to the hook, the callback is stored globally when
+    /// the JVM is created. Any panic from the callback is caught and logged.
+    pub fn exit_hook(mut self, hook: impl Fn(jint) + Send + Sync + 'static) -> Self {
+        self.hooks.exit = Some(std::sync::Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback that's called when the JVM aborts the process, via the special `abort`
+    /// option.
+    ///
+    /// This is called when the JVM encounters a fatal error. The JVM will abort the process after
+    /// the callback returns.
+    ///
+    /// Since the JVM doesn't pass any context to the hook, the callback is stored globally when
+    /// the JVM is created. Any panic from the callback is caught and logged.
+    pub fn abort_hook(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.hooks.abort = Some(std::sync::Arc::new(hook));
+        self
+    }
+
+    /// Enables or disables the default `vfprintf`, `exit` and `abort` hooks, which report to the
+    /// [`log`] crate.
+    ///
+    /// - The JVM's diagnostic output is buffered into lines, which are logged at the `Info` level
+    ///   with the `jni::jvm` target, instead of being written to `stdout` or `stderr`. Any
+    ///   incomplete last line is logged before the JVM exits or aborts the process.
+    /// - When the JVM exits the process, the exit code is logged (at the `Warn` level for a
+    ///   non-zero code) and the logger is flushed.
+    /// - When the JVM aborts the process, an error is logged and the logger is flushed.
+    ///
+    /// Any hook that's set via [`InitArgsBuilder::vfprintf_hook`], [`InitArgsBuilder::exit_hook`]
+    /// or [`InitArgsBuilder::abort_hook`] is used instead of the corresponding default hook.
+    ///
+    /// If disabled, the JVM's own defaults are used for any hook that's not set, which write to
+    /// `stderr` and exit or abort the process without notifying Rust code.
+    ///
+    /// Default: `true`
+    pub fn default_hooks(mut self, enabled: bool) -> Self {
+        self.default_hooks = enabled;
+        self
+    }
+
     /// Set JNI version for the init args
     ///
     /// Default: V8
@@ -304,15 +677,23 @@ impl<'a> InitArgsBuilder<'a> {
     ///
     /// If a call to [`InitArgsBuilder::option`] caused a deferred error, it is returned from this
     /// method.
-    pub fn build(self) -> Result<InitArgs<'a>, JvmError> {
+    pub fn build(mut self) -> Result<InitArgs<'a>, JvmError> {
         let opt_strings = self.opts?;
 
+        if self.default_hooks {
+            let hooks = &mut self.hooks;
+            hooks.vfprintf.get_or_insert_with(hooks::log_vfprintf_hook);
+            hooks.exit.get_or_insert_with(hooks::log_exit_hook);
+            hooks.abort.get_or_insert_with(hooks::log_abort_hook);
+        }
+
         let opts: Vec<JavaVMOption> = opt_strings
             .iter()
             .map(|opt_string| JavaVMOption {
                 optionString: opt_string.as_ptr() as _,
                 extraInfo: ptr::null_mut(),
             })
+            .chain(self.hooks.options())
             .collect();
 
         Ok(InitArgs {
@@ -324,6 +705,7 @@ impl<'a> InitArgsBuilder<'a> {
             },
             _opts: opts,
             _opt_strings: opt_strings,
+            hooks: self.hooks,
         })
     }
 
@@ -351,6 +733,10 @@ pub struct InitArgs<'a> {
     // Option strings are stored here. This ensures that any that are owned aren't dropped before
     // the JVM is finished with them.
     _opt_strings: Vec<Cow<'a, CStr>>,
+
+    // Callbacks for the special `vfprintf`, `exit` and `abort` options, which must be installed
+    // before the JVM is created.
+    hooks: JvmHooks,
 }
 
 impl InitArgs<'_> {
@@ -358,4 +744,165 @@ impl InitArgs<'_> {
     pub(crate) fn inner_ptr(&self) -> *mut c_void {
         &self.inner as *const _ as _
     }
+
+    /// Installs the callbacks for any `vfprintf`, `exit` or `abort` hooks, which must be done
+    /// before creating a JVM with these init args.
+    #[cfg(all(feature = "invocation", not(target_os = "android")))]
+    pub(crate) fn install_hooks(&self) {
+        self.hooks.install();
+    }
+}
+
+#[test]
+fn typed_options() {
+    use assert_matches::assert_matches;
+
+    let options = |builder: InitArgsBuilder<'static>| -> Vec<String> {
+        builder
+            .options()
+            .unwrap()
+            .iter()
+            .map(|opt| opt.to_str().unwrap().to_owned())
+            .collect()
+    };
+
+    let sep = PATH_SEPARATOR;
+    assert_eq!(
+        options(
+            InitArgsBuilder::new()
+                .class_path(["a dir/classes", "lib.jar"])
+                .module_path(["mods"])
+                .system_property("app.name", "hello world")
+                .max_heap(2 * 1024 * 1024 * 1024)
+                .initial_heap(64 * 1024 * 1024)
+                .agent_path("/opt/agent.so", "port=8000")
+                .agent_path("/opt/other.so", "")
+                .check_jni(true)
+                .check_jni(false)
+                .add_opens("java.base/java.lang", "ALL-UNNAMED")
+                .enable_preview()
+        ),
+        vec![
+            format!("-Djava.class.path=a dir/classes{sep}lib.jar"),
+            "--module-path=mods".to_owned(),
+            "-Dapp.name=hello world".to_owned(),
+            "-Xmx2g".to_owned(),
+            "-Xms64m".to_owned(),
+            "-agentpath:/opt/agent.so=port=8000".to_owned(),
+            "-agentpath:/opt/other.so".to_owned(),
+            "-Xcheck:jni".to_owned(),
+            "--add-opens=java.base/java.lang=ALL-UNNAMED".to_owned(),
+            "--enable-preview".to_owned(),
+        ]
+    );
+
+    let invalid = |builder: InitArgsBuilder<'static>| -> &'static str {
+        assert_matches!(builder.build().unwrap_err(), JvmError::InvalidOption { reason, .. } => reason)
+    };
+    invalid(InitArgsBuilder::new().class_path([format!("a{sep}b")]));
+    invalid(InitArgsBuilder::new().module_path(["a", &format!("b{sep}c")]));
+    #[cfg(windows)]
+    invalid(InitArgsBuilder::new().class_path(["a\"b"]));
+    invalid(InitArgsBuilder::new().system_property("", "value"));
+    invalid(InitArgsBuilder::new().system_property("a=b", "value"));
+    invalid(InitArgsBuilder::new().max_heap(0));
+    invalid(InitArgsBuilder::new().initial_heap(1000));
+    invalid(InitArgsBuilder::new().agent_path("/opt/a=b.so", ""));
+    invalid(InitArgsBuilder::new().add_opens("java.base", "ALL-UNNAMED"));
+    invalid(InitArgsBuilder::new().add_opens("java.base/java.lang", "ALL UNNAMED"));
+
+    // The first deferred error is kept
+    let error = InitArgsBuilder::new()
+        .system_property("", "first")
+        .max_heap(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(error.opt_string(), Some("-D=first"));
+
+    assert_matches!(
+        InitArgsBuilder::new().system_property("a", "b\0c").build(),
+        Err(JvmError::NullOptString(_))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn typed_options_non_utf8_path() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let builder = InitArgsBuilder::new().class_path([OsStr::from_bytes(b"caf\xe9.jar")]);
+    let options = builder.options().unwrap();
+    assert_eq!(options[0].to_bytes(), b"-Djava.class.path=caf\xe9.jar");
+}
+
+/// The default JavaVM init args, as reported by `JNI_GetDefaultJavaVMInitArgs`.
+///
+/// *This API requires "invocation" feature to be enabled,
+/// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+///
+/// See [`JavaVM::default_init_args`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefaultInitArgs {
+    version: JNIVersion,
+    ignore_unrecognized: bool,
+    options: Vec<String>,
+}
+
+impl DefaultInitArgs {
+    /// Reads the default init args that were written by `JNI_GetDefaultJavaVMInitArgs`.
+    ///
+    /// # Safety
+    ///
+    /// `args.options` must be null or point to `args.nOptions` valid options, whose option strings
+    /// must be null or valid nul-terminated strings.
+    #[cfg(all(feature = "invocation", not(target_os = "android")))]
+    pub(crate) unsafe fn from_raw(args: &JavaVMInitArgs) -> Self {
+        let options = if args.options.is_null() || args.nOptions <= 0 {
+            vec![]
+        } else {
+            let options =
+                unsafe { std::slice::from_raw_parts(args.options, args.nOptions as usize) };
+            options
+                .iter()
+                .filter(|option| !option.optionString.is_null())
+                .map(|option| {
+                    unsafe { CStr::from_ptr(option.optionString) }
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect()
+        };
+        DefaultInitArgs {
+            version: JNIVersion::from(args.version),
+            ignore_unrecognized: args.ignoreUnrecognized,
+            options,
+        }
+    }
+
+    /// Returns the JNI version, which is the version that was requested.
+    pub fn version(&self) -> JNIVersion {
+        self.version
+    }
+
+    /// Returns the default `ignoreUnrecognized` flag.
+    pub fn ignore_unrecognized(&self) -> bool {
+        self.ignore_unrecognized
+    }
+
+    /// Returns any default options.
+    ///
+    /// JVMs typically don't report any default options.
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Returns an [`InitArgsBuilder`] that's initialized with these defaults.
+    pub fn to_builder(&self) -> InitArgsBuilder<'static> {
+        self.options.iter().fold(
+            InitArgsBuilder::new()
+                .version(self.version)
+                .ignore_unrecognized(self.ignore_unrecognized),
+            |builder, option| builder.option(option.clone()),
+        )
+    }
 }
@@ -0,0 +1,237 @@
+//! Support for the special `vfprintf`, `exit` and `abort` JVM options, whose `extraInfo` is a
+//! function pointer that the JVM calls instead of writing to `stderr`, exiting or aborting.
+//!
+//! The JVM doesn't pass any user data to these hooks, so the Rust callbacks are stored globally
+//! when the JVM is created (there can only be one JVM per process).
+
+use std::{
+    ffi::{CStr, c_void},
+    fmt,
+    os::raw::{c_char, c_int},
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use crate::sys::{JavaVMOption, jint};
+
+pub(super) type VfprintfHook = Arc<dyn Fn(&str) + Send + Sync>;
+pub(super) type ExitHook = Arc<dyn Fn(jint) + Send + Sync>;
+pub(super) type AbortHook = Arc<dyn Fn() + Send + Sync>;
+
+static VFPRINTF_HOOK: RwLock<Option<VfprintfHook>> = RwLock::new(None);
+static EXIT_HOOK: RwLock<Option<ExitHook>> = RwLock::new(None);
+static ABORT_HOOK: RwLock<Option<AbortHook>> = RwLock::new(None);
+
+/// Output from the default `vfprintf` hook that hasn't been logged yet, since it's not a complete
+/// line
+static PENDING_LOG_LINE: Mutex<String> = Mutex::new(String::new());
+
+/// The Rust callbacks for the `vfprintf`, `exit` and `abort` JVM options
+#[derive(Default, Clone)]
+pub(super) struct JvmHooks {
+    pub(super) vfprintf: Option<VfprintfHook>,
+    pub(super) exit: Option<ExitHook>,
+    pub(super) abort: Option<AbortHook>,
+}
+
+impl fmt::Debug for JvmHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JvmHooks")
+            .field("vfprintf", &self.vfprintf.is_some())
+            .field("exit", &self.exit.is_some())
+            .field("abort", &self.abort.is_some())
+            .finish()
+    }
+}
+
+impl JvmHooks {
+    /// Returns the `JavaVMOption`s that will route the JVM's hooks to the trampolines below.
+    pub(super) fn options(&self) -> impl Iterator<Item = JavaVMOption> + '_ {
+        let vfprintf = self.vfprintf.as_ref().map(|_| JavaVMOption {
+            optionString: c"vfprintf".as_ptr() as _,
+            extraInfo: vfprintf_trampoline as *mut c_void,
+        });
+        let exit = self.exit.as_ref().map(|_| JavaVMOption {
+            optionString: c"exit".as_ptr() as _,
+            extraInfo: exit_trampoline as *mut c_void,
+        });
+        let abort = self.abort.as_ref().map(|_| JavaVMOption {
+            optionString: c"abort".as_ptr() as _,
+            extraInfo: abort_trampoline as *mut c_void,
+        });
+        vfprintf.into_iter().chain(exit).chain(abort)
+    }
+
+    /// Makes these hooks the ones called by the trampolines.
+    ///
+    /// This must be called before the JVM is created with the options from
+    /// [`JvmHooks::options`].
+    #[cfg(not(target_os = "android"))]
+    pub(super) fn install(&self) {
+        fn set<T: Clone>(lock: &RwLock<Option<T>>, hook: &Option<T>) {
+            if hook.is_some() {
+                *lock.write().unwrap_or_else(|e| e.into_inner()) = hook.clone();
+            }
+        }
+        set(&VFPRINTF_HOOK, &self.vfprintf);
+        set(&EXIT_HOOK, &self.exit);
+        set(&ABORT_HOOK, &self.abort);
+    }
+}
+
+/// Returns the default `vfprintf` hook, which forwards the JVM's output to the [`log`] crate.
+///
+/// The JVM often writes a single line in multiple parts, so output is buffered until a complete
+/// line has been written, and then logged with the `jni::jvm` target. Any incomplete line is
+/// logged by [`flush_pending_log_line`] before the JVM exits or aborts the process.
+pub(super) fn log_vfprintf_hook() -> VfprintfHook {
+    Arc::new(|output: &str| {
+        let mut buffer = PENDING_LOG_LINE.lock().unwrap_or_else(|e| e.into_inner());
+        buffer.push_str(output);
+        while let Some(end) = buffer.find('\n') {
+            log_line(&buffer[..end]);
+            buffer.drain(..=end);
+        }
+    })
+}
+
+/// Logs any incomplete line of output from the default `vfprintf` hook, such as the last line of
+/// a fatal error message that's written just before the JVM exits or aborts.
+fn flush_pending_log_line() {
+    let mut buffer = PENDING_LOG_LINE.lock().unwrap_or_else(|e| e.into_inner());
+    if !buffer.is_empty() {
+        log_line(&buffer);
+        buffer.clear();
+        log::logger().flush();
+    }
+}
+
+fn log_line(line: &str) {
+    let line = line.trim_end_matches('\r');
+    if !line.is_empty() {
+        log::info!(target: "jni::jvm", "{line}");
+    }
+}
+
+/// Returns the default `exit` hook, which logs the exit code before the JVM exits the process.
+pub(super) fn log_exit_hook() -> ExitHook {
+    Arc::new(|code| {
+        if code == 0 {
+            log::info!(target: "jni::jvm", "JVM is exiting the process with code {code}");
+        } else {
+            log::warn!(target: "jni::jvm", "JVM is exiting the process with code {code}");
+        }
+        log::logger().flush();
+    })
+}
+
+/// Returns the default `abort` hook, which logs an error before the JVM aborts the process.
+pub(super) fn log_abort_hook() -> AbortHook {
+    Arc::new(|| {
+        log::error!(target: "jni::jvm", "JVM is aborting the process");
+        log::logger().flush();
+    })
+}
+
+/// Calls a hook, catching any panic since it's not possible to unwind into the JVM.
+fn call_hook<T: ?Sized>(name: &str, lock: &RwLock<Option<Arc<T>>>, f: impl FnOnce(&T)) {
+    let hook = lock.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        if catch_unwind(AssertUnwindSafe(|| f(&hook))).is_err() {
+            log::error!("Panic in JVM {name} hook");
+        }
+    }
+}
+
+/// An opaque C `va_list`
+///
+/// On all supported platforms, a `va_list` function argument is passed as a pointer, either
+/// because `va_list` is a pointer or array type, or because it's a large struct that gets
+/// passed by reference.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+struct VaList(*mut c_void);
+
+unsafe extern "system" fn vfprintf_trampoline(
+    _stream: *mut c_void,
+    format: *const c_char,
+    args: VaList,
+) -> jint {
+    // Safety: the format string and arguments are passed through from the JVM
+    let Some(output) = (unsafe { format_va_list(format, args) }) else {
+        return -1;
+    };
+    call_hook("vfprintf", &VFPRINTF_HOOK, |hook| hook(&output));
+    output.len().try_into().unwrap_or(jint::MAX)
+}
+
+unsafe extern "system" fn exit_trampoline(code: jint) {
+    // A panic can't unwind into the JVM, and there's no useful way to report it
+    let _ = catch_unwind(flush_pending_log_line);
+    call_hook("exit", &EXIT_HOOK, |hook| hook(code));
+}
+
+unsafe extern "system" fn abort_trampoline() {
+    let _ = catch_unwind(flush_pending_log_line);
+    call_hook("abort", &ABORT_HOOK, |hook| hook());
+}
+
+#[cfg(unix)]
+unsafe extern "C" {
+    fn vasprintf(strp: *mut *mut c_char, format: *const c_char, args: VaList) -> c_int;
+    fn free(ptr: *mut c_void);
+}
+
+/// Formats a `printf` style format string and arguments into a `String`.
+///
+/// Any invalid UTF-8 in the output will be replaced with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// # Safety
+///
+/// `format` must be a valid nul-terminated format string and `args` must be the `va_list` of
+/// arguments for the format string, which must not be used again.
+#[cfg(unix)]
+unsafe fn format_va_list(format: *const c_char, args: VaList) -> Option<String> {
+    let mut output: *mut c_char = std::ptr::null_mut();
+    unsafe {
+        if vasprintf(&mut output, format, args) < 0 {
+            return None;
+        }
+        let string = CStr::from_ptr(output).to_string_lossy().into_owned();
+        free(output as *mut c_void);
+        Some(string)
+    }
+}
+
+// Note: the Universal CRT defines `vsnprintf` inline in its headers, so the linkable definition
+// comes from `legacy_stdio_definitions`
+#[cfg(windows)]
+#[cfg_attr(target_env = "msvc", link(name = "legacy_stdio_definitions"))]
+unsafe extern "C" {
+    fn vsnprintf(buffer: *mut c_char, count: usize, format: *const c_char, args: VaList) -> c_int;
+}
+
+/// Formats a `printf` style format string and arguments into a `String`.
+///
+/// Any invalid UTF-8 in the output will be replaced with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// # Safety
+///
+/// `format` must be a valid nul-terminated format string and `args` must be the `va_list` of
+/// arguments for the format string.
+#[cfg(windows)]
+unsafe fn format_va_list(format: *const c_char, args: VaList) -> Option<String> {
+    // On Windows a `va_list` is a plain pointer, so it can be used more than once
+    unsafe {
+        let len = usize::try_from(vsnprintf(std::ptr::null_mut(), 0, format, args)).ok()?;
+        let mut buffer = vec![0u8; len + 1];
+        vsnprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            format,
+            args,
+        );
+        let string = CStr::from_bytes_until_nul(&buffer).ok()?;
+        Some(string.to_string_lossy().into_owned())
+    }
+}
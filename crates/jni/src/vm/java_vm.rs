@@ -10,7 +10,7 @@ use log::debug;
 
 use crate::{
     JNIVersion,
-    env::Env,
+    env::{Env, EnvUnowned},
     errors::*,
     objects::{Global, JObject, JThrowable, Reference},
     strings::{JNIStr, JNIString},
@@ -20,7 +20,27 @@ use crate::{
 #[cfg(all(feature = "invocation", not(target_os = "android")))]
 use std::{os::raw::c_void, path::PathBuf};
 #[cfg(feature = "invocation")]
-use {crate::InitArgs, std::ffi::OsStr};
+use {
+    crate::{DefaultInitArgs, InitArgs},
+    std::ffi::OsStr,
+};
+
+/// The signature of `JNI_GetDefaultJavaVMInitArgs`
+#[cfg(all(feature = "invocation", not(target_os = "android")))]
+type GetDefaultInitArgsFn = unsafe extern "system" fn(args: *mut c_void) -> sys::jint;
+
+/// Locates the JVM shared library using [java-locator](https://crates.io/crates/java-locator).
+#[cfg(all(feature = "invocation", not(target_os = "android")))]
+fn locate_libjvm() -> StartJvmResult<PathBuf> {
+    Ok([
+        java_locator::locate_jvm_dyn_library()
+            .map_err(StartJvmError::NotFound)?
+            .as_str(),
+        java_locator::get_jvm_dyn_lib_file_name(),
+    ]
+    .iter()
+    .collect::<PathBuf>())
+}
 
 #[cfg(use_fls_attach_guard)]
 use super::fls_attach_guard;
@@ -28,10 +48,7 @@ use super::fls_attach_guard;
 use super::tls_attach_guard;
 
 #[cfg(doc)]
-use {
-    crate::env::{self, EnvUnowned},
-    crate::objects,
-};
+use {crate::env, crate::objects};
 
 /// The capacity of local frames, allocated for attached threads by default. Same as the default
 /// value Hotspot uses when calling native Java methods.
@@ -202,21 +219,13 @@ impl JavaVM {
     /// This will attempt to locate a JVM using
     /// [java-locator], if the JVM has not already been loaded. Use the
     /// [`with_libjvm`][Self::with_libjvm] method to give an explicit location for the JVM shared
-    /// library (`jvm.dll`, `libjvm.so`, or `libjvm.dylib`, depending on the platform).
+    /// library (`jvm.dll`, `libjvm.so`, or `libjvm.dylib`, depending on the platform), or see
+    /// [`JvmLocator`](crate::JvmLocator) for selecting a JVM by version, vendor or architecture.
     #[cfg(feature = "invocation")]
     pub fn new(args: InitArgs) -> StartJvmResult<Self> {
         #[cfg(not(target_os = "android"))]
         {
-            Self::with_libjvm(args, || {
-                Ok([
-                    java_locator::locate_jvm_dyn_library()
-                        .map_err(StartJvmError::NotFound)?
-                        .as_str(),
-                    java_locator::get_jvm_dyn_lib_file_name(),
-                ]
-                .iter()
-                .collect::<PathBuf>())
-            })
+            Self::with_libjvm(args, locate_libjvm)
         }
 
         #[cfg(target_os = "android")]
@@ -283,6 +292,8 @@ impl JavaVM {
         let mut ptr: *mut sys::JavaVM = ::std::ptr::null_mut();
         let mut env: *mut sys::JNIEnv = ::std::ptr::null_mut();
 
+        args.install_hooks();
+
         unsafe {
             jni_error_code_to_result(create_fn_ptr(
                 &mut ptr as *mut _,
@@ -412,6 +423,317 @@ impl JavaVM {
         }
     }
 
+    /// Get the Java VM that has already been created within the current process (by other code,
+    /// such as a host application that started Java itself), via `JNI_GetCreatedJavaVMs`.
+    ///
+    /// Returns `Ok(None)` if no Java VM has been created.
+    ///
+    /// If a Java VM is found then this will also initialize the [`JavaVM::singleton`].
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// This will attempt to locate the JVM shared library using [java-locator], in the same way as
+    /// [`JavaVM::new`]. Since the shared library must be the same one that has already been loaded
+    /// by the process, use [`get_created_with_libjvm`][Self::get_created_with_libjvm] if you need
+    /// to give an explicit location for the library.
+    ///
+    /// This can be useful if your code is loaded into a process that has already started Java,
+    /// and you don't have any `JNI_OnLoad` (or native method) call to get a [`JavaVM`] from.
+    ///
+    /// [java-locator]: https://crates.io/crates/java-locator
+    #[cfg(feature = "invocation")]
+    pub fn get_created() -> StartJvmResult<Option<Self>> {
+        #[cfg(not(target_os = "android"))]
+        {
+            Self::get_created_with_libjvm(locate_libjvm)
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    /// Get the Java VM that has already been created within the current process, via
+    /// `JNI_GetCreatedJavaVMs`, from the given JVM shared library.
+    ///
+    /// Returns `Ok(None)` if no Java VM has been created.
+    ///
+    /// If a Java VM is found then this will also initialize the [`JavaVM::singleton`].
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// The `libjvm_path` parameter takes a *closure* which returns the path to the JVM shared
+    /// library. This should be the same library that was used to create the Java VM (if a
+    /// different library is loaded then it won't be able to find the Java VM).
+    #[cfg(feature = "invocation")]
+    pub fn get_created_with_libjvm<P: AsRef<OsStr>>(
+        libjvm_path: impl FnOnce() -> StartJvmResult<P>,
+    ) -> StartJvmResult<Option<Self>> {
+        #[cfg(not(target_os = "android"))]
+        {
+            Self::impl_get_created_with_libjvm(libjvm_path)
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            let _libjvm_path = libjvm_path;
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    #[cfg(all(feature = "invocation", not(target_os = "android")))]
+    fn impl_get_created_with_libjvm<P: AsRef<OsStr>>(
+        libjvm_path: impl FnOnce() -> StartJvmResult<P>,
+    ) -> StartJvmResult<Option<Self>> {
+        // Determine the path to the shared library.
+        let libjvm_path = libjvm_path()?;
+        let libjvm_path_string = libjvm_path.as_ref().to_string_lossy().into_owned();
+
+        // Try to load it (if the library has already been loaded by the process then this will
+        // just return a new handle for the same library).
+        let libjvm = match unsafe { libloading::Library::new(libjvm_path.as_ref()) } {
+            Ok(ok) => ok,
+            Err(error) => return Err(StartJvmError::LoadError(libjvm_path_string, error)),
+        };
+
+        // Try to find the `JNI_GetCreatedJavaVMs` function in the loaded library.
+        let get_created_fn = unsafe {
+            libjvm
+                .get::<unsafe extern "system" fn(
+                    vm_buf: *mut *mut sys::JavaVM,
+                    buf_len: sys::jsize,
+                    n_vms: *mut sys::jsize,
+                ) -> sys::jint>(b"JNI_GetCreatedJavaVMs\0")
+                .map(|get_created_fn| *get_created_fn)
+        };
+
+        // Prevent libjvm from ever being unloaded, even if there's no Java VM yet, since it's not
+        // clear that a JVM library can be safely unloaded and later reloaded (see
+        // `impl_with_libjvm`).
+        std::mem::forget(libjvm);
+
+        let get_created_fn =
+            get_created_fn.map_err(|error| StartJvmError::LoadError(libjvm_path_string, error))?;
+
+        let mut ptr: *mut sys::JavaVM = ptr::null_mut();
+        let mut count: sys::jsize = 0;
+        unsafe {
+            // Note: JNI doesn't support creating more than one Java VM per process
+            jni_error_code_to_result(get_created_fn(&mut ptr, 1, &mut count))?;
+        }
+
+        if count < 1 || ptr.is_null() {
+            return Ok(None);
+        }
+
+        // Note: if the singleton has already been initialized then it must be for the same JVM,
+        // since there can only be one per process
+        Ok(Some(unsafe { Self::from_raw(ptr) }))
+    }
+
+    /// Get the default init args that the JVM reports for the given JNI `version`, via
+    /// `JNI_GetDefaultJavaVMInitArgs`, without creating a Java VM.
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// This will attempt to locate the JVM shared library using [java-locator], in the same way as
+    /// [`JavaVM::new`]. Use [`default_init_args_with_libjvm`][Self::default_init_args_with_libjvm]
+    /// to give an explicit location for the library.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StartJvmError::Create`] with [`JniError::WrongVersion`] if the JVM doesn't
+    /// support the requested `version` (JNI 1.1 isn't supported by this API).
+    ///
+    /// [java-locator]: https://crates.io/crates/java-locator
+    #[cfg(feature = "invocation")]
+    pub fn default_init_args(version: JNIVersion) -> StartJvmResult<DefaultInitArgs> {
+        #[cfg(not(target_os = "android"))]
+        {
+            Self::default_init_args_with_libjvm(version, locate_libjvm)
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            let _version = version;
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    /// Get the default init args that the JVM reports for the given JNI `version`, via
+    /// `JNI_GetDefaultJavaVMInitArgs`, from the given JVM shared library.
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// This loads the shared library (which then stays loaded) but doesn't create a Java VM.
+    ///
+    /// # Errors
+    ///
+    /// See [`JavaVM::default_init_args`].
+    #[cfg(feature = "invocation")]
+    pub fn default_init_args_with_libjvm<P: AsRef<OsStr>>(
+        version: JNIVersion,
+        libjvm_path: impl FnOnce() -> StartJvmResult<P>,
+    ) -> StartJvmResult<DefaultInitArgs> {
+        #[cfg(not(target_os = "android"))]
+        {
+            let get_default_init_args_fn = Self::load_get_default_init_args_fn(libjvm_path)?;
+            unsafe { Self::call_get_default_init_args(get_default_init_args_fn, version) }
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            let _version = version;
+            let _libjvm_path = libjvm_path;
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    /// Probe the highest JNI version that's supported by the JVM, via
+    /// `JNI_GetDefaultJavaVMInitArgs`, without creating a Java VM.
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// The result can be passed to [`crate::InitArgsBuilder::version`] instead of hard-coding a version.
+    /// Only versions that are known to this crate (those with a [`JNIVersion`] constant) are
+    /// probed, so a newer JVM will report the newest version that's known.
+    ///
+    /// This will attempt to locate the JVM shared library using [java-locator], in the same way as
+    /// [`JavaVM::new`]. Use
+    /// [`highest_supported_version_with_libjvm`][Self::highest_supported_version_with_libjvm] to
+    /// give an explicit location for the library.
+    ///
+    /// [java-locator]: https://crates.io/crates/java-locator
+    #[cfg(feature = "invocation")]
+    pub fn highest_supported_version() -> StartJvmResult<JNIVersion> {
+        #[cfg(not(target_os = "android"))]
+        {
+            Self::highest_supported_version_with_libjvm(locate_libjvm)
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    /// Probe the highest JNI version that's supported by the given JVM shared library, via
+    /// `JNI_GetDefaultJavaVMInitArgs`, without creating a Java VM.
+    ///
+    /// *This API requires the "invocation" feature to be enabled,
+    /// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+    ///
+    /// This loads the shared library (which then stays loaded) but doesn't create a Java VM.
+    ///
+    /// See [`JavaVM::highest_supported_version`].
+    #[cfg(feature = "invocation")]
+    pub fn highest_supported_version_with_libjvm<P: AsRef<OsStr>>(
+        libjvm_path: impl FnOnce() -> StartJvmResult<P>,
+    ) -> StartJvmResult<JNIVersion> {
+        #[cfg(not(target_os = "android"))]
+        {
+            const VERSIONS: &[JNIVersion] = &[
+                JNIVersion::V24,
+                JNIVersion::V21,
+                JNIVersion::V20,
+                JNIVersion::V19,
+                JNIVersion::V10,
+                JNIVersion::V9,
+                JNIVersion::V1_8,
+                JNIVersion::V1_6,
+                JNIVersion::V1_4,
+                JNIVersion::V1_2,
+            ];
+
+            let get_default_init_args_fn = Self::load_get_default_init_args_fn(libjvm_path)?;
+            for version in VERSIONS {
+                match unsafe {
+                    Self::call_get_default_init_args(get_default_init_args_fn, *version)
+                } {
+                    Ok(_) => return Ok(*version),
+                    Err(StartJvmError::Create(Error::JniCall(JniError::WrongVersion))) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            Err(StartJvmError::Create(Error::JniCall(
+                JniError::WrongVersion,
+            )))
+        }
+
+        #[cfg(target_os = "android")]
+        {
+            let _libjvm_path = libjvm_path;
+            Err(StartJvmError::Unsupported)
+        }
+    }
+
+    #[cfg(all(feature = "invocation", not(target_os = "android")))]
+    fn load_get_default_init_args_fn<P: AsRef<OsStr>>(
+        libjvm_path: impl FnOnce() -> StartJvmResult<P>,
+    ) -> StartJvmResult<GetDefaultInitArgsFn> {
+        // Determine the path to the shared library.
+        let libjvm_path = libjvm_path()?;
+        let libjvm_path_string = libjvm_path.as_ref().to_string_lossy().into_owned();
+
+        let libjvm = match unsafe { libloading::Library::new(libjvm_path.as_ref()) } {
+            Ok(ok) => ok,
+            Err(error) => return Err(StartJvmError::LoadError(libjvm_path_string, error)),
+        };
+
+        let get_default_init_args_fn = unsafe {
+            libjvm
+                .get::<GetDefaultInitArgsFn>(b"JNI_GetDefaultJavaVMInitArgs\0")
+                .map(|get_default_init_args_fn| *get_default_init_args_fn)
+        };
+
+        // Keep libjvm loaded, since it's not clear that a JVM library can be safely unloaded and
+        // later reloaded, and the function pointer must remain valid (see `impl_with_libjvm`).
+        std::mem::forget(libjvm);
+
+        get_default_init_args_fn
+            .map_err(|error| StartJvmError::LoadError(libjvm_path_string, error))
+    }
+
+    /// # Safety
+    ///
+    /// `get_default_init_args_fn` must be a `JNI_GetDefaultJavaVMInitArgs` function pointer from
+    /// a loaded JVM library.
+    #[cfg(all(feature = "invocation", not(target_os = "android")))]
+    unsafe fn call_get_default_init_args(
+        get_default_init_args_fn: GetDefaultInitArgsFn,
+        version: JNIVersion,
+    ) -> StartJvmResult<DefaultInitArgs> {
+        // JNI 1.1 used a different (larger) `JDK1_1InitArgs` struct that we don't support
+        if version < JNIVersion::V1_2 {
+            return Err(StartJvmError::Create(Error::JniCall(
+                JniError::WrongVersion,
+            )));
+        }
+
+        let mut args = sys::JavaVMInitArgs {
+            version: version.into(),
+            nOptions: 0,
+            options: ptr::null_mut(),
+            ignoreUnrecognized: sys::JNI_FALSE,
+        };
+        let res = unsafe { get_default_init_args_fn(&mut args as *mut _ as *mut c_void) };
+        if res != sys::JNI_OK {
+            // HotSpot returns JNI_ERR (not JNI_EVERSION) for an unsupported version, which is the
+            // only documented reason for this function to fail
+            return Err(StartJvmError::Create(Error::JniCall(
+                JniError::WrongVersion,
+            )));
+        }
+
+        Ok(unsafe { DefaultInitArgs::from_raw(&args) })
+    }
+
     /// Create a JavaVM from a raw pointer.
     ///
     /// # Safety
@@ -737,8 +1059,9 @@ impl JavaVM {
                     Err(Error::JniCall(JniError::ThreadDetached)) => {
                         let config = config();
                         if config.scoped {
-                            let jni = sys_attach_current_thread(self, &config, &current(), true)?;
-                            AttachGuard::from_owned(jni)
+                            let jni =
+                                sys_attach_current_thread(self, &config, &current(), true, false)?;
+                            AttachGuard::from_owned(jni, config.daemon)
                         } else {
                             #[cfg(use_tls_attach_guard)]
                             {
@@ -810,12 +1133,25 @@ impl JavaVM {
     ///
     /// This method is provided mostly for diagnostic purposes / unit tests.
     ///
+    /// This includes threads that were attached as daemon threads (see [`AttachConfig::daemon`]).
+    ///
     /// Check the docs for `ATTACHED_THREADS` for more details - including caveats for Windows with FLS.
     #[doc(hidden)]
     pub fn threads_attached(&self) -> usize {
         ATTACHED_THREADS.load(Ordering::SeqCst)
     }
 
+    /// Returns the current number of threads attached to the JVM as daemon threads.
+    ///
+    /// This only counts the thread attachments made via this library's API, with
+    /// [`AttachConfig::daemon`].
+    ///
+    /// This method is provided mostly for diagnostic purposes / unit tests.
+    #[doc(hidden)]
+    pub fn daemon_threads_attached(&self) -> usize {
+        ATTACHED_DAEMON_THREADS.load(Ordering::SeqCst)
+    }
+
     /// Returns the current nesting level for [`AttachGuard`]s
     ///
     /// This is only really public since it's useful for unit tests
@@ -856,6 +1192,25 @@ impl JavaVM {
         }
     }
 
+    /// Get an [`EnvUnowned`] for the current thread's existing JNI attachment, via `GetEnv`.
+    ///
+    /// This is used to implement library entry points (such as `JNI_OnLoad`) that are called by
+    /// the JVM on an attached thread, without passing a `JNIEnv` pointer.
+    ///
+    /// # Safety
+    ///
+    /// The returned [`EnvUnowned`] must not outlive the current thread's attachment (in
+    /// practice, it should only be used within the entry point that was called by the JVM).
+    pub(crate) unsafe fn sys_get_env_unowned<'local>(&self) -> Result<EnvUnowned<'local>> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let res =
+                java_vm_call_unchecked!(self, v1_2, GetEnv, &mut ptr, JNIVersion::V1_4.into());
+            jni_error_code_to_result(res)?;
+            Ok(EnvUnowned::from_raw(ptr as *mut sys::JNIEnv))
+        }
+    }
+
     /// Returns `true` if the current thread is attached to a Java VM.
     ///
     /// Since this calls [`sys::JNIInvokeInterface__1_2::GetEnv`], it will also recognize thread
@@ -872,6 +1227,30 @@ impl JavaVM {
         }
     }
 
+    /// Returns a JVMTI environment that supports the given JVMTI `version` (via
+    /// [`sys::JNIInvokeInterface__1_2::GetEnv`]).
+    ///
+    /// Each call creates a new, independent JVMTI environment, with its own capabilities and
+    /// object tags.
+    ///
+    /// During the live phase, HotSpot requires the current thread to be attached to the Java VM,
+    /// otherwise this will return [`Error::JniCall`] with [`JniError::ThreadDetached`].
+    ///
+    /// If the requested version isn't supported this will return [`Error::JniCall`] with
+    /// [`JniError::WrongVersion`].
+    pub fn get_jvmti_env(
+        &self,
+        version: crate::jvmti::JvmtiVersion,
+    ) -> Result<crate::jvmti::JvmtiEnv> {
+        // Safety: we require JNI >= 1.4, so GetEnv is available
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let res = java_vm_call_unchecked!(self, v1_2, GetEnv, &mut ptr, version.into());
+            jni_error_code_to_result(res)?;
+            crate::jvmti::JvmtiEnv::from_raw(ptr as *mut crate::jvmti::sys::jvmtiEnv)
+        }
+    }
+
     /// Returns an [`AttachGuard`] for the [`Env`] associated with the current thread, if there is
     /// already at least one `AttachGuard` on the stack for the current thread.
     ///
@@ -1039,12 +1418,12 @@ impl JavaVM {
     /// RAII types that will automatically make JNI calls within their `Drop`
     /// implementation.
     ///
-    /// For this reason `jni-rs` doesn't directly support attaching or detaching
-    /// 'daemon' threads and it's assumed you will manage their safety yourself
-    /// if you're using them.
+    /// Threads can be attached as daemon threads via [`AttachConfig::daemon`],
+    /// but it's assumed you will manage their safety yourself if you're using
+    /// them.
     ///
     /// Note: [`JavaVM::detach_current_thread()`] is a no-op for daemon threads
-    /// because it will only detach threads that were attached via `jni-rs` APIs.
+    /// that were not attached via `jni-rs` APIs.
     ///
     /// ## Don't call from a Java native function
     ///
@@ -1176,6 +1555,7 @@ pub enum AttachmentExceptionPolicy {
 #[derive(Debug, Default)]
 pub struct AttachConfig<'a> {
     scoped: bool,
+    pub(super) daemon: bool,
     exceptions_policy: AttachmentExceptionPolicy,
     deprecated_thread_name: Option<JNIString>,
     thread_name: Option<&'a JNIStr>,
@@ -1201,6 +1581,25 @@ impl<'a> AttachConfig<'a> {
         self
     }
 
+    /// Sets whether the thread should be attached as a daemon thread (via
+    /// `AttachCurrentThreadAsDaemon`).
+    ///
+    /// The default is `false`.
+    ///
+    /// The JVM doesn't wait for daemon threads before it shuts down, so this
+    /// can be used for background threads that shouldn't block
+    /// [`JavaVM::destroy`] (which waits for all non-daemon threads to detach).
+    ///
+    /// Note: this has no effect if the thread is already attached.
+    ///
+    /// **Beware** that it's your responsibility to ensure that daemon threads
+    /// stop using JNI before the JVM is destroyed - see the "Daemon thread
+    /// rules" for [`JavaVM::destroy`].
+    pub fn daemon(mut self, daemon: bool) -> Self {
+        self.daemon = daemon;
+        self
+    }
+
     /// Sets the policy for handling exceptions during thread attachment.
     ///
     /// The default is [`AttachmentExceptionPolicy::PreReThrowPostCatch`] which
@@ -1255,11 +1654,21 @@ impl<'a> AttachConfig<'a> {
 /// if detach_current_thread() is called from each fiber).
 pub(super) static ATTACHED_THREADS: AtomicUsize = AtomicUsize::new(0);
 
+/// Tracks the number of logical attachments managed by this crate that were attached as daemon
+/// threads (these are also counted in `ATTACHED_THREADS`).
+///
+/// Whether an attachment is a daemon attachment is tracked with the attachment itself: in the
+/// TLS/FLS record for a permanent attachment, or the owning [`AttachGuard`] for a scoped
+/// attachment. (On Windows, permanent attachments are tracked per fiber, so the status can't be
+/// tracked per thread.)
+pub(super) static ATTACHED_DAEMON_THREADS: AtomicUsize = AtomicUsize::new(0);
+
 pub(super) unsafe fn sys_attach_current_thread(
     vm: &JavaVM,
     config: &AttachConfig,
     thread: &Thread,
     inc_attached_count: bool,
+    was_daemon: bool,
 ) -> Result<*mut sys::JNIEnv> {
     assert_eq!(JavaVM::thread_attach_guard_level(), 0);
     let mut env_ptr = ptr::null_mut();
@@ -1283,13 +1692,23 @@ pub(super) unsafe fn sys_attach_current_thread(
             .unwrap_or(ptr::null_mut()),
     };
     let res = unsafe {
-        java_vm_call_unchecked!(
-            vm,
-            v1_1,
-            AttachCurrentThread,
-            &mut env_ptr,
-            &mut args as *mut sys::JavaVMAttachArgs as *mut core::ffi::c_void
-        )
+        if config.daemon {
+            java_vm_call_unchecked!(
+                vm,
+                v1_4,
+                AttachCurrentThreadAsDaemon,
+                &mut env_ptr,
+                &mut args as *mut sys::JavaVMAttachArgs as *mut core::ffi::c_void
+            )
+        } else {
+            java_vm_call_unchecked!(
+                vm,
+                v1_1,
+                AttachCurrentThread,
+                &mut env_ptr,
+                &mut args as *mut sys::JavaVMAttachArgs as *mut core::ffi::c_void
+            )
+        }
     };
     jni_error_code_to_result(res)?;
 
@@ -1305,10 +1724,24 @@ pub(super) unsafe fn sys_attach_current_thread(
         ATTACHED_THREADS.fetch_add(1, Ordering::SeqCst);
     }
 
+    // When re-attaching, the previous attachment (whose record is being replaced) may have had a
+    // different daemon status
+    let was_daemon = was_daemon && !inc_attached_count;
+    match (was_daemon, config.daemon) {
+        (false, true) => {
+            ATTACHED_DAEMON_THREADS.fetch_add(1, Ordering::SeqCst);
+        }
+        (true, false) => {
+            ATTACHED_DAEMON_THREADS.fetch_sub(1, Ordering::SeqCst);
+        }
+        _ => {}
+    }
+
     debug!(
-        "Attached thread {} ({:?}). {} threads attached",
+        "Attached thread {} ({:?}){}. {} threads attached",
         thread.name().unwrap_or_default(),
         thread.id(),
+        if config.daemon { " as daemon" } else { "" },
         ATTACHED_THREADS.load(Ordering::SeqCst)
     );
 
@@ -1338,6 +1771,9 @@ fn windows_is_shutdown_in_progress() -> bool {
 /// `cross_check_env` is not provided for permanent TLS/FLS attachments since we allow external
 /// code to detach these early (as long as there are no AttachGuards currently on the stack).
 ///
+/// `daemon` should be the daemon status that was recorded for the attachment, which determines
+/// whether `ATTACHED_DAEMON_THREADS` is also decremented.
+///
 /// The `ATTACHED_THREADS` count is always decremented when this is called, even if the thread
 /// was already detached by other means (e.g., manual `DetachCurrentThread` call via raw JNI API,
 /// or by another fiber on Windows). This maintains correct logical accounting - we track how many
@@ -1345,6 +1781,7 @@ fn windows_is_shutdown_in_progress() -> bool {
 pub(super) unsafe fn sys_detach_current_thread(
     cross_check_env: Option<*mut jni_sys::JNIEnv>,
     thread: &Thread,
+    daemon: bool,
 ) -> Result<()> {
     assert_eq!(JavaVM::thread_attach_guard_level(), 0);
 
@@ -1434,6 +1871,9 @@ pub(super) unsafe fn sys_detach_current_thread(
     // - On Windows with FLS: each fiber attachment increments this counter
     // - On other platforms with TLS: each thread attachment increments this counter
     ATTACHED_THREADS.fetch_sub(1, Ordering::SeqCst);
+    if daemon {
+        ATTACHED_DAEMON_THREADS.fetch_sub(1, Ordering::SeqCst);
+    }
 
     debug!(
         "{} attachments remain (logical count)",
@@ -1541,6 +1981,8 @@ thread_local! {
 #[derive(Debug)]
 pub struct AttachGuard<'local> {
     env: Env<'local>,
+    /// Whether the attachment that's owned by this guard was attached as a daemon thread
+    daemon: bool,
 }
 
 /// Increments the thread guard level, returning the new level.
@@ -1592,11 +2034,12 @@ impl<'local> AttachGuard<'local> {
     /// **IMPORTANTLY:** Never give the returned guard a `'static` lifetime; the
     /// guard must be treated as an immovable value on the stack and not be
     /// re-ordered relative to other guards on the stack.
-    unsafe fn from_owned(env: *mut sys::JNIEnv) -> Self {
+    unsafe fn from_owned(env: *mut sys::JNIEnv, daemon: bool) -> Self {
         let level = thread_guard_level_push(env);
 
         let guard = Self {
             env: unsafe { Env::new(env, level, true) },
+            daemon,
         };
 
         // Guarantee that if you have an `AttachGuard` then
@@ -1632,6 +2075,7 @@ impl<'local> AttachGuard<'local> {
         let level = thread_guard_level_push(env);
         let guard = Self {
             env: unsafe { Env::new(env, level, false) },
+            daemon: false,
         };
 
         // Guarantee that if you have an `AttachGuard` then
@@ -1707,7 +2151,9 @@ impl<'local> AttachGuard<'local> {
                 new_level, 0,
                 "Spurious AttachGuard that owns its attachment but is nested under another guard"
             );
-            unsafe { sys_detach_current_thread(Some(self.env.raw), &std::thread::current()) }
+            unsafe {
+                sys_detach_current_thread(Some(self.env.raw), &std::thread::current(), self.daemon)
+            }
         } else {
             Ok(())
         };
@@ -0,0 +1,667 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    JNIVersion, JavaVM,
+    errors::{StartJvmError, StartJvmResult},
+};
+
+#[cfg(doc)]
+use crate::InitArgsBuilder;
+
+/// A Java version, as read from the `JAVA_VERSION` property of a JDK's `release` file
+///
+/// Legacy version strings like `1.8.0_292` are normalized so that the `major` version is `8` and
+/// the update number (`292`) is treated as the `patch` version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JavaVersion {
+    /// The feature release number, such as `17`
+    pub major: u32,
+    /// The interim release number, which is normally `0`
+    pub minor: u32,
+    /// The update release number
+    pub patch: u32,
+}
+
+impl JavaVersion {
+    /// Parses a Java version string, such as `17.0.2`, `21`, `11.0.12+7` or `1.8.0_292`.
+    ///
+    /// Returns `None` if the version string isn't recognized.
+    pub fn parse(version: &str) -> Option<Self> {
+        // Strip any build number or pre-release identifier
+        let version = version.trim().split(['+', '-']).next().unwrap_or_default();
+        let mut parts = version.split(['.', '_']);
+        let mut major: u32 = parts.next()?.parse().ok()?;
+        let mut minor: u32 = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+        let mut patch: u32 = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+        if major == 1 {
+            // Legacy `1.<major>.0_<update>` version
+            let update: u32 = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+            (major, minor, patch) = (minor, 0, update);
+        }
+        Some(JavaVersion {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    fn components(&self) -> [u32; 3] {
+        [self.major, self.minor, self.patch]
+    }
+}
+
+impl fmt::Display for JavaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A version comparison operator, for a [`JvmLocator::version`] requirement
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum VersionOp {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// A single comparison within a [`JvmLocator::version`] requirement, such as `>=17`
+#[derive(Debug, Clone)]
+struct VersionComparator {
+    op: VersionOp,
+    /// The `major[.minor[.patch]]` components that were given
+    components: Vec<u32>,
+}
+
+impl VersionComparator {
+    fn parse(comparator: &str) -> Option<Self> {
+        let comparator = comparator.trim();
+        let (op, version) = [
+            (">=", VersionOp::Ge),
+            ("<=", VersionOp::Le),
+            (">", VersionOp::Gt),
+            ("<", VersionOp::Lt),
+            ("=", VersionOp::Eq),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| comparator.strip_prefix(prefix).map(|v| (op, v)))
+        .unwrap_or((VersionOp::Eq, comparator));
+
+        let components = version
+            .trim()
+            .split('.')
+            .map(|c| c.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        if components.is_empty() || components.len() > 3 {
+            return None;
+        }
+        Some(VersionComparator { op, components })
+    }
+
+    /// Only compares as many components as were given, so that `<=21` matches `21.0.2` and
+    /// `=17` matches any `17.x.y` version.
+    fn matches(&self, version: &JavaVersion) -> bool {
+        let ordering = version.components()[..self.components.len()].cmp(&self.components[..]);
+        match self.op {
+            VersionOp::Eq => ordering == Ordering::Equal,
+            VersionOp::Gt => ordering == Ordering::Greater,
+            VersionOp::Ge => ordering != Ordering::Less,
+            VersionOp::Lt => ordering == Ordering::Less,
+            VersionOp::Le => ordering != Ordering::Greater,
+        }
+    }
+}
+
+/// Normalizes an architecture name, so that `OS_ARCH` values from a `release` file (such as
+/// `amd64`) can be compared with [`std::env::consts::ARCH`] (such as `x86_64`).
+fn normalize_arch(arch: &str) -> String {
+    match arch.to_ascii_lowercase().as_str() {
+        "amd64" | "x64" => "x86_64".to_string(),
+        "i386" | "i486" | "i586" | "i686" | "x86_32" => "x86".to_string(),
+        "arm64" => "aarch64".to_string(),
+        "ppc64" | "ppc64le" => "powerpc64".to_string(),
+        "ppc" => "powerpc".to_string(),
+        arch => arch.to_string(),
+    }
+}
+
+/// A Java installation that was found by a [`JvmLocator`]
+///
+/// The version, vendor and architecture are read from the `release` file in the installation's
+/// home directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jdk {
+    home: PathBuf,
+    release: BTreeMap<String, String>,
+}
+
+impl Jdk {
+    /// Reads the Java installation at `home`, such as `/usr/lib/jvm/java-17-openjdk-amd64`.
+    ///
+    /// Returns `None` if `home` doesn't look like a Java installation (if it has neither a
+    /// `release` file nor a JVM shared library).
+    pub fn from_home(home: impl AsRef<Path>) -> Option<Self> {
+        let home = home.as_ref();
+        let release = match fs::read_to_string(home.join("release")) {
+            Ok(release) => parse_release(&release),
+            Err(_) if find_libjvm(home, None).is_some() => BTreeMap::new(),
+            Err(_) => return None,
+        };
+        Some(Jdk {
+            home: home.to_path_buf(),
+            release,
+        })
+    }
+
+    /// Returns the home directory of this Java installation.
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    /// Returns the Java version (`JAVA_VERSION`), if known.
+    pub fn version(&self) -> Option<JavaVersion> {
+        self.release_property("JAVA_VERSION")
+            .and_then(JavaVersion::parse)
+    }
+
+    /// Returns the vendor (`IMPLEMENTOR`), if known.
+    pub fn vendor(&self) -> Option<&str> {
+        self.release_property("IMPLEMENTOR")
+    }
+
+    /// Returns the architecture (`OS_ARCH`), if known.
+    ///
+    /// This is returned as-is from the `release` file, so it may be different from the
+    /// corresponding [`std::env::consts::ARCH`] name (for example `amd64` instead of `x86_64`).
+    pub fn arch(&self) -> Option<&str> {
+        self.release_property("OS_ARCH")
+    }
+
+    /// Returns a property from the installation's `release` file, with any quotes removed.
+    pub fn release_property(&self, key: &str) -> Option<&str> {
+        self.release.get(key).map(String::as_str)
+    }
+
+    /// Returns the path to the JVM shared library (`jvm.dll`, `libjvm.so`, or `libjvm.dylib`,
+    /// depending on the platform), which can be passed to [`JavaVM::with_libjvm`].
+    ///
+    /// The `server` JVM is preferred over the `client` JVM.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StartJvmError::LibjvmNotFound`] if the shared library wasn't found.
+    pub fn libjvm_path(&self) -> StartJvmResult<PathBuf> {
+        find_libjvm(&self.home, self.arch())
+            .ok_or_else(|| StartJvmError::LibjvmNotFound(self.home.clone()))
+    }
+
+    /// Probes the highest JNI version that's supported by this installation's JVM, without
+    /// creating a Java VM, for passing to [`InitArgsBuilder::version`].
+    ///
+    /// See [`JavaVM::highest_supported_version_with_libjvm`].
+    pub fn highest_supported_version(&self) -> StartJvmResult<JNIVersion> {
+        JavaVM::highest_supported_version_with_libjvm(|| self.libjvm_path())
+    }
+}
+
+impl fmt::Display for Jdk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.home.display())?;
+        let version = self.version().map(|v| v.to_string());
+        let details: Vec<&str> = [version.as_deref(), self.vendor(), self.arch()]
+            .into_iter()
+            .flatten()
+            .collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the `KEY="value"` lines of a JDK `release` file.
+fn parse_release(release: &str) -> BTreeMap<String, String> {
+    release
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            (key.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+/// Finds the JVM shared library within a Java installation.
+fn find_libjvm(home: &Path, arch: Option<&str>) -> Option<PathBuf> {
+    let lib_name = java_locator::get_jvm_dyn_lib_file_name();
+
+    // Java 8 used legacy architecture names for its `lib/<arch>` directories
+    let legacy_arch = match normalize_arch(arch.unwrap_or(env::consts::ARCH)).as_str() {
+        "x86_64" => "amd64",
+        "x86" => "i386",
+        "powerpc64" => "ppc64le",
+        other => return find_libjvm_in(home, lib_name, other),
+    };
+    find_libjvm_in(home, lib_name, legacy_arch)
+}
+
+fn find_libjvm_in(home: &Path, lib_name: &str, legacy_arch: &str) -> Option<PathBuf> {
+    let dirs = [
+        PathBuf::from("lib/server"),
+        PathBuf::from("bin/server"),
+        PathBuf::from("jre/lib/server"),
+        PathBuf::from("jre/bin/server"),
+        Path::new("jre/lib").join(legacy_arch).join("server"),
+        Path::new("lib").join(legacy_arch).join("server"),
+        PathBuf::from("lib/client"),
+        PathBuf::from("bin/client"),
+        PathBuf::from("jre/bin/client"),
+        Path::new("jre/lib").join(legacy_arch).join("client"),
+    ];
+    dirs.iter()
+        .map(|dir| home.join(dir).join(lib_name))
+        .find(|path| path.is_file())
+}
+
+/// Discovers Java installations and selects one that satisfies a set of requirements
+///
+/// *This API requires "invocation" feature to be enabled,
+/// see ["Launching JVM from Rust"](struct.JavaVM.html#launching-jvm-from-rust).*
+///
+/// Candidate installations are discovered from (in order):
+///
+/// 1. Any roots added via [`JvmLocator::root`]
+/// 2. The `JAVA_HOME` environment variable
+/// 3. Any `java` executable found via the `PATH` environment variable (following symlinks)
+/// 4. System-wide installation directories, such as `/usr/lib/jvm` on Linux,
+///    `/Library/Java/JavaVirtualMachines` on macOS, or `C:\Program Files\Java` on Windows
+///
+/// Each installation's version, vendor and architecture are read from its `release` file.
+///
+/// [`JvmLocator::locate`] selects the newest installation that satisfies all the requirements,
+/// with ties broken by the order of discovery, so the selection is deterministic for a given
+/// set of installations.
+///
+/// By default, only installations that match the architecture of the current process are
+/// selected, since a JVM with a different architecture can't be loaded.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni::{InitArgsBuilder, JavaVM, JvmLocator};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let locator = JvmLocator::new().version(">=17, <22");
+/// let args = InitArgsBuilder::new().build()?;
+/// let jvm = JavaVM::with_libjvm(args, || locator.locate_libjvm())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JvmLocator {
+    roots: Vec<PathBuf>,
+    java_home: bool,
+    path: bool,
+    system_roots: bool,
+    version: Result<Vec<VersionComparator>, String>,
+    version_req: Option<String>,
+    vendor: Option<String>,
+    arch: Option<String>,
+}
+
+impl Default for JvmLocator {
+    fn default() -> Self {
+        JvmLocator {
+            roots: vec![],
+            java_home: true,
+            path: true,
+            system_roots: true,
+            version: Ok(vec![]),
+            version_req: None,
+            vendor: None,
+            arch: Some(env::consts::ARCH.to_string()),
+        }
+    }
+}
+
+impl JvmLocator {
+    /// Creates a new `JvmLocator` that searches the default locations, without any version or
+    /// vendor requirements.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a root directory to search, before any of the default locations.
+    ///
+    /// A root may either be a Java installation itself, or a directory that contains Java
+    /// installations (such as `/usr/lib/jvm`). Roots are searched in the order they're added.
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.roots.push(root.into());
+        self
+    }
+
+    /// Sets whether to search the `JAVA_HOME` environment variable.
+    ///
+    /// Default: `true`
+    pub fn java_home(mut self, enabled: bool) -> Self {
+        self.java_home = enabled;
+        self
+    }
+
+    /// Sets whether to search for a `java` executable via the `PATH` environment variable.
+    ///
+    /// Default: `true`
+    pub fn path(mut self, enabled: bool) -> Self {
+        self.path = enabled;
+        self
+    }
+
+    /// Sets whether to search system-wide installation directories, such as `/usr/lib/jvm`.
+    ///
+    /// Default: `true`
+    pub fn system_roots(mut self, enabled: bool) -> Self {
+        self.system_roots = enabled;
+        self
+    }
+
+    /// Requires the Java version to satisfy a requirement, such as `>=17`.
+    ///
+    /// A requirement is a comma separated list of comparisons, which must all be satisfied.
+    /// Each comparison is one of `=`, `>`, `>=`, `<` or `<=` followed by a `major[.minor[.patch]]`
+    /// version. A version without an operator is an exact match, so `17` is the same as `=17`.
+    ///
+    /// Only the version components that are given are compared, so `17` matches any `17.x.y`
+    /// version and `<=21` matches `21.0.2`.
+    ///
+    /// An installation without a known version never satisfies a version requirement.
+    ///
+    /// # Errors
+    ///
+    /// If the requirement can't be parsed, the error is deferred and returned from
+    /// [`JvmLocator::locate`] as [`StartJvmError::InvalidVersionRequirement`].
+    pub fn version(mut self, requirement: &str) -> Self {
+        self.version = requirement
+            .split(',')
+            .map(VersionComparator::parse)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| requirement.to_string());
+        self.version_req = Some(requirement.to_string());
+        self
+    }
+
+    /// Requires the vendor (the `IMPLEMENTOR` property of the `release` file) to contain `vendor`,
+    /// ignoring case.
+    ///
+    /// For example, `"adoptium"` matches `Eclipse Adoptium`.
+    pub fn vendor(mut self, vendor: impl Into<String>) -> Self {
+        self.vendor = Some(vendor.into());
+        self
+    }
+
+    /// Requires the architecture (the `OS_ARCH` property of the `release` file) to match `arch`,
+    /// or `None` to allow any architecture.
+    ///
+    /// Architecture names are normalized, so `amd64` and `x86_64` are considered the same.
+    /// Installations without a known architecture are assumed to match.
+    ///
+    /// Default: [`std::env::consts::ARCH`]
+    pub fn arch(mut self, arch: Option<&str>) -> Self {
+        self.arch = arch.map(str::to_string);
+        self
+    }
+
+    /// Returns all discovered Java installations, in the order of discovery, regardless of
+    /// whether they satisfy the requirements.
+    ///
+    /// Installations that are found multiple times (such as via both `JAVA_HOME` and `PATH`) are
+    /// only returned once.
+    pub fn candidates(&self) -> Vec<Jdk> {
+        let mut homes: Vec<PathBuf> = vec![];
+
+        for root in &self.roots {
+            homes.extend(homes_in_root(root));
+        }
+        if self.java_home {
+            if let Some(java_home) = env::var_os("JAVA_HOME").filter(|h| !h.is_empty()) {
+                homes.push(PathBuf::from(java_home));
+            }
+        }
+        if self.path {
+            if let Some(path) = env::var_os("PATH") {
+                homes.extend(env::split_paths(&path).filter_map(|dir| home_from_path_dir(&dir)));
+            }
+        }
+        if self.system_roots {
+            for root in system_roots() {
+                homes.extend(homes_in_root(&root));
+            }
+        }
+
+        let mut seen = vec![];
+        homes
+            .into_iter()
+            .filter(|home| {
+                let canonical = fs::canonicalize(home).unwrap_or_else(|_| home.clone());
+                if seen.contains(&canonical) {
+                    false
+                } else {
+                    seen.push(canonical);
+                    true
+                }
+            })
+            .filter_map(Jdk::from_home)
+            .collect()
+    }
+
+    /// Selects the newest discovered Java installation that satisfies all the requirements.
+    ///
+    /// # Errors
+    ///
+    /// - [`StartJvmError::InvalidVersionRequirement`] if the [`JvmLocator::version`] requirement
+    ///   couldn't be parsed.
+    /// - [`StartJvmError::NoMatchingJdk`] if no installation satisfies the requirements. The error
+    ///   describes each candidate and why it was rejected.
+    pub fn locate(&self) -> StartJvmResult<Jdk> {
+        let comparators = self
+            .version
+            .as_ref()
+            .map_err(|req| StartJvmError::InvalidVersionRequirement(req.clone()))?;
+
+        let mut rejected = vec![];
+        let mut selected: Option<Jdk> = None;
+        for jdk in self.candidates() {
+            match self.check(&jdk, comparators) {
+                Err(reason) => rejected.push(format!("{jdk}: {reason}")),
+                Ok(()) => {
+                    if selected
+                        .as_ref()
+                        .is_none_or(|selected| jdk.version() > selected.version())
+                    {
+                        selected = Some(jdk);
+                    }
+                }
+            }
+        }
+
+        selected.ok_or_else(|| StartJvmError::NoMatchingJdk {
+            requirements: self.describe_requirements(),
+            rejected,
+        })
+    }
+
+    /// Selects a Java installation via [`JvmLocator::locate`] and returns the path to its JVM
+    /// shared library, for passing to [`JavaVM::with_libjvm`].
+    pub fn locate_libjvm(&self) -> StartJvmResult<PathBuf> {
+        self.locate()?.libjvm_path()
+    }
+
+    /// Checks whether a Java installation satisfies the requirements, or returns why not.
+    fn check(&self, jdk: &Jdk, comparators: &[VersionComparator]) -> Result<(), String> {
+        if !comparators.is_empty() {
+            let version_req = self.version_req.as_deref().unwrap_or_default();
+            match jdk.version() {
+                None => return Err("unknown version".to_string()),
+                Some(version) if !comparators.iter().all(|c| c.matches(&version)) => {
+                    return Err(format!("version {version} doesn't match {version_req}"));
+                }
+                Some(_) => {}
+            }
+        }
+        if let Some(vendor) = &self.vendor {
+            let matches = jdk
+                .vendor()
+                .is_some_and(|v| v.to_lowercase().contains(&vendor.to_lowercase()));
+            if !matches {
+                return Err(format!("vendor isn't {vendor:?}"));
+            }
+        }
+        if let (Some(arch), Some(jdk_arch)) = (&self.arch, jdk.arch()) {
+            if normalize_arch(jdk_arch) != normalize_arch(arch) {
+                return Err(format!("architecture isn't {arch}"));
+            }
+        }
+        if let Err(err) = jdk.libjvm_path() {
+            return Err(err.to_string());
+        }
+        Ok(())
+    }
+
+    fn describe_requirements(&self) -> String {
+        let mut requirements = vec![];
+        if let Some(version_req) = &self.version_req {
+            requirements.push(format!("version {version_req}"));
+        }
+        if let Some(vendor) = &self.vendor {
+            requirements.push(format!("vendor {vendor:?}"));
+        }
+        if let Some(arch) = &self.arch {
+            requirements.push(format!("architecture {arch}"));
+        }
+        if requirements.is_empty() {
+            "any".to_string()
+        } else {
+            requirements.join(", ")
+        }
+    }
+}
+
+/// Returns the Java installation homes within a root directory, which may itself be a home.
+fn homes_in_root(root: &Path) -> Vec<PathBuf> {
+    if is_home(root) {
+        return vec![root.to_path_buf()];
+    }
+    let Ok(entries) = fs::read_dir(root) else {
+        return vec![];
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    // Sort for a deterministic discovery order
+    dirs.sort();
+    dirs.into_iter()
+        .filter_map(|dir| {
+            // macOS bundles have their home in `Contents/Home`
+            let bundle_home = dir.join("Contents").join("Home");
+            if is_home(&bundle_home) {
+                Some(bundle_home)
+            } else if is_home(&dir) {
+                Some(dir)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn is_home(dir: &Path) -> bool {
+    dir.join("release").is_file() || find_libjvm(dir, None).is_some()
+}
+
+/// Returns the Java installation home for a `PATH` directory that contains a `java` executable.
+fn home_from_path_dir(dir: &Path) -> Option<PathBuf> {
+    let java = dir.join(format!("java{}", env::consts::EXE_SUFFIX));
+    if !java.is_file() {
+        return None;
+    }
+    // Follow symlinks, such as `/usr/bin/java` -> `/usr/lib/jvm/<jdk>/bin/java`
+    let java = fs::canonicalize(&java).unwrap_or(java);
+    let home = java.parent()?.parent()?;
+
+    // A Java 8 JDK has a separate `jre` home, but only the JDK home has a `release` file
+    if home.file_name().is_some_and(|name| name == "jre") {
+        if let Some(jdk_home) = home.parent().filter(|h| h.join("release").is_file()) {
+            return Some(jdk_home.to_path_buf());
+        }
+    }
+    Some(home.to_path_buf())
+}
+
+/// Returns the system-wide directories where Java installations are typically found.
+fn system_roots() -> Vec<PathBuf> {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "macos")] {
+            let mut roots = vec![PathBuf::from("/Library/Java/JavaVirtualMachines")];
+            if let Some(home) = env::var_os("HOME") {
+                roots.push(Path::new(&home).join("Library/Java/JavaVirtualMachines"));
+            }
+            roots
+        } else if #[cfg(windows)] {
+            let program_files = env::var_os("ProgramFiles")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(r"C:\Program Files"));
+            ["Java", "Eclipse Adoptium", "Microsoft", "Zulu"]
+                .iter()
+                .map(|vendor| program_files.join(vendor))
+                .collect()
+        } else {
+            vec![PathBuf::from("/usr/lib/jvm"), PathBuf::from("/usr/lib64/jvm")]
+        }
+    }
+}
+
+#[test]
+fn java_version_parse() {
+    let v = |major, minor, patch| {
+        Some(JavaVersion {
+            major,
+            minor,
+            patch,
+        })
+    };
+    assert_eq!(JavaVersion::parse("17.0.2"), v(17, 0, 2));
+    assert_eq!(JavaVersion::parse("21"), v(21, 0, 0));
+    assert_eq!(JavaVersion::parse("11.0.12+7"), v(11, 0, 12));
+    assert_eq!(JavaVersion::parse("1.8.0_292"), v(8, 0, 292));
+    assert_eq!(JavaVersion::parse("1.8.0"), v(8, 0, 0));
+    assert_eq!(JavaVersion::parse("22-ea"), v(22, 0, 0));
+    assert_eq!(JavaVersion::parse("abc"), None);
+
+    let matches = |req: &str, version: &str| {
+        let version = JavaVersion::parse(version).unwrap();
+        req.split(',')
+            .map(|c| VersionComparator::parse(c).unwrap())
+            .all(|c| c.matches(&version))
+    };
+    assert!(matches(">=17", "17.0.2"));
+    assert!(matches(">=17", "21"));
+    assert!(!matches(">=17", "11.0.12"));
+    assert!(matches("17", "17.0.15"));
+    assert!(!matches("=17.0.2", "17.0.15"));
+    assert!(matches("<=21", "21.0.2"));
+    assert!(!matches("<21", "21.0.2"));
+    assert!(matches(">11, <21", "17"));
+    assert!(!matches(">11", "11.0.2"));
+    assert!(VersionComparator::parse(">=x").is_none());
+    assert!(VersionComparator::parse("1.2.3.4").is_none());
+}
@@ -3,9 +3,17 @@ mod init_args;
 #[cfg(feature = "invocation")]
 pub use self::init_args::*;
 
+#[cfg(all(feature = "invocation", not(target_os = "android")))]
+mod locator;
+#[cfg(all(feature = "invocation", not(target_os = "android")))]
+pub use self::locator::*;
+
 mod java_vm;
 pub use self::java_vm::*;
 
+mod on_load;
+pub use self::on_load::*;
+
 #[cfg(use_fls_attach_guard)]
 mod fls_attach_guard;
 #[cfg(not(use_fls_attach_guard))]
@@ -0,0 +1,121 @@
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::{
+    Env, EnvUnowned,
+    errors::{Error, Result},
+    objects::JClassLoader,
+    refs::Reference,
+    strings::JNIString,
+    sys::{self, jint},
+    vm::JavaVM,
+};
+
+/// Logs a panic payload that was caught at a library entry point, such as `JNI_OnLoad`
+pub(crate) fn log_entry_point_panic(
+    entry_point: &str,
+    payload: Box<dyn std::any::Any + Send + 'static>,
+) {
+    let panic_string = match payload.downcast::<&'static str>() {
+        Ok(s) => (*s).to_string(),
+        Err(payload) => match payload.downcast::<String>() {
+            Ok(s) => *s,
+            Err(payload) => {
+                // Since it's possible that dropping a panic payload may itself panic,
+                // we catch any panic and fallback to forgetting/leaking the payload.
+                if let Err(drop_panic) = catch_unwind(AssertUnwindSafe(|| drop(payload))) {
+                    std::mem::forget(drop_panic);
+                }
+                "non-string panic payload".to_string()
+            }
+        },
+    };
+    log::error!("Rust panic in {entry_point}: {panic_string}");
+}
+
+/// Implements a `JNI_OnLoad` entry point.
+///
+/// This is used by code generated by the [`jni::on_load`](crate::on_load) attribute macro.
+///
+/// `f` is called with an [`EnvUnowned`] for the current thread, which the generated code upgrades
+/// to an [`Env`](crate::Env) via [`EnvUnowned::with_env`] before resolving the outcome into a
+/// JNI version via an [`ErrorPolicy`](crate::errors::ErrorPolicy), in the same way as for native
+/// methods.
+///
+/// Any panic outside of `with_env` is logged before returning `JNI_ERR`.
+///
+/// # Safety
+///
+/// `vm` must be a valid `JavaVM` pointer, as passed to `JNI_OnLoad`.
+#[doc(hidden)]
+pub unsafe fn __jni_on_load<F>(vm: *mut sys::JavaVM, f: F) -> jint
+where
+    F: for<'local> FnOnce(&JavaVM, EnvUnowned<'local>) -> jint,
+{
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let vm = unsafe { JavaVM::from_raw(vm) };
+
+        // JNI_OnLoad is always called on a thread that's attached to the JVM (the thread that
+        // is loading the library)
+        //
+        // Safety: the EnvUnowned is only used while JNI_OnLoad is running
+        let unowned_env = unsafe { vm.sys_get_env_unowned()? };
+        Ok::<_, Error>(f(&vm, unowned_env))
+    }));
+    match res {
+        Ok(Ok(version)) => version,
+        Ok(Err(err)) => {
+            log::error!("Failed to get Env in JNI_OnLoad: {err}");
+            sys::JNI_ERR
+        }
+        Err(payload) => {
+            log_entry_point_panic("JNI_OnLoad", payload);
+            sys::JNI_ERR
+        }
+    }
+}
+
+/// Returns the class loader that `FindClass` resolves the class of `T` with, while running
+/// `JNI_OnLoad`.
+///
+/// This is used by code generated by the [`jni::on_load`](crate::on_load) attribute macro to
+/// look up each `natives` class (which registers its native methods) with an explicit
+/// [`LoaderContext::Loader`](crate::refs::LoaderContext::Loader).
+///
+/// Within `JNI_OnLoad`, `FindClass` uses the class loader of the class that called
+/// `System.loadLibrary`, whereas [`LoaderContext::None`](crate::refs::LoaderContext::None)
+/// would first check the thread's context class loader, which may find a same-named class from
+/// an unrelated loader (or no class at all).
+#[doc(hidden)]
+pub fn __jni_on_load_class_loader<'local, T: Reference>(
+    env: &mut Env<'local>,
+) -> Result<JClassLoader<'local>> {
+    // `FindClass` would initialize the class itself, which may depend on the native methods that
+    // haven't been registered yet, but finding an array class only loads its element class (with
+    // the same loader)
+    let name = JNIString::new(format!("[L{};", T::class_name().to_str().replace('.', "/")));
+    let array_class = env.find_class(&name)?;
+    array_class.get_class_loader(env)
+}
+
+/// Implements a `JNI_OnUnload` entry point.
+///
+/// This is used by code generated by the [`jni::on_load`](crate::on_load) attribute macro.
+///
+/// Any panic is caught and logged.
+///
+/// # Safety
+///
+/// `vm` must be a valid `JavaVM` pointer, as passed to `JNI_OnUnload`.
+#[doc(hidden)]
+pub unsafe fn __jni_on_unload<F>(vm: *mut sys::JavaVM, f: F)
+where
+    F: FnOnce(&JavaVM),
+{
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let vm = unsafe { JavaVM::from_raw(vm) };
+        f(&vm)
+    }));
+    if let Err(payload) = res {
+        log_entry_point_panic("JNI_OnUnload", payload);
+    }
+}
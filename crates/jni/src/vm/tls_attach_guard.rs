@@ -39,6 +39,8 @@ struct TLSAttachGuard {
     /// Since this struct will be saved as a thread-local variable, we capture the thread meta-data
     /// during creation
     thread: Thread,
+    /// Whether the thread was attached as a daemon thread
+    daemon: bool,
 }
 
 impl TLSAttachGuard {
@@ -48,7 +50,7 @@ impl TLSAttachGuard {
     /// Since this is used in the implementation of `Drop` you must make sure
     /// to not let `Drop` run if this is called explicitly.
     unsafe fn detach_impl(&self) -> Result<()> {
-        unsafe { sys_detach_current_thread(None, &self.thread) }
+        unsafe { sys_detach_current_thread(None, &self.thread, self.daemon) }
     }
 }
 
@@ -88,17 +90,22 @@ pub(super) unsafe fn tls_attach_current_thread<'local>(
         // but some external code manually detached it (this is allowed as long as there are no
         // active AttachGuards). In this case we don't want to double-increment the attached
         // threads counter.
-        let inc_attached_count = if let Some(guard) = f.borrow_mut().take() {
+        let (inc_attached_count, was_daemon) = if let Some(guard) = f.borrow_mut().take() {
+            let was_daemon = guard.daemon;
             // We use `std::mem::forget` to ensure we don't drop the existing guard and
             // call detach again.
             std::mem::forget(guard);
-            false
+            (false, was_daemon)
         } else {
-            true
+            (true, false)
         };
-        let env =
-            unsafe { sys_attach_current_thread(java_vm, config, &thread, inc_attached_count)? };
-        *f.borrow_mut() = Some(TLSAttachGuard { thread: current() });
+        let env = unsafe {
+            sys_attach_current_thread(java_vm, config, &thread, inc_attached_count, was_daemon)?
+        };
+        *f.borrow_mut() = Some(TLSAttachGuard {
+            thread: current(),
+            daemon: config.daemon,
+        });
         Ok(env)
     })?;
 
@@ -0,0 +1,159 @@
+#![cfg(feature = "invocation")]
+//! Tests that the `#[jni::on_load]` attribute exports a `JNI_OnLoad` function that registers
+//! the native methods for `bind_java_type!` types and returns the requested JNI version.
+
+mod util;
+
+use std::{
+    ffi::c_void,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use jni::{
+    Env, JNIVersion, JavaVM, bind_java_type,
+    errors::Error,
+    jni_sig, jni_str,
+    objects::{JClass, JClassLoader, JObject, JThread},
+    refs::{LoaderContext, Reference as _},
+    sys::{self, jint},
+};
+use rusty_fork::rusty_fork_test;
+
+bind_java_type! {
+    rust_type = TestNativeStaticInit,
+    java_type = "com.example.TestNativeStaticInit",
+    methods {
+        static fn get_static_value() -> jint,
+    },
+    native_methods {
+        static fn native_initialize_static(jint) -> jint,
+    }
+}
+impl TestNativeStaticInitNativeInterface for TestNativeStaticInitAPI {
+    type Error = jni::errors::Error;
+
+    fn native_initialize_static<'local>(
+        _env: &mut Env<'local>,
+        _class: JClass<'local>,
+        value: jint,
+    ) -> Result<jint, Self::Error> {
+        Ok(value + 27)
+    }
+}
+
+static ON_LOAD_FAIL: AtomicBool = AtomicBool::new(false);
+static ON_LOAD_CALLS: AtomicUsize = AtomicUsize::new(0);
+static ON_UNLOAD_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[jni::on_load(version = JNIVersion::V1_8, natives = [TestNativeStaticInit])]
+fn on_load(vm: &JavaVM, env: &mut Env) -> Result<(), Error> {
+    assert_eq!(vm.get_raw(), JavaVM::singleton()?.get_raw());
+    assert!(env.version()? >= JNIVersion::V1_8);
+    ON_LOAD_CALLS.fetch_add(1, Ordering::SeqCst);
+    if ON_LOAD_FAIL.load(Ordering::SeqCst) {
+        return Err(Error::JniCall(jni::errors::JniError::Unknown));
+    }
+    Ok(())
+}
+
+#[jni::on_unload]
+fn on_unload(_vm: &JavaVM) {
+    ON_UNLOAD_CALLS.fetch_add(1, Ordering::SeqCst);
+}
+
+unsafe extern "system" {
+    fn JNI_OnLoad(vm: *mut sys::JavaVM, reserved: *mut c_void) -> jint;
+    fn JNI_OnUnload(vm: *mut sys::JavaVM, reserved: *mut c_void);
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_on_load_registers_natives() {
+        let out_dir = util::setup_test_output("test_on_load_registers_natives");
+
+        javac::Build::new()
+            .file("tests/java/com/example/TestNativeStaticInit.java")
+            .output_dir(&out_dir)
+            .compile();
+
+        let vm = util::jvm().get_raw();
+        util::attach_current_thread(|env| {
+            util::load_test_class(env, &out_dir, "TestNativeStaticInit")?;
+
+            // Errors are mapped via the (default) ThrowRuntimeExAndDefault policy
+            ON_LOAD_FAIL.store(true, Ordering::SeqCst);
+            let version = unsafe { JNI_OnLoad(vm, std::ptr::null_mut()) };
+            assert_eq!(version, 0);
+            assert!(env.exception_check());
+            env.exception_clear();
+
+            ON_LOAD_FAIL.store(false, Ordering::SeqCst);
+            let version = unsafe { JNI_OnLoad(vm, std::ptr::null_mut()) };
+            assert_eq!(version, jint::from(JNIVersion::V1_8));
+            assert!(!env.exception_check());
+            assert_eq!(ON_LOAD_CALLS.load(Ordering::SeqCst), 2);
+
+            // The static initializer calls the native method, which must have been registered
+            let static_value = TestNativeStaticInit::get_static_value(env)?;
+            assert_eq!(static_value, 42);
+
+            unsafe { JNI_OnUnload(vm, std::ptr::null_mut()) };
+            assert_eq!(ON_UNLOAD_CALLS.load(Ordering::SeqCst), 1);
+            Ok(())
+        })
+        .expect("test_on_load_registers_natives failed");
+    }
+
+    #[test]
+    fn test_on_load_ignores_context_class_loader() {
+        let out_dir = util::setup_test_output("test_on_load_ignores_context_class_loader");
+
+        javac::Build::new()
+            .file("tests/java/com/example/TestNativeStaticInit.java")
+            .output_dir(&out_dir)
+            .compile();
+
+        let vm = util::jvm().get_raw();
+        util::attach_current_thread(|env| {
+            util::load_test_class(env, &out_dir, "TestNativeStaticInit")?;
+
+            // A context class loader that can load a separate class with the same name
+            let path = env.new_string(out_dir.to_str().unwrap())?;
+            let file = env.new_object(
+                jni_str!("java/io/File"),
+                jni_sig!("(Ljava/lang/String;)V"),
+                &[(&path).into()],
+            )?;
+            let uri = env
+                .call_method(&file, jni_str!("toURI"), jni_sig!("()Ljava/net/URI;"), &[])?
+                .l()?;
+            let url = env
+                .call_method(&uri, jni_str!("toURL"), jni_sig!("()Ljava/net/URL;"), &[])?
+                .l()?;
+            let urls = env.new_object_array(1, jni_str!("java/net/URL"), &url)?;
+            let loader = env.new_object(
+                jni_str!("java/net/URLClassLoader"),
+                jni_sig!("([Ljava/net/URL;Ljava/lang/ClassLoader;)V"),
+                &[(&urls).into(), (&JObject::null()).into()],
+            )?;
+            let loader = env.cast_local::<JClassLoader>(loader)?;
+
+            let thread = JThread::current_thread(env)?;
+            let context_loader = thread.get_context_class_loader(env)?;
+            thread.set_context_class_loader(env, &loader)?;
+            let version = unsafe { JNI_OnLoad(vm, std::ptr::null_mut()) };
+            thread.set_context_class_loader(env, &context_loader)?;
+            assert_eq!(version, jint::from(JNIVersion::V1_8));
+
+            // The natives must be registered with the class that `FindClass` resolves (which
+            // calls the native method from its static initializer)
+            let class = env.find_class(jni_str!("com/example/TestNativeStaticInit"))?;
+            let bound_class = TestNativeStaticInit::lookup_class(env, &LoaderContext::None)?;
+            assert!(env.is_same_object(&class, &*bound_class)?);
+            let static_value = TestNativeStaticInit::get_static_value(env)?;
+            assert_eq!(static_value, 42);
+            Ok(())
+        })
+        .expect("test_on_load_ignores_context_class_loader failed");
+    }
+}
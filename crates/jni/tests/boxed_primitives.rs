@@ -0,0 +1,105 @@
+#![cfg(feature = "invocation")]
+
+use jni::{
+    errors::Error,
+    jni_sig, jni_str,
+    objects::{
+        BoxedPrimitive, JBoolean, JByte, JCharacter, JDouble, JFloat, JInteger, JLong, JNumber,
+        JObject, JShort, JString,
+    },
+};
+
+mod util;
+use util::attach_current_thread;
+
+#[test]
+fn boxed_primitive_round_trips() {
+    attach_current_thread(|env| {
+        let value = JByte::try_from_primitive(env, -5)?;
+        assert_eq!(value.try_to_primitive(env)?, -5);
+        let value = JShort::try_from_primitive(env, 1000)?;
+        assert_eq!(value.try_to_primitive(env)?, 1000);
+        let value = JInteger::try_from_primitive(env, i32::MIN)?;
+        assert_eq!(value.try_to_primitive(env)?, i32::MIN);
+        let value = JLong::try_from_primitive(env, i64::MAX)?;
+        assert_eq!(value.try_to_primitive(env)?, i64::MAX);
+        let value = JFloat::try_from_primitive(env, 1.5)?;
+        assert_eq!(value.try_to_primitive(env)?, 1.5);
+        let value = JDouble::try_from_primitive(env, -0.25)?;
+        assert_eq!(value.try_to_primitive(env)?, -0.25);
+        let value = JBoolean::try_from_primitive(env, true)?;
+        assert!(value.try_to_primitive(env)?);
+        let value = JCharacter::try_from_primitive(env, 'x' as u16)?;
+        assert_eq!(value.try_to_char(env)?, Some('x'));
+        let value = JCharacter::try_from_primitive(env, 0xd800)?;
+        assert_eq!(value.try_to_char(env)?, None);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn boxed_primitive_java_values() {
+    attach_current_thread(|env| {
+        let value = JInteger::value_of(env, 42)?;
+        let string = env
+            .call_method(
+                &value,
+                jni_str!("toString"),
+                jni_sig!("()Ljava/lang/String;"),
+                &[],
+            )?
+            .l()?;
+        let string = env.cast_local::<JString>(string)?;
+        assert_eq!(string.try_to_string(env)?, "42");
+
+        // Small values are cached by `valueOf`
+        let other = JInteger::value_of(env, 42)?;
+        assert!(env.is_same_object(&value, &other)?);
+
+        let string = JString::from_str(env, "-7")?;
+        let parsed = env
+            .call_static_method(
+                jni_str!("java/lang/Long"),
+                jni_str!("valueOf"),
+                jni_sig!("(Ljava/lang/String;)Ljava/lang/Long;"),
+                &[(&string).into()],
+            )?
+            .l()?;
+        let parsed = env.cast_local::<JLong>(parsed)?;
+        assert_eq!(parsed.long_value(env)?, -7);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn number_conversions() {
+    attach_current_thread(|env| {
+        let value = JDouble::value_of(env, 2.75)?;
+        let number: JNumber = value.into();
+        assert_eq!(number.int_value(env)?, 2);
+        assert_eq!(number.long_value(env)?, 2);
+        assert_eq!(number.float_value(env)?, 2.75);
+
+        let value = JInteger::value_of(env, 300)?;
+        let number = value.as_number();
+        assert_eq!(number.byte_value(env)?, 300_i32 as i8);
+        assert_eq!(number.double_value(env)?, 300.0);
+
+        let value = JLong::value_of(env, 1)?;
+        assert!(env.cast_local::<JNumber>(value).is_ok());
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn null_boxed_primitive() {
+    attach_current_thread(|env| {
+        let null = env.cast_local::<JInteger>(JObject::null())?;
+        assert!(matches!(null.try_to_primitive(env), Err(Error::NullPtr(_))));
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+use jni::{
+    errors::{Error, Exception, ThrowRuntimeExAndDefault, ToException},
+    objects::{Global, JCompletableFuture, JObject, JString},
+};
+
+mod util;
+use util::{attach_current_thread, jvm};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor for driving a future on the current thread
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+struct TestError(&'static str);
+
+impl ToException for TestError {
+    fn to_exception(&self) -> Exception {
+        Exception {
+            class: "java/lang/IllegalStateException".to_string(),
+            msg: self.0.to_string(),
+        }
+    }
+}
+
+fn new_java_future() -> Global<JCompletableFuture<'static>> {
+    attach_current_thread(|env| {
+        let future = JCompletableFuture::new(env)?;
+        env.new_global_ref(future)
+    })
+    .unwrap()
+}
+
+#[test]
+fn rust_future_completes_java_future() {
+    let java_future = new_java_future();
+    let task = attach_current_thread(|env| {
+        let value = JString::from_str(env, "hello")?;
+        let value = env.new_global_ref(value)?;
+        java_future.complete_with(env, async move {
+            thread::sleep(Duration::from_millis(10));
+            Ok::<_, TestError>(value)
+        })
+    })
+    .unwrap();
+
+    // Drive the Rust future on a thread that isn't attached to the JVM
+    thread::spawn(move || block_on(task)).join().unwrap();
+
+    attach_current_thread(|env| {
+        assert!(java_future.is_done(env)?);
+        let value = java_future.join(env)?;
+        let value = env.cast_local::<JString>(value)?;
+        assert_eq!(value.try_to_string(env)?, "hello");
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn rust_future_error_completes_java_future_exceptionally() {
+    let java_future = new_java_future();
+    let task = attach_current_thread(|env| {
+        java_future.complete_with(env, async {
+            Err::<Global<JObject<'static>>, _>(TestError("failed"))
+        })
+    })
+    .unwrap();
+    thread::spawn(move || block_on(task)).join().unwrap();
+
+    let result = attach_current_thread(|env| {
+        assert!(java_future.is_completed_exceptionally(env)?);
+        java_future.to_future(env)
+    })
+    .map(block_on)
+    .unwrap();
+    match result {
+        Err(Error::CaughtJavaException { name, msg, .. }) => {
+            assert_eq!(name, "java.lang.IllegalStateException");
+            assert_eq!(msg, "failed");
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn rust_future_panic_completes_java_future_exceptionally() {
+    let java_future = new_java_future();
+    let task = attach_current_thread(|env| {
+        java_future.complete_with_policy::<ThrowRuntimeExAndDefault, _, _, _>(env, async {
+            if true {
+                panic!("future panicked");
+            }
+            Ok::<Global<JObject<'static>>, Error>(Global::null())
+        })
+    })
+    .unwrap();
+    thread::spawn(move || block_on(task)).join().unwrap();
+
+    let result = attach_current_thread(|env| java_future.to_future(env))
+        .map(block_on)
+        .unwrap();
+    match result {
+        Err(Error::CaughtJavaException { name, msg, .. }) => {
+            assert_eq!(name, "java.lang.RuntimeException");
+            assert_eq!(msg, "Rust panic: future panicked");
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn java_future_wakes_rust_future() {
+    let java_future = new_java_future();
+    let rust_future = attach_current_thread(|env| java_future.to_future(env)).unwrap();
+
+    let completer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        attach_current_thread(|env| {
+            let value = JString::from_str(env, "from java")?;
+            assert!(java_future.complete(env, &value)?);
+            Ok(())
+        })
+        .unwrap();
+    });
+
+    let value = block_on(rust_future).unwrap();
+    completer.join().unwrap();
+
+    attach_current_thread(|env| {
+        let value = env.new_local_ref(&value)?;
+        let value = env.cast_local::<JString>(value)?;
+        assert_eq!(value.try_to_string(env)?, "from java");
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn completed_and_cancelled_java_futures() {
+    let (completed, cancelled) = attach_current_thread(|env| {
+        let value = JString::from_str(env, "done")?;
+        let completed = JCompletableFuture::completed_future(env, &value)?;
+
+        let cancelled = JCompletableFuture::new(env)?;
+        let rust_future = cancelled.to_future(env)?;
+        assert!(cancelled.cancel(env, true)?);
+        assert!(cancelled.is_cancelled(env)?);
+
+        Ok((completed.to_future(env)?, rust_future))
+    })
+    .unwrap();
+
+    assert!(block_on(completed).is_ok());
+    match block_on(cancelled) {
+        Err(Error::CaughtJavaException { name, .. }) => {
+            assert_eq!(name, "java.util.concurrent.CancellationException");
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn completion_future_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+    let java_future = new_java_future();
+    let rust_future = jvm()
+        .attach_current_thread(|env| java_future.to_future(env))
+        .unwrap();
+    assert_send(&rust_future);
+}
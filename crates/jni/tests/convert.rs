@@ -0,0 +1,140 @@
+#![cfg(feature = "invocation")]
+
+use std::collections::{HashMap, HashSet};
+
+use jni::{
+    JValueOwned,
+    convert::{FromJava, IntoJava},
+    errors::Error,
+    jni_sig, jni_str,
+    objects::{JIntArray, JInteger, JObject, JObjectArray, JString},
+};
+
+mod util;
+use util::attach_current_thread;
+
+#[test]
+fn convert_primitives_and_strings() {
+    attach_current_thread(|env| {
+        let value: i32 = JValueOwned::Int(7).into_rust(env)?;
+        assert_eq!(value, 7);
+
+        // Boxed primitives are unboxed
+        let boxed = JInteger::value_of(env, 42)?;
+        let value: i32 = JValueOwned::Object(boxed.into()).into_rust(env)?;
+        assert_eq!(value, 42);
+
+        let result = i64::from_java(env, JValueOwned::Int(1));
+        assert!(matches!(result, Err(Error::WrongJValueType("long", "int"))));
+
+        let string = "héllo".into_java(env)?;
+        let value: String = string.into_rust(env)?;
+        assert_eq!(value, "héllo");
+
+        let four = JString::from_str(env, "four")?;
+        let length: i32 = env
+            .call_method(&four, jni_str!("length"), jni_sig!("()I"), &[])?
+            .into_rust(env)?;
+        assert_eq!(length, 4);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn convert_null() {
+    attach_current_thread(|env| {
+        let value: Option<String> = JValueOwned::Object(JObject::null()).into_rust(env)?;
+        assert_eq!(value, None);
+
+        let value: Option<String> = "some".into_java(env)?.into_rust(env)?;
+        assert_eq!(value.as_deref(), Some("some"));
+
+        let result = String::from_java(env, JValueOwned::Object(JObject::null()));
+        assert!(matches!(result, Err(Error::NullPtr(_))));
+
+        let null = None::<String>.into_java(env)?;
+        assert!(null.is_null());
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn convert_arrays() {
+    attach_current_thread(|env| {
+        let array: JIntArray = env.new_int_array(3)?;
+        array.set_region(env, 0, &[1, 2, 3])?;
+        let value: Vec<i32> = JValueOwned::Object(array.into()).into_rust(env)?;
+        assert_eq!(value, [1, 2, 3]);
+
+        let bytes = vec![1_u8, 2, 255].into_java(env)?;
+        let value: Vec<u8> = bytes.into_rust(env)?;
+        assert_eq!(value, [1, 2, 255]);
+
+        let array: JObjectArray =
+            env.new_object_array(2, jni_str!("java/lang/String"), JObject::null())?;
+        let first = JString::from_str(env, "a")?;
+        array.set_element(env, 0, &first)?;
+        let value: Vec<Option<String>> = JValueOwned::Object(array.into()).into_rust(env)?;
+        assert_eq!(value, [Some("a".to_owned()), None]);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn convert_collections() {
+    attach_current_thread(|env| {
+        let list = vec!["x", "y", "z"].into_java(env)?;
+        let size = env
+            .call_method(list.borrow().l()?, jni_str!("size"), jni_sig!("()I"), &[])?
+            .i()?;
+        assert_eq!(size, 3);
+        let value: Vec<String> = list.into_rust(env)?;
+        assert_eq!(value, ["x", "y", "z"]);
+
+        // Primitive elements are boxed
+        let list = vec![1_i64, 2].into_java(env)?;
+        let value: Vec<i64> = list.into_rust(env)?;
+        assert_eq!(value, [1, 2]);
+
+        let set = HashSet::from([1, 2, 3]).into_java(env)?;
+        let value: HashSet<i32> = set.into_rust(env)?;
+        assert_eq!(value, HashSet::from([1, 2, 3]));
+
+        let map = HashMap::from([("one".to_owned(), 1.5_f64), ("two".to_owned(), 2.5)]);
+        let value: HashMap<String, f64> = map.clone().into_java(env)?.into_rust(env)?;
+        assert_eq!(value, map);
+
+        let map = HashMap::from([("k", vec![true, false])]).into_java(env)?;
+        let value: HashMap<String, Vec<bool>> = map.into_rust(env)?;
+        assert_eq!(value, HashMap::from([("k".to_owned(), vec![true, false])]));
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn convert_large_collection() {
+    attach_current_thread(|env| {
+        // Exceeds the default local reference capacity, unless each element gets its own frame
+        let strings: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+        let list = strings.clone().into_java(env)?;
+        let value: Vec<String> = list.into_rust(env)?;
+        assert_eq!(value, strings);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn convert_wrong_type() {
+    attach_current_thread(|env| {
+        let string = "not a list".into_java(env)?;
+        let result = Vec::<i32>::from_java(env, string);
+        assert!(matches!(result, Err(Error::WrongObjectType)));
+        Ok(())
+    })
+    .unwrap();
+}
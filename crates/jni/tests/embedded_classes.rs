@@ -0,0 +1,71 @@
+//! Checks that the prebuilt classes under `java/classes`, which are embedded in the crate with
+//! `include_bytes!`, are up to date with their sources under `java/src`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Each embedded Java source, with the Java release that it's compiled for
+///
+/// Sources are compiled in order, with the previously compiled classes on the class path.
+const SOURCES: &[(&str, &str)] = &[
+    ("io/github/jni_rs/CompletionCallback.java", "8"),
+    ("io/github/jni_rs/RustCleanup.java", "9"),
+    ("io/github/jni_rs/RustInvocationHandler.java", "9"),
+    ("io/github/jni_rs/RustFunction.java", "9"),
+];
+
+/// Returns the paths of all `.class` files under `dir`, relative to `dir`
+fn class_files(dir: &Path) -> Vec<PathBuf> {
+    fn visit(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(root, &path, files);
+            } else if path.extension().is_some_and(|ext| ext == "class") {
+                files.push(path.strip_prefix(root).unwrap().to_owned());
+            }
+        }
+    }
+    let mut files = Vec::new();
+    visit(dir, dir, &mut files);
+    files.sort();
+    files
+}
+
+#[test]
+fn embedded_classes_match_sources() {
+    let java_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("java");
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("embedded_classes");
+    let _ = fs::remove_dir_all(&out_dir);
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for (source, release) in SOURCES {
+        javac::Build::new()
+            .file(java_dir.join("src").join(source))
+            .release(*release)
+            .classpath(&out_dir)
+            .output_dir(&out_dir)
+            .cargo_metadata(false)
+            .compile();
+    }
+
+    let committed_dir = java_dir.join("classes");
+    let committed = class_files(&committed_dir);
+    assert_eq!(
+        committed,
+        class_files(&out_dir),
+        "java/classes doesn't contain the same classes as the compiled java/src"
+    );
+    for class in &committed {
+        assert!(
+            fs::read(committed_dir.join(class)).unwrap() == fs::read(out_dir.join(class)).unwrap(),
+            "java/classes/{} is out of date with java/src. Recompile it with: \
+             javac --release <release> -d java/classes java/src/<source> \
+             (see SOURCES in {})",
+            class.display(),
+            file!(),
+        );
+    }
+}
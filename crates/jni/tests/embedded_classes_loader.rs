@@ -0,0 +1,98 @@
+#![cfg(feature = "invocation")]
+//! Tests that the classes that are embedded in the crate can be defined twice in the same JVM
+//! (such as by two libraries that both use `jni`), since they're defined in a private class
+//! loader.
+
+use std::{
+    fs,
+    future::Future,
+    path::Path,
+    pin::pin,
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use jni::{
+    handle::RustHandle,
+    jni_sig, jni_str,
+    objects::{JClassLoader, JCompletableFuture, JFunction, JObject, JString},
+    proxy::RustProxy,
+    strings::JNIString,
+};
+
+mod util;
+use util::attach_current_thread;
+
+/// Polls a future on the current thread until it's ready
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::yield_now(),
+        }
+    }
+}
+
+#[test]
+fn embedded_classes_defined_twice() {
+    attach_current_thread(|env| {
+        // Define the embedded classes in the system class loader first, in the same way as
+        // another library that embeds the same classes might
+        let system_loader = JClassLoader::get_system_class_loader(env)?;
+        let classes_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("java/classes");
+        let package_dir = classes_dir.join("io/github/jni_rs");
+        let mut classes: Vec<String> = fs::read_dir(&package_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter_map(|file| file.strip_suffix(".class").map(str::to_owned))
+            .collect();
+        // Nested classes must be defined after their `RustFunction` superclass
+        classes.sort_by_key(|class| class.contains('$'));
+        for class in &classes {
+            let bytes = fs::read(package_dir.join(format!("{class}.class"))).unwrap();
+            let name = JNIString::new(format!("io/github/jni_rs/{class}"));
+            env.define_class(Some(&name), &system_loader, &bytes)?;
+        }
+
+        // `RustFunction` and `RustCleanup`
+        let to_upper = JFunction::from_fn(env, |env, arg| {
+            let arg = env.cast_local::<JString>(arg)?;
+            let upper = arg.try_to_string(env)?.to_uppercase();
+            Ok(JString::from_str(env, upper)?.into())
+        })?;
+        let hello = JString::from_str(env, "hello")?;
+        let result = to_upper.apply(env, &hello)?;
+        let result = env.cast_local::<JString>(result)?;
+        assert_eq!(result.try_to_string(env)?, "HELLO");
+
+        let class = env.get_object_class(&to_upper)?;
+        let loader = class.get_class_loader(env)?;
+        assert!(!env.is_same_object(&loader, &system_loader)?);
+
+        // `RustInvocationHandler`
+        let runnable = RustProxy::from_fn(|_env, _invocation| Ok(JObject::null()))
+            .interface(jni_str!("java.lang.Runnable"))
+            .build(env)?;
+        env.call_method(&runnable, jni_str!("run"), jni_sig!("()V"), &[])?;
+
+        // `RustCleanup`
+        let owner = env.new_object(jni_str!("java/lang/Object"), jni_sig!("()V"), &[])?;
+        let handle = RustHandle::register(env, &owner, 42)?;
+        let value: i32 = unsafe { RustHandle::<i32>::from_raw(handle)?.take()? };
+        assert_eq!(value, 42);
+
+        // `CompletionCallback`
+        let java_future = JCompletableFuture::new(env)?;
+        let value = JString::from_str(env, "done")?;
+        assert!(java_future.complete(env, &value)?);
+        let rust_future = java_future.to_future(env)?;
+        let value = block_on(rust_future)?;
+        let value = env.new_local_ref(&value)?;
+        let value = env.cast_local::<JString>(value)?;
+        assert_eq!(value.try_to_string(env)?, "done");
+        Ok(())
+    })
+    .unwrap();
+}
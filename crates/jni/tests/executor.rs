@@ -0,0 +1,112 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::Arc,
+};
+
+use jni::{
+    errors::Error,
+    executor::{AttachedThreadPool, AttachedThreadPoolBuilder},
+    jni_sig, jni_str,
+    objects::{JClassLoader, JThread},
+};
+
+mod util;
+use util::{attach_current_thread, jvm};
+
+#[test]
+fn attached_thread_pool_runs_jobs() {
+    let pool = AttachedThreadPool::new(jvm(), 4).unwrap();
+    assert_eq!(pool.threads(), 4);
+
+    let handles: Vec<_> = (0..100)
+        .map(|i| {
+            pool.spawn(move |env| -> jni::errors::Result<String> {
+                let s = env.new_string(format!("job {i}"))?;
+                s.try_to_string(env)
+            })
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(handle.join().unwrap(), format!("job {i}"));
+    }
+}
+
+#[test]
+fn attached_thread_pool_configuration() {
+    let loader = attach_current_thread(|env| {
+        let urls =
+            env.new_object_array(0, jni_str!("java/net/URL"), jni::objects::JObject::null())?;
+        let loader = env.new_object(
+            jni_str!("java/net/URLClassLoader"),
+            jni_sig!("([Ljava/net/URL;)V"),
+            &[(&urls).into()],
+        )?;
+        let loader = env.cast_local::<JClassLoader>(loader)?;
+        Ok::<_, Error>((env.new_global_ref(&loader)?, env.new_global_ref(&loader)?))
+    })
+    .unwrap();
+    let (pool_loader, loader) = (loader.0, Arc::new(loader.1));
+
+    let pool = AttachedThreadPoolBuilder::new(2)
+        .thread_name("test-pool")
+        .daemon(true)
+        .context_class_loader(pool_loader)
+        .build(jvm())
+        .unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let loader = loader.clone();
+            pool.spawn(move |env| -> jni::errors::Result<(String, bool, bool)> {
+                let thread = JThread::current_thread(env)?;
+                let name = thread.get_name(env)?.try_to_string(env)?;
+                let daemon = env
+                    .call_method(&thread, jni_str!("isDaemon"), jni_sig!("()Z"), &[])?
+                    .z()?;
+                let context_loader = thread.get_context_class_loader(env)?;
+                let same_loader = env.is_same_object(&context_loader, &*loader)?;
+                Ok((name, daemon, same_loader))
+            })
+        })
+        .collect();
+    for handle in handles {
+        let (name, daemon, same_loader) = handle.join().unwrap();
+        assert!(
+            name == "test-pool-0" || name == "test-pool-1",
+            "unexpected thread name {name}"
+        );
+        assert!(daemon);
+        assert!(same_loader);
+    }
+}
+
+#[test]
+fn attached_thread_pool_exceptions_and_panics() {
+    let pool = AttachedThreadPool::new(jvm(), 1).unwrap();
+
+    // A pending exception is caught and returned as an error
+    let handle = pool.spawn(|env| -> jni::errors::Result<()> {
+        env.throw_new(
+            jni_str!("java/lang/IllegalStateException"),
+            jni_str!("oops"),
+        )?;
+        Ok(())
+    });
+    assert!(matches!(
+        handle.join(),
+        Err(Error::CaughtJavaException { ref name, .. }) if name == "java.lang.IllegalStateException"
+    ));
+
+    // A panic is resumed when joining, and the worker keeps running
+    let handle = pool.spawn(|_env| -> jni::errors::Result<()> { panic!("job panic") });
+    let panic = catch_unwind(AssertUnwindSafe(|| handle.join())).unwrap_err();
+    assert_eq!(panic.downcast_ref::<&str>(), Some(&"job panic"));
+
+    let handle = pool.spawn(|env| -> jni::errors::Result<bool> { Ok(env.exception_check()) });
+    while !handle.is_finished() {
+        std::thread::yield_now();
+    }
+    assert!(!handle.join().unwrap());
+}
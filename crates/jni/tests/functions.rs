@@ -0,0 +1,189 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use jni::{
+    JValue,
+    errors::Error,
+    jni_sig, jni_str,
+    objects::{
+        JBiFunction, JConsumer, JFunction, JObject, JPredicate, JRunnable, JString, JSupplier,
+    },
+};
+
+mod util;
+use util::attach_current_thread;
+
+#[test]
+fn function_and_bi_function() {
+    attach_current_thread(|env| {
+        let to_upper = JFunction::from_fn(env, |env, arg| {
+            let arg = env.cast_local::<JString>(arg)?;
+            let upper = arg.try_to_string(env)?.to_uppercase();
+            Ok(JString::from_str(env, upper)?.into())
+        })?;
+        let concat = JBiFunction::from_fn(env, |env, a, b| {
+            let a = env.cast_local::<JString>(a)?;
+            let b = env.cast_local::<JString>(b)?;
+            let concat = a.try_to_string(env)? + &b.try_to_string(env)?;
+            Ok(JString::from_str(env, concat)?.into())
+        })?;
+
+        let hello = JString::from_str(env, "hello")?;
+        let world = JString::from_str(env, " world")?;
+        let result = to_upper.apply(env, &hello)?;
+        let result = env.cast_local::<JString>(result)?;
+        assert_eq!(result.try_to_string(env)?, "HELLO");
+
+        let result = concat.apply(env, &hello, &world)?;
+        let result = env.cast_local::<JString>(result)?;
+        assert_eq!(result.try_to_string(env)?, "hello world");
+
+        // Default methods are implemented by Java
+        let composed = env
+            .call_method(
+                &to_upper,
+                jni_str!("andThen"),
+                jni_sig!("(Ljava/util/function/Function;)Ljava/util/function/Function;"),
+                &[JValue::Object(&to_upper)],
+            )?
+            .l()?;
+        let composed = env.cast_local::<JFunction>(composed)?;
+        let result = composed.apply(env, &hello)?;
+        let result = env.cast_local::<JString>(result)?;
+        assert_eq!(result.try_to_string(env)?, "HELLO");
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn supplier_consumer_and_predicate() {
+    let consumed = Arc::new(AtomicUsize::new(0));
+    let consumer_count = consumed.clone();
+
+    attach_current_thread(|env| {
+        let supplier =
+            JSupplier::from_fn(env, |env| Ok(JString::from_str(env, "supplied")?.into()))?;
+        let consumer = JConsumer::from_fn(env, move |_env, arg| {
+            assert!(!arg.is_null());
+            consumer_count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        })?;
+        let is_empty = JPredicate::from_fn(env, |env, arg| {
+            let arg = env.cast_local::<JString>(arg)?;
+            Ok(arg.try_to_string(env)?.is_empty())
+        })?;
+
+        let value = supplier.get(env)?;
+        consumer.accept(env, &value)?;
+        consumer.accept(env, &value)?;
+        assert_eq!(consumed.load(Ordering::SeqCst), 2);
+
+        let empty = JString::from_str(env, "")?;
+        assert!(!is_empty.test(env, &value)?);
+        assert!(is_empty.test(env, &empty)?);
+
+        let is_not_empty = env
+            .call_method(
+                &is_empty,
+                jni_str!("negate"),
+                jni_sig!("()Ljava/util/function/Predicate;"),
+                &[],
+            )?
+            .l()?;
+        let is_not_empty = env.cast_local::<JPredicate>(is_not_empty)?;
+        assert!(is_not_empty.test(env, &value)?);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn runnable_on_java_thread() {
+    let ran = Arc::new(AtomicBool::new(false));
+    let runnable_ran = ran.clone();
+
+    attach_current_thread(|env| {
+        let runnable = JRunnable::from_fn(env, move |_env| {
+            runnable_ran.store(true, Ordering::SeqCst);
+            Ok(())
+        })?;
+        let thread = env.new_object(
+            jni_str!("java/lang/Thread"),
+            jni_sig!("(Ljava/lang/Runnable;)V"),
+            &[JValue::Object(&runnable)],
+        )?;
+        env.call_method(&thread, jni_str!("start"), jni_sig!("()V"), &[])?;
+        env.call_method(&thread, jni_str!("join"), jni_sig!("()V"), &[])?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert!(ran.load(Ordering::SeqCst));
+}
+
+#[test]
+fn function_errors_throw_exceptions() {
+    attach_current_thread(|env| {
+        let function = JFunction::from_fn(env, |_env, _arg| Err(Error::WrongObjectType))?;
+
+        let result = function.apply(env, JObject::null());
+        assert!(matches!(result, Err(Error::JavaException)));
+        let exception = env
+            .exception_occurred()
+            .expect("exception should be pending");
+        env.exception_clear();
+        let class = env.get_object_class(&exception)?;
+        assert_eq!(
+            class.get_name(env)?.to_string(),
+            "java.lang.RuntimeException"
+        );
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn closure_dropped_after_collection() {
+    struct DropFlag(Arc<AtomicBool>);
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+    let dropped = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(dropped.clone());
+
+    attach_current_thread(|env| {
+        env.with_local_frame(4, |env| -> jni::errors::Result<()> {
+            let supplier = JSupplier::from_fn(env, move |_env| {
+                let _flag = &flag;
+                Ok(JObject::null())
+            })?;
+            assert!(supplier.get(env)?.is_null());
+            Ok(())
+        })?;
+
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while !dropped.load(Ordering::SeqCst) {
+            assert!(Instant::now() < deadline, "closure was never dropped");
+            env.call_static_method(
+                jni_str!("java/lang/System"),
+                jni_str!("gc"),
+                jni_sig!("()V"),
+                &[],
+            )?;
+            thread::sleep(Duration::from_millis(20));
+        }
+        Ok(())
+    })
+    .unwrap();
+}
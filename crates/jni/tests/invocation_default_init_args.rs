@@ -0,0 +1,48 @@
+// This is a separate test program because it has to query the JVM library before a JVM is
+// created, and then create a JVM with the probed version.
+
+#![cfg(feature = "invocation")]
+
+use jni::{
+    JNIVersion, JavaVM, JvmLocator,
+    errors::{Error, JniError, StartJvmError},
+};
+
+#[test]
+fn invocation_default_init_args() {
+    let version = JavaVM::highest_supported_version().unwrap_or_else(|e| panic!("{e}"));
+    assert!(
+        version >= JNIVersion::V1_8,
+        "unexpected version {version:?}"
+    );
+
+    let jdk = JvmLocator::new().locate().unwrap_or_else(|e| panic!("{e}"));
+    assert_eq!(jdk.highest_supported_version().unwrap(), version);
+
+    let defaults = JavaVM::default_init_args(version).unwrap_or_else(|e| panic!("{e}"));
+    assert_eq!(defaults.version(), version);
+
+    // JNI 1.1 isn't supported
+    assert!(matches!(
+        JavaVM::default_init_args(JNIVersion::V1_1),
+        Err(StartJvmError::Create(Error::JniCall(
+            JniError::WrongVersion
+        )))
+    ));
+
+    // No JVM has been created yet
+    assert!(JavaVM::get_created().unwrap().is_none());
+
+    let jvm_args = defaults
+        .to_builder()
+        .check_jni(true)
+        .build()
+        .unwrap_or_else(|e| panic!("{:#?}", e));
+    let jvm = JavaVM::new(jvm_args).unwrap_or_else(|e| panic!("{:#?}", e));
+
+    jvm.attach_current_thread(|env| -> jni::errors::Result<()> {
+        assert!(env.version()? >= version);
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,35 @@
+// This is a separate test program because it needs to query for a JVM before one is created.
+
+#![cfg(feature = "invocation")]
+
+use jni::{InitArgsBuilder, JavaVM, errors::Error};
+
+#[test]
+fn invocation_get_created() {
+    assert!(JavaVM::get_created().unwrap().is_none());
+    assert!(matches!(
+        JavaVM::singleton(),
+        Err(Error::UninitializedJavaVM)
+    ));
+
+    let jvm_args = InitArgsBuilder::new()
+        .version(jni::JNIVersion::V1_8)
+        .option("-Xcheck:jni")
+        .build()
+        .unwrap_or_else(|e| panic!("{:#?}", e));
+    let jvm = JavaVM::new(jvm_args).unwrap_or_else(|e| panic!("{:#?}", e));
+
+    let created = JavaVM::get_created()
+        .unwrap()
+        .expect("Failed to get created JavaVM");
+    assert_eq!(created.get_raw(), jvm.get_raw());
+    assert_eq!(JavaVM::singleton().unwrap().get_raw(), jvm.get_raw());
+
+    created
+        .attach_current_thread(|env| -> jni::errors::Result<()> {
+            let s = env.new_string("hello")?;
+            assert_eq!(s.try_to_string(env)?, "hello");
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,63 @@
+// This is a separate test program because it has to start a JVM with specific options, and each
+// test runs in a separate process since it creates its own JVM.
+
+#![cfg(feature = "invocation")]
+
+use std::sync::{Arc, Mutex};
+
+use jni::{InitArgsBuilder, JavaVM, jni_sig, jni_str, objects::JValue};
+use rusty_fork::rusty_fork_test;
+
+rusty_fork_test! {
+    #[test]
+    fn invocation_vfprintf_hook() {
+        let output = Arc::new(Mutex::new(String::new()));
+        let jvm_args = InitArgsBuilder::new()
+            .version(jni::JNIVersion::V1_8)
+            .option("-XX:+PrintFlagsFinal")
+            .default_hooks(false)
+            .vfprintf_hook({
+                let output = output.clone();
+                move |s| output.lock().unwrap().push_str(s)
+            })
+            .build()
+            .unwrap_or_else(|e| panic!("{:#?}", e));
+
+        let _jvm = JavaVM::new(jvm_args).unwrap_or_else(|e| panic!("{:#?}", e));
+
+        // The flags are formatted by the JVM as "<type> <name> = <value> ..." lines
+        let output = output.lock().unwrap();
+        assert!(
+            output.lines().any(|line| line.contains(" MaxHeapSize ")),
+            "unexpected JVM output: {output}"
+        );
+    }
+
+    #[test]
+    fn invocation_exit_hook() {
+        let jvm_args = InitArgsBuilder::new()
+            .version(jni::JNIVersion::V1_8)
+            .exit_hook(|code| {
+                // Exit successfully ourselves, otherwise the JVM would exit with code 42, which
+                // would fail the test
+                std::process::exit(if code == 42 { 0 } else { 1 });
+            })
+            .build()
+            .unwrap_or_else(|e| panic!("{:#?}", e));
+
+        let jvm = JavaVM::new(jvm_args).unwrap_or_else(|e| panic!("{:#?}", e));
+
+        jvm.attach_current_thread(|env| -> jni::errors::Result<()> {
+            env.call_static_method(
+                jni_str!("java/lang/System"),
+                jni_str!("exit"),
+                jni_sig!("(I)V"),
+                &[JValue::Int(42)],
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        unreachable!("System.exit returned");
+    }
+}
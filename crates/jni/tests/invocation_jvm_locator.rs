@@ -0,0 +1,138 @@
+// This is a separate test program because it starts a JVM via a `JvmLocator`.
+
+#![cfg(feature = "invocation")]
+
+use std::path::Path;
+
+use jni::{InitArgsBuilder, JavaVM, JvmLocator, errors::StartJvmError};
+
+mod util;
+
+#[cfg(windows)]
+const LIBJVM: &str = "bin/server/jvm.dll";
+#[cfg(target_os = "macos")]
+const LIBJVM: &str = "lib/server/libjvm.dylib";
+#[cfg(not(any(windows, target_os = "macos")))]
+const LIBJVM: &str = "lib/server/libjvm.so";
+
+fn fake_jdk(root: &Path, name: &str, release: &str, with_libjvm: bool) {
+    let home = root.join(name);
+    std::fs::create_dir_all(&home).unwrap();
+    std::fs::write(home.join("release"), release).unwrap();
+    if with_libjvm {
+        let libjvm = home.join(LIBJVM);
+        std::fs::create_dir_all(libjvm.parent().unwrap()).unwrap();
+        std::fs::write(libjvm, "").unwrap();
+    }
+}
+
+#[test]
+fn jvm_locator_selection() {
+    let root = util::setup_test_output("jvm_locator_selection");
+    let arch = std::env::consts::ARCH;
+    fake_jdk(
+        &root,
+        "jdk-11",
+        &format!("JAVA_VERSION=\"11.0.2\"\nIMPLEMENTOR=\"Vendor A\"\nOS_ARCH=\"{arch}\"\n"),
+        true,
+    );
+    fake_jdk(
+        &root,
+        "jdk-17",
+        &format!("JAVA_VERSION=\"17.0.1\"\nIMPLEMENTOR=\"Vendor B\"\nOS_ARCH=\"{arch}\"\n"),
+        true,
+    );
+    fake_jdk(
+        &root,
+        "jdk-21-nolib",
+        &format!("JAVA_VERSION=\"21\"\nIMPLEMENTOR=\"Vendor A\"\nOS_ARCH=\"{arch}\"\n"),
+        false,
+    );
+    fake_jdk(
+        &root,
+        "jdk-25-other-arch",
+        "JAVA_VERSION=\"25\"\nIMPLEMENTOR=\"Vendor A\"\nOS_ARCH=\"other\"\n",
+        true,
+    );
+    std::fs::create_dir_all(root.join("not-a-jdk")).unwrap();
+
+    let locator = JvmLocator::new()
+        .java_home(false)
+        .path(false)
+        .system_roots(false)
+        .root(&root);
+
+    let candidates = locator.candidates();
+    let names: Vec<_> = candidates
+        .iter()
+        .map(|jdk| jdk.home().file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        ["jdk-11", "jdk-17", "jdk-21-nolib", "jdk-25-other-arch"]
+    );
+    assert_eq!(candidates[1].version().unwrap().major, 17);
+    assert_eq!(candidates[1].vendor(), Some("Vendor B"));
+
+    // The newest matching JDK is selected
+    let jdk = locator.locate().unwrap();
+    assert_eq!(jdk.home(), root.join("jdk-17"));
+    assert_eq!(jdk.libjvm_path().unwrap(), root.join("jdk-17").join(LIBJVM));
+
+    let jdk = locator.clone().version("<17").locate().unwrap();
+    assert_eq!(jdk.home(), root.join("jdk-11"));
+
+    let jdk = locator.clone().vendor("vendor a").locate().unwrap();
+    assert_eq!(jdk.home(), root.join("jdk-11"));
+
+    let jdk = locator.clone().arch(None).locate().unwrap();
+    assert_eq!(jdk.home(), root.join("jdk-25-other-arch"));
+
+    let err = locator.clone().version(">=18").locate().unwrap_err();
+    assert!(
+        matches!(err, StartJvmError::NoMatchingJdk { ref rejected, .. } if rejected.len() == 4)
+    );
+    let message = err.to_string();
+    assert!(
+        message.contains("version 17.0.1 doesn't match >=18"),
+        "{message}"
+    );
+    assert!(message.contains("jdk-21-nolib"), "{message}");
+    assert!(message.contains("architecture isn't"), "{message}");
+
+    assert!(matches!(
+        locator.clone().version(">=seventeen").locate(),
+        Err(StartJvmError::InvalidVersionRequirement(_))
+    ));
+
+    let empty = JvmLocator::new()
+        .java_home(false)
+        .path(false)
+        .system_roots(false)
+        .root(root.join("not-a-jdk"));
+    let err = empty.locate().unwrap_err();
+    assert!(
+        err.to_string().contains("no Java installations were found"),
+        "{err}"
+    );
+}
+
+#[test]
+fn jvm_locator_start_jvm() {
+    let locator = JvmLocator::new().version(">=8");
+    let jdk = locator.locate().unwrap_or_else(|e| panic!("{e}"));
+    println!("Selected {jdk}");
+
+    let jvm_args = InitArgsBuilder::new()
+        .build()
+        .unwrap_or_else(|e| panic!("{:#?}", e));
+    let jvm = JavaVM::with_libjvm(jvm_args, || locator.locate_libjvm())
+        .unwrap_or_else(|e| panic!("{:#?}", e));
+
+    jvm.attach_current_thread(|env| -> jni::errors::Result<()> {
+        let s = env.new_string("hello")?;
+        assert_eq!(s.try_to_string(env)?, "hello");
+        Ok(())
+    })
+    .unwrap();
+}
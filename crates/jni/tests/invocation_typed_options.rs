@@ -0,0 +1,46 @@
+// This is a separate test program because it has to start a JVM with specific options.
+
+#![cfg(feature = "invocation")]
+
+use jni::{InitArgsBuilder, JavaVM, jni_sig, jni_str, objects::JString};
+
+#[test]
+fn invocation_typed_options() {
+    let jvm_args = InitArgsBuilder::new()
+        .version(jni::JNIVersion::V1_8)
+        .check_jni(true)
+        .class_path(["dir with spaces", "other.jar"])
+        .system_property("jni.test", "a value with spaces")
+        .initial_heap(16 * 1024 * 1024)
+        .max_heap(256 * 1024 * 1024)
+        .build()
+        .unwrap_or_else(|e| panic!("{:#?}", e));
+
+    let jvm = JavaVM::new(jvm_args).unwrap_or_else(|e| panic!("{:#?}", e));
+
+    jvm.attach_current_thread(|env| -> jni::errors::Result<()> {
+        let mut get_property = |name: &str| -> jni::errors::Result<String> {
+            let name = env.new_string(name)?;
+            let value = env
+                .call_static_method(
+                    jni_str!("java/lang/System"),
+                    jni_str!("getProperty"),
+                    jni_sig!("(Ljava/lang/String;)Ljava/lang/String;"),
+                    &[(&name).into()],
+                )?
+                .l()?;
+            let value = env.cast_local::<JString>(value)?;
+            value.try_to_string(env)
+        };
+
+        assert_eq!(get_property("jni.test")?, "a value with spaces");
+
+        let separator = if cfg!(windows) { ';' } else { ':' };
+        assert_eq!(
+            get_property("java.class.path")?,
+            format!("dir with spaces{separator}other.jar")
+        );
+        Ok(())
+    })
+    .unwrap()
+}
@@ -1,8 +1,10 @@
 #![cfg(feature = "invocation")]
 
 use jni::{
+    Env,
+    errors::{Error, Result},
     jni_sig, jni_str,
-    objects::{IntoAuto, JList, JString},
+    objects::{IntoAuto, JComparator, JInteger, JList, JString},
     sys::jint,
 };
 
@@ -307,3 +309,144 @@ pub fn jlist_iterator_with_auto() {
     })
     .unwrap();
 }
+
+fn new_string_list<'local>(env: &mut Env<'local>, len: usize) -> Result<JList<'local>> {
+    let list_object = env.new_object(jni_str!("java/util/ArrayList"), jni_sig!("()V"), &[])?;
+    let list = JList::cast_local(env, list_object)?;
+    for i in 0..len {
+        let string = env.new_string(format!("item{i}"))?.auto();
+        list.add(env, &string)?;
+    }
+    Ok(list)
+}
+
+#[test]
+pub fn jlist_iter_as() {
+    attach_current_thread(|env| {
+        let list = new_string_list(env, 3)?;
+
+        let mut collected = Vec::new();
+        for item in list.iter_as::<String>(env)? {
+            collected.push(item?);
+        }
+        assert_eq!(collected, ["item0", "item1", "item2"]);
+
+        let collected = list.to_vec::<String>(env)?;
+        assert_eq!(collected, ["item0", "item1", "item2"]);
+
+        let lengths: Vec<usize> = list
+            .iter_as::<String>(env)?
+            .map(|item| item.map(|item| item.len()))
+            .collect::<Result<_>>()?;
+        assert_eq!(lengths, [5, 5, 5]);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jlist_iter_as_large_list() {
+    attach_current_thread(|env| {
+        let len = 50_000;
+        let list = new_string_list(env, len)?;
+
+        // Each element is converted in its own frame, so this doesn't accumulate local references
+        let mut count = 0;
+        for item in list.iter_as::<String>(env)? {
+            assert_eq!(item?, format!("item{count}"));
+            count += 1;
+        }
+        assert_eq!(count, len);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jlist_iter_as_stops_on_error() {
+    attach_current_thread(|env| {
+        let list = new_string_list(env, 1)?;
+        let integer = JInteger::value_of(env, 1)?;
+        list.add(env, &integer)?;
+        let string = env.new_string("last")?;
+        list.add(env, &string)?;
+
+        let mut iter = list.iter_as::<String>(env)?;
+        assert_eq!(iter.next().unwrap()?, "item0");
+        assert!(matches!(iter.next(), Some(Err(Error::WrongObjectType))));
+        assert!(iter.next().is_none());
+        drop(iter);
+
+        assert!(matches!(
+            list.to_vec::<String>(env),
+            Err(Error::WrongObjectType)
+        ));
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jlist_collect_globals() {
+    attach_current_thread(|env| {
+        let globals = env.with_local_frame(4, |env| -> Result<_> {
+            let list = new_string_list(env, 2)?;
+            list.collect_globals(env)
+        })?;
+        assert_eq!(globals.len(), 2);
+
+        // The global references outlive the frame of the list
+        let second = env.new_cast_local_ref::<JString>(&globals[1])?;
+        assert_eq!(second.try_to_string(env)?, "item1");
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jlist_typed_elements() {
+    attach_current_thread(|env| {
+        let list = new_string_list(env, 3)?;
+        let list = env.cast_local::<JList<JString>>(list)?;
+
+        let first: JString = list.get(env, 0)?;
+        assert_eq!(first.try_to_string(env)?, "item0");
+        assert!(list.contains(env, &first)?);
+        assert_eq!(list.index_of(env, &first)?, 0);
+
+        let replacement = JString::from_str(env, "replaced")?;
+        let old = list.set(env, 1, &replacement)?;
+        assert_eq!(old.try_to_string(env)?, "item1");
+        list.insert(env, 0, &replacement)?;
+        assert_eq!(list.index_of(env, &replacement)?, 0);
+        assert_eq!(list.last_index_of(env, &replacement)?, 2);
+        assert_eq!(list.size(env)?, 4);
+
+        let removed = list.remove(env, 0)?;
+        assert_eq!(removed.try_to_string(env)?, "replaced");
+        assert_eq!(list.to_vec::<String>(env)?, ["item0", "replaced", "item2"]);
+
+        let sub_list = list.sub_list(env, 1, 3)?;
+        assert_eq!(sub_list.size(env)?, 2);
+        let last: JString = sub_list.get(env, 1)?;
+        assert_eq!(last.try_to_string(env)?, "item2");
+
+        let reverse_order = JComparator::reverse_order(env)?;
+        list.sort(env, &reverse_order)?;
+        assert_eq!(list.to_vec::<String>(env)?, ["replaced", "item2", "item0"]);
+
+        // A null comparator sorts by natural order
+        list.sort(env, JComparator::null())?;
+        assert_eq!(list.to_vec::<String>(env)?, ["item0", "item2", "replaced"]);
+
+        // Element types are checked when elements are returned
+        let integer = JInteger::value_of(env, 1)?;
+        let untyped = env.new_cast_local_ref::<JList>(&list)?;
+        untyped.add(env, &integer)?;
+        assert!(matches!(list.get(env, 3), Err(Error::WrongObjectType)));
+        let integers = untyped.cast_elements::<JInteger>();
+        assert_eq!(integers.get(env, 3)?.int_value(env)?, 1);
+        Ok(())
+    })
+    .unwrap();
+}
@@ -1,8 +1,9 @@
 #![cfg(feature = "invocation")]
 
 use jni::{
+    errors::Error,
     jni_sig, jni_str,
-    objects::{JMap, JObject, JString},
+    objects::{JBiFunction, JInteger, JMap, JMapEntry, JObject, JSet, JString},
 };
 
 mod util;
@@ -57,3 +58,106 @@ pub fn jmap_push_and_iterate() {
     })
     .unwrap();
 }
+
+#[test]
+pub fn jmap_iter_as() {
+    attach_current_thread(|env| {
+        let map_object =
+            env.new_object(jni_str!("java/util/LinkedHashMap"), jni_sig!("()V"), &[])?;
+        let map = JMap::cast_local(env, map_object)?;
+        for (key, value) in [("one", Some(1)), ("two", None), ("three", Some(3))] {
+            let key = JString::from_str(env, key)?;
+            let value = match value {
+                Some(value) => JObject::from(JInteger::value_of(env, value)?),
+                None => JObject::null(),
+            };
+            map.put(env, &key, &value)?;
+        }
+
+        let mut collected = Vec::new();
+        for entry in map.iter_as::<String, Option<i32>>(env)? {
+            collected.push(entry?);
+        }
+        assert_eq!(
+            collected,
+            [
+                ("one".to_owned(), Some(1)),
+                ("two".to_owned(), None),
+                ("three".to_owned(), Some(3))
+            ]
+        );
+
+        let keys: Vec<String> = map
+            .to_vec::<String, Option<i32>>(env)?
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, ["one", "two", "three"]);
+
+        let globals = map.collect_globals(env)?;
+        assert_eq!(globals.len(), 3);
+        let key = env.new_cast_local_ref::<JString>(&globals[2].0)?;
+        assert_eq!(key.try_to_string(env)?, "three");
+        assert!(globals[1].1.is_null());
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jmap_typed_entries() {
+    attach_current_thread(|env| {
+        let map_object =
+            env.new_object(jni_str!("java/util/LinkedHashMap"), jni_sig!("()V"), &[])?;
+        let map = env.cast_local::<JMap<JString, JInteger>>(map_object)?;
+
+        let one = JString::from_str(env, "one")?;
+        let two = JString::from_str(env, "two")?;
+        let value = JInteger::value_of(env, 1)?;
+        assert!(map.put_if_absent(env, &one, &value)?.is_none());
+        let other = JInteger::value_of(env, 100)?;
+        let current = map.put_if_absent(env, &one, &other)?.unwrap();
+        assert_eq!(current.int_value(env)?, 1);
+        assert!(map.contains_key(env, &one)?);
+        assert!(!map.contains_key(env, &two)?);
+        assert!(map.contains_value(env, &value)?);
+
+        // Increments the value for a key, or starts counting from 1
+        let increment = JBiFunction::from_fn(env, |env, _key, value| {
+            let count = if value.is_null() {
+                0
+            } else {
+                env.cast_local::<JInteger>(value)?.int_value(env)?
+            };
+            Ok(JInteger::value_of(env, count + 1)?.into())
+        })?;
+        let one_count: JInteger = map.compute(env, &one, &increment)?.unwrap();
+        assert_eq!(one_count.int_value(env)?, 2);
+        let two_count = map.compute(env, &two, &increment)?.unwrap();
+        assert_eq!(two_count.int_value(env)?, 1);
+
+        let keys: JSet<JString> = map.key_set(env)?;
+        assert_eq!(keys.size(env)?, 2);
+        assert!(keys.contains(env, &two)?);
+        assert_eq!(keys.to_vec::<String>(env)?, ["one", "two"]);
+        assert_eq!(map.values(env)?.to_vec::<i32>(env)?, [2, 1]);
+
+        let entries = map.entry_set(env)?;
+        let iterator = entries.iterator(env)?;
+        let entry = iterator.next(env)?.unwrap();
+        let entry = env.cast_local::<JMapEntry>(entry)?;
+        let key = entry.key(env)?;
+        let key = env.cast_local::<JString>(key)?;
+        assert_eq!(key.try_to_string(env)?, "one");
+
+        // Value types are checked when values are returned
+        let untyped = env.new_cast_local_ref::<JMap>(&map)?;
+        let string = JString::from_str(env, "not an integer")?;
+        untyped.put(env, &two, &string)?;
+        assert!(matches!(map.get(env, &two), Err(Error::WrongObjectType)));
+        assert!(map.remove_value(env, &one, &one_count)?);
+        assert!(map.get(env, &one)?.is_none());
+        Ok(())
+    })
+    .unwrap();
+}
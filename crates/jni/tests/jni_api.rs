@@ -4,15 +4,15 @@ use std::convert::TryFrom;
 use assert_matches::assert_matches;
 
 use jni::{
-    Env,
+    Env, JNIVersion,
     descriptors::Desc,
     errors::{CharToJavaError, Error, JniError},
     jni_sig, jni_str,
     objects::{
-        AutoElements, IntoAuto as _, JByteBuffer, JList, JObject, JObjectArray, JStackTraceElement,
-        JString, JThrowable, JValue, ReleaseMode, Weak,
+        AutoElements, IntoAuto as _, JByteBuffer, JConstructor, JList, JMethod, JObject,
+        JObjectArray, JStackTraceElement, JString, JThread, JThrowable, JValue, ReleaseMode, Weak,
     },
-    refs::Reference,
+    refs::{LoaderContext, RefType, Reference},
     signature::{JavaType, MethodSignature, Primitive, ReturnType},
     strings::{JNIStr, JNIString},
     sys::{jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort},
@@ -1008,6 +1008,107 @@ pub fn get_reflected_static_method_from_id() {
     .unwrap();
 }
 
+#[test]
+pub fn get_method_id_from_reflected() {
+    attach_current_thread(|env| {
+        let ctor_method_id = env
+            .get_method_id(
+                INTEGER_CLASS,
+                jni_str!("<init>"),
+                jni_sig!("(Ljava/lang/String;)V"),
+            )
+            .unwrap();
+        let ctor = env
+            .to_reflected_method(INTEGER_CLASS, ctor_method_id)
+            .unwrap();
+        let ctor = env.cast_local::<JConstructor>(ctor).unwrap();
+        assert_eq!(ctor.as_executable().get_parameter_count(env).unwrap(), 1);
+        let method_id = env.from_reflected_method(&ctor).unwrap();
+        assert_eq!(method_id.into_raw(), ctor_method_id.into_raw());
+        assert_matches!(
+            env.from_reflected_static_method(&ctor),
+            Err(Error::WrongObjectType)
+        );
+
+        let math_class = env.find_class(MATH_CLASS).unwrap();
+        let abs_method_id = env
+            .get_static_method_id(&math_class, MATH_ABS_METHOD_NAME, MATH_ABS_SIGNATURE)
+            .unwrap();
+        let abs_method = env
+            .to_reflected_static_method(&math_class, abs_method_id)
+            .unwrap();
+        let abs_method = env.cast_local::<JMethod>(abs_method).unwrap();
+        assert!(abs_method.as_executable().is_static(env).unwrap());
+        assert_eq!(
+            abs_method
+                .get_name(env)
+                .unwrap()
+                .try_to_string(env)
+                .unwrap(),
+            "abs"
+        );
+        let method_id = env.from_reflected_static_method(&abs_method).unwrap();
+        assert_eq!(method_id.into_raw(), abs_method_id.into_raw());
+        assert_matches!(
+            env.from_reflected_method(&abs_method),
+            Err(Error::WrongObjectType)
+        );
+
+        assert_matches!(
+            env.from_reflected_method(JMethod::null()),
+            Err(Error::NullPtr(_))
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn get_field_id_from_reflected() {
+    attach_current_thread(|env| {
+        let system_class = env.find_class(jni_str!("java/lang/System")).unwrap();
+        let out_field_id = env
+            .get_static_field_id(
+                &system_class,
+                jni_str!("out"),
+                jni_sig!("Ljava/io/PrintStream;"),
+            )
+            .unwrap();
+        let out_field = env
+            .to_reflected_static_field(&system_class, out_field_id)
+            .unwrap();
+        assert!(out_field.is_static(env).unwrap());
+        assert_eq!(
+            out_field.get_name(env).unwrap().try_to_string(env).unwrap(),
+            "out"
+        );
+        let field_id = env.from_reflected_static_field(&out_field).unwrap();
+        assert_eq!(field_id.into_raw(), out_field_id.into_raw());
+        assert_matches!(
+            env.from_reflected_field(&out_field),
+            Err(Error::WrongObjectType)
+        );
+
+        let value_field_id = env
+            .get_field_id(INTEGER_CLASS, jni_str!("value"), jni_sig!("I"))
+            .unwrap();
+        let value_field = env
+            .to_reflected_field(INTEGER_CLASS, value_field_id)
+            .unwrap();
+        assert!(!value_field.is_static(env).unwrap());
+        let field_id = env.from_reflected_field(&value_field).unwrap();
+        assert_eq!(field_id.into_raw(), value_field_id.into_raw());
+        assert_matches!(
+            env.from_reflected_static_field(&value_field),
+            Err(Error::WrongObjectType)
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[test]
 pub fn java_byte_array_from_slice() {
     attach_current_thread(|env| {
@@ -1795,6 +1896,161 @@ pub fn test_conversion() {
     .unwrap();
 }
 
+#[test]
+pub fn get_object_ref_type() {
+    attach_current_thread(|env| {
+        let local = env.new_string("test").unwrap();
+        assert_eq!(env.get_object_ref_type(&local).unwrap(), RefType::Local);
+
+        let global = env.new_global_ref(&local).unwrap();
+        assert_eq!(env.get_object_ref_type(&global).unwrap(), RefType::Global);
+        global.debug_assert_ref_type(env);
+
+        let weak = env.new_weak_ref(&local).unwrap();
+        assert_eq!(env.get_object_ref_type(&weak).unwrap(), RefType::WeakGlobal);
+        weak.debug_assert_ref_type(env);
+
+        let auto = local.auto();
+        assert_eq!(env.get_object_ref_type(&auto).unwrap(), RefType::Local);
+        auto.debug_assert_ref_type(env);
+
+        assert_eq!(
+            env.get_object_ref_type(JObject::null()).unwrap(),
+            RefType::Invalid
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Expected a Global reference")]
+pub fn global_debug_assert_ref_type_panics_for_local() {
+    attach_current_thread(|env| {
+        let local = env.new_string("test").unwrap();
+        // Safety: this deliberately wraps a local reference as a global, and forgets the wrapper
+        // before it could be dropped
+        let global = unsafe { env.global_from_raw::<JObject>(local.as_raw()) };
+        let global = std::mem::ManuallyDrop::new(global);
+        global.debug_assert_ref_type(env);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn get_module() {
+    attach_current_thread(|env| {
+        let module = env.get_module(STRING_CLASS).unwrap();
+        assert!(module.is_named(env).unwrap());
+        assert_eq!(
+            module.get_name(env).unwrap().try_to_string(env).unwrap(),
+            "java.base"
+        );
+
+        let java_lang = JString::from_str(env, "java.lang").unwrap();
+        assert!(module.is_exported(env, &java_lang).unwrap());
+        assert!(!module.is_open(env, &java_lang).unwrap());
+
+        let internal_misc = JString::from_str(env, "jdk.internal.misc").unwrap();
+        assert!(!module.is_exported(env, &internal_misc).unwrap());
+
+        let logging = env
+            .get_module(jni_str!("java/util/logging/Logger"))
+            .unwrap();
+        assert!(
+            module
+                .is_exported_to(env, &internal_misc, &logging)
+                .unwrap()
+        );
+        assert!(!module.is_open_to(env, &internal_misc, &logging).unwrap());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn load_exported_class() {
+    attach_current_thread(|env| {
+        let logging = env
+            .get_module(jni_str!("java/util/logging/Logger"))
+            .unwrap();
+
+        // Exported unconditionally
+        LoaderContext::None
+            .load_exported_class(env, jni_str!("java.lang.String"), false, &logging)
+            .unwrap();
+        LoaderContext::None
+            .load_exported_class(env, jni_str!("[[Ljava.lang.String;"), false, &logging)
+            .unwrap();
+        LoaderContext::None
+            .load_exported_class(env, jni_str!("[I"), false, &logging)
+            .unwrap();
+
+        // Qualified export to `java.logging`
+        LoaderContext::None
+            .load_exported_class(env, jni_str!("jdk.internal.misc.Unsafe"), false, &logging)
+            .unwrap();
+
+        let err = LoaderContext::None
+            .load_exported_class(env, jni_str!("sun.nio.ch.Net"), false, &logging)
+            .unwrap_err();
+        assert_matches!(err, Error::ClassNotExported { requested, package, module, caller } => {
+            assert_eq!(requested, "sun.nio.ch.Net");
+            assert_eq!(package, "sun.nio.ch");
+            assert_eq!(module, "module java.base");
+            assert_eq!(caller, "module java.logging");
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn is_virtual_thread() {
+    attach_current_thread(|env| {
+        let current = JThread::current_thread(env).unwrap();
+        if env.version().unwrap() >= JNIVersion::V21 {
+            assert!(!current.is_virtual(env).unwrap());
+            assert!(!env.is_virtual_thread(JObject::null()).unwrap());
+        } else {
+            assert_matches!(
+                current.is_virtual(env),
+                Err(Error::UnsupportedJniVersion { required, .. }) if required == JNIVersion::V21
+            );
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn jstring_mutf8_len() {
+    attach_current_thread(|env| {
+        // 'é' is two bytes and the nul char is encoded as two bytes in modified UTF-8
+        let s = JString::from_str(env, "caf\u{e9}\0").unwrap();
+        assert_eq!(s.mutf8_len(env).unwrap(), 7);
+        if env.version().unwrap() >= JNIVersion::V24 {
+            assert_eq!(s.mutf8_len_long(env).unwrap(), 7);
+        } else {
+            assert_matches!(
+                s.mutf8_len_long(env),
+                Err(Error::UnsupportedJniVersion { required, .. }) if required == JNIVersion::V24
+            );
+        }
+        assert_matches!(JString::null().mutf8_len(env), Err(Error::NullPtr(_)));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 rusty_fork_test! {
 #[test]
 fn test_jstring_conversion() {
@@ -1835,6 +2091,130 @@ pub fn test_null_string_mutf8_chars() {
     .unwrap();
 }
 
+#[test]
+pub fn test_jstring_utf16() {
+    attach_current_thread(|env| {
+        // Includes a surrogate pair and an unpaired surrogate, which can't be represented in UTF-8
+        let units: Vec<u16> = "Hello, 🤓!"
+            .encode_utf16()
+            .chain(std::iter::once(0xd800))
+            .collect();
+        let s = JString::from_utf16(env, &units).unwrap();
+        assert_eq!(s.utf16_len(env).unwrap(), units.len());
+
+        let chars = s.utf16_chars(env).unwrap();
+        assert_eq!(&*chars, &units[..]);
+        drop(chars);
+
+        let mut buf = [0u16; 4];
+        assert_eq!(s.get_region(env, 7, 3, &mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], &units[7..10]);
+
+        assert_matches!(
+            s.get_region(env, 8, 4, &mut buf),
+            Err(Error::IndexOutOfBounds)
+        );
+        assert!(!env.exception_check());
+
+        let empty = JString::from_utf16(env, &[]).unwrap();
+        assert_eq!(empty.utf16_len(env).unwrap(), 0);
+        assert!(empty.utf16_chars(env).unwrap().is_empty());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn test_jstring_utf16_chars_critical() {
+    attach_current_thread(|env| {
+        let units: Vec<u16> = "Hello, 🤓!".encode_utf16().collect();
+        let s = JString::from_utf16(env, &units).unwrap();
+
+        {
+            let chars = unsafe { s.utf16_chars_critical(env) }.unwrap();
+            assert_eq!(chars.len(), units.len());
+            assert_eq!(&*chars, &units[..]);
+        }
+
+        let null = JString::null();
+        assert_matches!(
+            unsafe { null.utf16_chars_critical(env) },
+            Err(Error::NullPtr(_))
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn test_jstring_utf_region() {
+    attach_current_thread(|env| {
+        let s = JString::from_str(env, "a\0é🤓z").unwrap();
+        // "a", "\0", "é", "🤓" (surrogate pair), "z"
+        assert_eq!(s.utf16_len(env).unwrap(), 6);
+
+        let mut buf = [0xffu8; 16];
+        assert_eq!(s.get_utf_region(env, 0, 1, &mut buf).unwrap(), 1);
+        assert_eq!(&buf[..1], b"a");
+        // nul is encoded as two bytes in modified UTF-8
+        assert_eq!(s.get_utf_region(env, 1, 2, &mut buf).unwrap(), 4);
+        assert_eq!(&buf[..4], &[0xc0, 0x80, 0xc3, 0xa9]);
+        // each surrogate is encoded separately in modified UTF-8
+        assert_eq!(s.get_utf_region(env, 3, 2, &mut buf).unwrap(), 6);
+
+        assert_eq!(s.get_utf_region(env, 3, 3, &mut buf[..8]).unwrap(), 7);
+        assert_eq!(&buf[6..7], b"z");
+        // the buffer must have space for a nul terminator after the encoded region
+        assert_matches!(
+            s.get_utf_region(env, 3, 3, &mut buf[..7]),
+            Err(Error::JniCall(JniError::InvalidArguments))
+        );
+        assert_eq!(s.get_utf_region(env, 0, 6, &mut buf[..13]).unwrap(), 12);
+        assert_matches!(
+            s.get_utf_region(env, 0, 6, &mut buf[..12]),
+            Err(Error::JniCall(JniError::InvalidArguments))
+        );
+        assert_matches!(
+            s.get_utf_region(env, 5, 2, &mut buf),
+            Err(Error::IndexOutOfBounds)
+        );
+        assert!(!env.exception_check());
+
+        let mut string = String::with_capacity(64);
+        assert_eq!(s.get_region_into_string(env, 0, 1, &mut string).unwrap(), 1);
+        assert_eq!(string, "a");
+        s.get_region_into_string(env, 0, 6, &mut string).unwrap();
+        assert_eq!(string, "a\0é🤓z");
+        s.get_region_into_string(env, 5, 1, &mut string).unwrap();
+        assert_eq!(string, "z");
+        // unpaired surrogate
+        s.get_region_into_string(env, 3, 1, &mut string).unwrap();
+        assert!(!string.is_empty() && string.chars().all(|c| c == '\u{fffd}'));
+        assert_matches!(
+            s.get_region_into_string(env, 6, 1, &mut string),
+            Err(Error::IndexOutOfBounds)
+        );
+        assert!(string.is_empty());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+pub fn test_null_string_utf16_chars() {
+    attach_current_thread(|env| {
+        let s = unsafe { JString::from_raw(env, std::ptr::null_mut() as _) };
+        assert!(s.utf16_chars(env).is_err());
+        assert!(s.utf16_len(env).is_err());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 fn test_throwable_descriptor_with_default_type<'local, D>(env: &mut Env<'local>, descriptor: D)
 where
     D: Desc<'local, JThrowable<'local>>,
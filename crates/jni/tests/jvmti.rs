@@ -0,0 +1,114 @@
+#![cfg(feature = "invocation")]
+
+use jni::{
+    jni_str,
+    jvmti::{Capabilities, HeapFilter, HeapVisit, JvmtiEnv, JvmtiVersion},
+    objects::{JObject, JThread},
+};
+
+mod util;
+use util::{attach_current_thread, jvm};
+
+fn jvmti_with_tagging() -> JvmtiEnv {
+    let jvmti = jvm().get_jvmti_env(JvmtiVersion::V1_2).unwrap();
+    assert!(jvmti.version().unwrap() >= JvmtiVersion::V1_2);
+
+    let potential = jvmti.get_potential_capabilities().unwrap();
+    assert!(potential.can_tag_objects());
+
+    let mut caps = Capabilities::new();
+    caps.set_can_tag_objects(true);
+    jvmti.add_capabilities(&caps).unwrap();
+    assert!(jvmti.get_capabilities().unwrap().can_tag_objects());
+    jvmti
+}
+
+#[test]
+fn jvmti_object_tagging() {
+    attach_current_thread(|env| {
+        let jvmti = jvmti_with_tagging();
+
+        let obj = env.new_string("tagged")?;
+        assert_eq!(jvmti.get_tag(&obj)?, 0);
+        jvmti.set_tag(&obj, 42)?;
+        assert_eq!(jvmti.get_tag(&obj)?, 42);
+
+        assert!(jvmti.get_tag(JObject::null()).is_err());
+
+        let found = jvmti.get_objects_with_tags(env, &[42])?;
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, 42);
+        assert!(env.is_same_object(&found[0].0, &obj)?);
+
+        // Only tagged objects should be reported (and re-tag the object while iterating)
+        let mut seen = Vec::new();
+        jvmti.iterate_through_heap(HeapFilter::UNTAGGED, None, |object| {
+            seen.push(object.tag);
+            if object.tag == 42 {
+                object.tag = 43;
+            }
+            HeapVisit::Continue
+        })?;
+        assert_eq!(seen, vec![42]);
+        assert_eq!(jvmti.get_tag(&obj)?, 43);
+
+        // Check that iteration can be aborted
+        let mut count = 0;
+        jvmti.iterate_through_heap(HeapFilter::NONE, None, |_object| {
+            count += 1;
+            HeapVisit::Abort
+        })?;
+        assert_eq!(count, 1);
+
+        unsafe { jvmti.dispose() }
+    })
+    .unwrap();
+}
+
+#[test]
+fn jvmti_loaded_classes() {
+    attach_current_thread(|env| {
+        let jvmti = jvm().get_jvmti_env(JvmtiVersion::V1_2)?;
+        env.with_local_frame(1024, |env| -> jni::errors::Result<()> {
+            let string_class = env.find_class(jni_str!("java/lang/String"))?;
+            let classes = jvmti.get_loaded_classes(env)?;
+            assert!(!classes.is_empty());
+            assert!(
+                classes
+                    .iter()
+                    .any(|c| env.is_same_object(c, &string_class).unwrap())
+            );
+            Ok(())
+        })
+    })
+    .unwrap();
+}
+
+#[test]
+fn jvmti_thread_info_and_stack_trace() {
+    attach_current_thread(|env| {
+        let jvmti = jvm().get_jvmti_env(JvmtiVersion::V1_2)?;
+
+        let current = JThread::current_thread(env)?;
+        let name = current.get_name(env)?.try_to_string(env)?;
+        let info = jvmti.get_thread_info(env, None)?;
+        assert_eq!(info.name, name);
+        assert!(!info.thread_group.is_null());
+
+        let info = jvmti.get_thread_info(env, Some(&current))?;
+        assert_eq!(info.name, name);
+
+        // There are no Java frames on the stack for a natively attached thread
+        let frames = jvmti.get_stack_trace(None, 0, 16)?;
+        assert!(frames.len() <= 16);
+
+        jvmti.force_garbage_collection()?;
+
+        let mut mem = jvmti.allocate(16)?;
+        assert_eq!(mem.len(), 16);
+        assert!(mem.iter().all(|b| *b == 0));
+        mem[0] = 1;
+        Ok(())
+    })
+    .unwrap();
+}
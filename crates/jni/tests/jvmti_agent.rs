@@ -0,0 +1,116 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    ffi::{CStr, c_void},
+    os::raw::c_char,
+    sync::Mutex,
+};
+
+use jni::{
+    Env, JavaVM,
+    errors::Error,
+    jvmti::{AgentOptions, AgentStatus, JvmtiVersion},
+    objects::JString,
+    sys::{self, jint},
+};
+
+mod util;
+use util::{attach_current_thread, jvm};
+
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[jni::agent(on_load)]
+fn agent_on_load(vm: &JavaVM, options: &AgentOptions) -> Result<(), Error> {
+    vm.get_jvmti_env(JvmtiVersion::V1_2)?;
+    if options.contains("fail") {
+        return Err(Error::JniCall(jni::errors::JniError::Unknown));
+    }
+    if options.contains("panic") {
+        panic!("on_load panic");
+    }
+    EVENTS
+        .lock()
+        .unwrap()
+        .push(format!("load: {}", options.get("name").unwrap_or_default()));
+    Ok(())
+}
+
+#[jni::agent(on_attach)]
+fn agent_on_attach(_vm: &JavaVM, env: &mut Env, options: &AgentOptions) -> Result<(), Error> {
+    let name = JString::from_str(env, options.get("name").unwrap_or_default())?;
+    if options.contains("fail") {
+        return Err(Error::JniCall(jni::errors::JniError::Unknown));
+    }
+    if options.contains("panic") {
+        panic!("on_attach panic");
+    }
+    EVENTS
+        .lock()
+        .unwrap()
+        .push(format!("attach: {}", name.try_to_string(env)?));
+    Ok(())
+}
+
+#[jni::agent(on_unload)]
+fn agent_on_unload(_vm: &JavaVM) {
+    EVENTS.lock().unwrap().push("unload".to_string());
+}
+
+unsafe extern "system" {
+    fn Agent_OnLoad(vm: *mut sys::JavaVM, options: *mut c_char, reserved: *mut c_void) -> jint;
+    fn Agent_OnAttach(vm: *mut sys::JavaVM, options: *mut c_char, reserved: *mut c_void) -> jint;
+    fn Agent_OnUnload(vm: *mut sys::JavaVM);
+}
+
+fn call_entry_point(
+    f: unsafe extern "system" fn(*mut sys::JavaVM, *mut c_char, *mut c_void) -> jint,
+    options: &CStr,
+) -> AgentStatus {
+    let status = unsafe {
+        f(
+            jvm().get_raw(),
+            options.as_ptr() as *mut c_char,
+            std::ptr::null_mut(),
+        )
+    };
+    if status == AgentStatus::OK.into_raw() {
+        AgentStatus::OK
+    } else {
+        AgentStatus::ERR
+    }
+}
+
+// Calls the exported entry points directly (the JVM has already been created, but the
+// entry points only depend on the `JavaVM` pointer being valid).
+#[test]
+fn agent_entry_points() {
+    attach_current_thread(|_env| {
+        assert_eq!(call_entry_point(Agent_OnLoad, c"name=foo"), AgentStatus::OK);
+        assert_eq!(call_entry_point(Agent_OnLoad, c"fail"), AgentStatus::ERR);
+        assert_eq!(call_entry_point(Agent_OnLoad, c"panic"), AgentStatus::ERR);
+
+        assert_eq!(
+            call_entry_point(Agent_OnAttach, c"name=bar"),
+            AgentStatus::OK
+        );
+        assert_eq!(call_entry_point(Agent_OnAttach, c"fail"), AgentStatus::ERR);
+        assert_eq!(call_entry_point(Agent_OnAttach, c"panic"), AgentStatus::ERR);
+
+        unsafe { Agent_OnUnload(jvm().get_raw()) };
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(
+        *EVENTS.lock().unwrap(),
+        vec!["load: foo", "attach: bar", "unload"]
+    );
+}
+
+#[test]
+fn agent_options_from_raw() {
+    let options = unsafe { AgentOptions::from_raw(c"a=1,b".as_ptr()) };
+    assert_eq!(options.as_str(), "a=1,b");
+    assert_eq!(options.get("a"), Some("1"));
+    assert_eq!(options.get("b"), Some(""));
+}
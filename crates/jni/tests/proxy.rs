@@ -0,0 +1,216 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use jni::{
+    JValue,
+    errors::Error,
+    jni_sig, jni_str,
+    objects::{JClassLoader, JObject, JString},
+    proxy::RustProxy,
+};
+
+mod util;
+use util::attach_current_thread;
+
+#[test]
+fn proxy_dispatches_to_closure() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler_calls = calls.clone();
+
+    attach_current_thread(|env| {
+        let runnable = RustProxy::from_fn(move |env, invocation| {
+            assert_eq!(invocation.method_name(env)?, "run");
+            assert_eq!(invocation.arg_count(env)?, 0);
+            handler_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(JObject::null())
+        })
+        .interface(jni_str!("java.lang.Runnable"))
+        .build(env)?;
+
+        env.call_method(&runnable, jni_str!("run"), jni_sig!("()V"), &[])?;
+        env.call_method(&runnable, jni_str!("run"), jni_sig!("()V"), &[])?;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // `Object` methods are implemented based on the identity of the proxy
+        let hash_code = env
+            .call_method(&runnable, jni_str!("hashCode"), jni_sig!("()I"), &[])?
+            .i()?;
+        let identity_hash_code = env
+            .call_static_method(
+                jni_str!("java/lang/System"),
+                jni_str!("identityHashCode"),
+                jni_sig!("(Ljava/lang/Object;)I"),
+                &[JValue::Object(&runnable)],
+            )?
+            .i()?;
+        assert_eq!(hash_code, identity_hash_code);
+        let equals = env
+            .call_method(
+                &runnable,
+                jni_str!("equals"),
+                jni_sig!("(Ljava/lang/Object;)Z"),
+                &[JValue::Object(&runnable)],
+            )?
+            .z()?;
+        assert!(equals);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn proxy_arguments_and_return_value() {
+    attach_current_thread(|env| {
+        // Compares strings by length
+        let comparator = RustProxy::from_fn(|env, invocation| {
+            assert_eq!(invocation.method_name(env)?, "compare");
+            assert_eq!(invocation.arg_count(env)?, 2);
+            let a = invocation.arg(env, 0)?;
+            let a = env.cast_local::<JString>(a)?;
+            let b = invocation.arg(env, 1)?;
+            let b = env.cast_local::<JString>(b)?;
+            let ordering = a.try_to_string(env)?.len() as i32 - b.try_to_string(env)?.len() as i32;
+            env.call_static_method(
+                jni_str!("java/lang/Integer"),
+                jni_str!("valueOf"),
+                jni_sig!("(I)Ljava/lang/Integer;"),
+                &[JValue::Int(ordering)],
+            )?
+            .l()
+        })
+        .interface(jni_str!("java.util.Comparator"))
+        .build(env)?;
+
+        let short = JString::from_str(env, "ab")?;
+        let long = JString::from_str(env, "abcd")?;
+        let ordering = env
+            .call_method(
+                &comparator,
+                jni_str!("compare"),
+                jni_sig!("(Ljava/lang/Object;Ljava/lang/Object;)I"),
+                &[JValue::Object(&short), JValue::Object(&long)],
+            )?
+            .i()?;
+        assert_eq!(ordering, -2);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn proxy_errors_throw_exceptions() {
+    attach_current_thread(|env| {
+        let runnable = RustProxy::from_fn(|_env, _invocation| Err(Error::WrongObjectType))
+            .interface(jni_str!("java.lang.Runnable"))
+            .build(env)?;
+
+        let result = env.call_method(&runnable, jni_str!("run"), jni_sig!("()V"), &[]);
+        assert!(matches!(result, Err(Error::JavaException)));
+        let exception = env
+            .exception_occurred()
+            .expect("exception should be pending");
+        env.exception_clear();
+        let class = env.get_object_class(&exception)?;
+        assert_eq!(
+            class.get_name(env)?.to_string(),
+            "java.lang.RuntimeException"
+        );
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn proxy_multiple_interfaces_with_class_loader() {
+    attach_current_thread(|env| {
+        let runnable_class = env.find_class(jni_str!("java/lang/Runnable"))?;
+        let loader = JClassLoader::get_system_class_loader(env)?;
+        let proxy = RustProxy::from_fn(|_env, _invocation| Ok(JObject::null()))
+            .interface_class(&runnable_class)
+            .interface(jni_str!("java.util.EventListener"))
+            .class_loader(&loader)
+            .build(env)?;
+
+        assert!(env.is_instance_of(&proxy, jni_str!("java/lang/Runnable"))?);
+        assert!(env.is_instance_of(&proxy, jni_str!("java/util/EventListener"))?);
+        assert!(!env.is_instance_of(&proxy, jni_str!("java/util/Comparator"))?);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn proxy_build_fails_for_non_interface() {
+    struct DropFlag(Arc<AtomicBool>);
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+    let dropped = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(dropped.clone());
+
+    attach_current_thread(|env| {
+        let result = RustProxy::from_fn(move |_env, _invocation| {
+            let _flag = &flag;
+            Ok(JObject::null())
+        })
+        .interface(jni_str!("java.lang.String"))
+        .build(env);
+        assert!(result.is_err());
+        env.exception_clear();
+        Ok(())
+    })
+    .unwrap();
+
+    // The handler is dropped immediately if the proxy couldn't be created
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn proxy_handler_dropped_after_collection() {
+    struct DropFlag(Arc<AtomicBool>);
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+    let dropped = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(dropped.clone());
+
+    attach_current_thread(|env| {
+        env.with_local_frame(4, |env| -> jni::errors::Result<()> {
+            let runnable = RustProxy::from_fn(move |_env, _invocation| {
+                let _flag = &flag;
+                Ok(JObject::null())
+            })
+            .interface(jni_str!("java.lang.Runnable"))
+            .build(env)?;
+            env.call_method(&runnable, jni_str!("run"), jni_sig!("()V"), &[])?;
+            Ok(())
+        })?;
+
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while !dropped.load(Ordering::SeqCst) {
+            assert!(Instant::now() < deadline, "proxy handler was never dropped");
+            env.call_static_method(
+                jni_str!("java/lang/System"),
+                jni_str!("gc"),
+                jni_sig!("()V"),
+                &[],
+            )?;
+            thread::sleep(Duration::from_millis(20));
+        }
+        Ok(())
+    })
+    .unwrap();
+}
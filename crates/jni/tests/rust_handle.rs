@@ -0,0 +1,156 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use jni::{
+    Env,
+    errors::{Error, Result},
+    handle::RustHandle,
+    jni_sig, jni_str,
+    objects::JObject,
+};
+
+mod util;
+use util::attach_current_thread;
+
+/// Counts how many times it's dropped
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Returns a new `AtomicLong`, whose private `long value` field is used to store a handle
+fn new_owner<'local>(env: &mut Env<'local>) -> Result<JObject<'local>> {
+    env.new_object(
+        jni_str!("java/util/concurrent/atomic/AtomicLong"),
+        jni_sig!("()V"),
+        &[],
+    )
+}
+
+fn collect_until(env: &mut Env, condition: impl Fn() -> bool) -> Result<()> {
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !condition() {
+        assert!(Instant::now() < deadline, "rust handle was never dropped");
+        env.call_static_method(
+            jni_str!("java/lang/System"),
+            jni_str!("gc"),
+            jni_sig!("()V"),
+            &[],
+        )?;
+        thread::sleep(Duration::from_millis(20));
+    }
+    Ok(())
+}
+
+#[test]
+fn rust_handle_field_set_lock_and_take() {
+    attach_current_thread(|env| {
+        let owner = new_owner(env)?;
+        unsafe { RustHandle::set_field(env, &owner, jni_str!("value"), vec![1, 2, 3])? };
+
+        let result = unsafe { RustHandle::set_field(env, &owner, jni_str!("value"), vec![4]) };
+        assert!(matches!(result, Err(Error::FieldAlreadySet(_))));
+
+        {
+            let mut value =
+                unsafe { RustHandle::<Vec<i32>>::lock_field(env, &owner, jni_str!("value"))? };
+            value.push(4);
+        }
+
+        let value: Vec<i32> = unsafe { RustHandle::take_field(env, &owner, jni_str!("value"))? };
+        assert_eq!(value, [1, 2, 3, 4]);
+
+        // The field is reset once the value has been taken
+        let handle = env
+            .call_method(&owner, jni_str!("get"), jni_sig!("()J"), &[])?
+            .j()?;
+        assert_eq!(handle, 0);
+        let result = unsafe { RustHandle::<Vec<i32>>::lock_field(env, &owner, jni_str!("value")) };
+        assert!(matches!(result, Err(Error::NullPtr(_))));
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn rust_handle_raw_lock_and_take() {
+    attach_current_thread(|env| {
+        let owner = new_owner(env)?;
+        let handle = RustHandle::register(env, &owner, String::from("hello"))?;
+
+        let rust_handle = unsafe { RustHandle::<String>::from_raw(handle)? };
+        rust_handle.lock()?.push_str(" world");
+        {
+            let _guard = rust_handle.lock()?;
+            assert!(matches!(rust_handle.take(), Err(Error::TryLock)));
+        }
+        assert_eq!(rust_handle.take()?, "hello world");
+        assert!(matches!(rust_handle.take(), Err(Error::NullPtr(_))));
+        assert!(matches!(rust_handle.lock(), Err(Error::NullPtr(_))));
+
+        assert!(matches!(
+            unsafe { RustHandle::<String>::from_raw(0) },
+            Err(Error::NullPtr(_))
+        ));
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn rust_handle_dropped_after_collection() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let value = DropCounter(drops.clone());
+
+    attach_current_thread(|env| {
+        env.with_local_frame(4, |env| -> Result<()> {
+            let owner = new_owner(env)?;
+            unsafe { RustHandle::set_field(env, &owner, jni_str!("value"), value)? };
+            Ok(())
+        })?;
+
+        collect_until(env, || drops.load(Ordering::SeqCst) > 0)
+    })
+    .unwrap();
+
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn rust_handle_taken_value_not_dropped_by_cleaner() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let value = DropCounter(drops.clone());
+    let released = Arc::new(AtomicUsize::new(0));
+    let marker = DropCounter(released.clone());
+
+    attach_current_thread(|env| {
+        env.with_local_frame(4, |env| -> Result<()> {
+            let owner = new_owner(env)?;
+            RustHandle::register(env, &owner, marker)?;
+            unsafe { RustHandle::set_field(env, &owner, jni_str!("value"), value)? };
+            let value: DropCounter =
+                unsafe { RustHandle::take_field(env, &owner, jni_str!("value"))? };
+            drop(value);
+            Ok(())
+        })?;
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+        // The other handle is only released once the owner has been collected, which also
+        // queues the cleaning action for the taken handle
+        collect_until(env, || released.load(Ordering::SeqCst) > 0)
+    })
+    .unwrap();
+
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
@@ -48,3 +48,40 @@ fn attach_config() {
 
     let _ = thread.join().unwrap();
 }
+
+// Tests that an `AttachConfig` can be used to attach a daemon thread
+#[test]
+fn attach_config_daemon() {
+    let jvm = jvm();
+
+    for scoped in [false, true] {
+        let thread = spawn({
+            move || {
+                jvm.attach_current_thread_with_config(
+                    || AttachConfig::new().daemon(true).scoped(scoped),
+                    None,
+                    |env| -> jni::errors::Result<_> {
+                        assert_eq!(jvm.daemon_threads_attached(), 1);
+
+                        let thread = env
+                            .call_static_method(
+                                jni_str!("java/lang/Thread"),
+                                jni_str!("currentThread"),
+                                jni_sig!("()Ljava/lang/Thread;"),
+                                &[],
+                            )?
+                            .l()?;
+                        let is_daemon = env
+                            .call_method(thread, jni_str!("isDaemon"), jni_sig!("()Z"), &[])?
+                            .z()?;
+                        assert!(is_daemon);
+                        Ok(())
+                    },
+                )
+            }
+        });
+
+        thread.join().unwrap().unwrap();
+        assert_eq!(jvm.daemon_threads_attached(), 0);
+    }
+}